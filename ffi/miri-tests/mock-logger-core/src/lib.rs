@@ -40,3 +40,70 @@ pub fn log<Message: AsRef<str>, Identifier: AsRef<str>>(
 ) {
     // No-op for Miri tests
 }
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogRotation {
+    #[default]
+    Hourly,
+    Daily,
+    Interval(std::time::Duration),
+    Size(u64),
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LogFileConfig {
+    pub rotation: LogRotation,
+    pub max_files: Option<usize>,
+    pub compress: bool,
+}
+
+impl LogFileConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        match self.rotation {
+            LogRotation::Interval(interval) if interval.is_zero() => {
+                Err("The interval of the log rotation must be positive".to_string())
+            }
+            LogRotation::Size(0) => {
+                Err("The size of the log rotation must be positive".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+pub fn init_with_file_config(
+    _minimal_level: Option<Level>,
+    _file_name: Option<&str>,
+    _format: LogFormat,
+    file_config: LogFileConfig,
+) -> Result<Level, String> {
+    file_config.validate()?;
+    Ok(Level::Warn)
+}
+
+#[derive(Debug)]
+pub struct LogRecord<'a> {
+    pub level: Level,
+    pub target: &'a str,
+    pub identifier: &'a str,
+    pub message: &'a str,
+    pub fields: &'a [(&'static str, String)],
+}
+
+pub trait LogSink: Send + Sync {
+    fn log(&self, record: &LogRecord<'_>);
+}
+
+pub fn set_log_sink(
+    minimal_level: Option<Level>,
+    _sink: Option<std::sync::Arc<dyn LogSink>>,
+) -> Level {
+    minimal_level.unwrap_or(Level::Warn)
+}
@@ -2,9 +2,11 @@
 
 use std::{
     future::Future,
+    pin::Pin,
+    sync::Mutex,
     task::{Context, Poll},
 };
-use crate::task::JoinHandle;
+use crate::task::{JoinHandle, TaskState};
 
 pub struct Runtime;
 
@@ -25,12 +27,13 @@ impl Runtime {
     {
         let waker = std::task::Waker::noop();
         let context = &mut Context::from_waker(&waker);
-        match std::pin::pin!(future).poll(context) {
-            Poll::Ready(result) => result,
-            _ => panic!("No result found")
+        let mut future: Pin<Box<dyn Future<Output = F::Output> + Send>> = Box::pin(future);
+        let state = match future.as_mut().poll(context) {
+            Poll::Ready(result) => TaskState::Finished(Some(result)),
+            _ => TaskState::Pending(future),
         };
         JoinHandle {
-            _p: std::marker::PhantomData::<F::Output>
+            state: Mutex::new(state),
         }
     }
 }
@@ -1,7 +1,80 @@
 // Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
 
-use std::marker::PhantomData;
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Mutex,
+    task::{Context, Poll},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Id;
+
+#[derive(Debug)]
+pub struct JoinError;
+
+pub(crate) enum TaskState<T> {
+    Pending(Pin<Box<dyn Future<Output = T> + Send>>),
+    Finished(Option<T>),
+    Aborted,
+}
 
 pub struct JoinHandle<T> {
-    pub _p: PhantomData<T>,
+    pub(crate) state: Mutex<TaskState<T>>,
+}
+
+impl<T> JoinHandle<T> {
+    // Tasks that wait for events, such as the push notifications task, can't be driven further, and are only dropped
+    // once they're aborted
+    pub fn abort(&self) {
+        let mut state = self.state.lock().unwrap();
+        if let TaskState::Pending(_) = *state {
+            *state = TaskState::Aborted;
+        }
+    }
+
+    pub fn id(&self) -> Id {
+        Id
+    }
+}
+
+impl<T> Unpin for JoinHandle<T> {}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+        match &mut *state {
+            TaskState::Pending(future) => match future.as_mut().poll(cx) {
+                Poll::Ready(result) => {
+                    *state = TaskState::Finished(None);
+                    Poll::Ready(Ok(result))
+                }
+                Poll::Pending => Poll::Pending,
+            },
+            TaskState::Finished(result) => {
+                Poll::Ready(Ok(result.take().expect("JoinHandle polled after completion")))
+            }
+            TaskState::Aborted => Poll::Ready(Err(JoinError)),
+        }
+    }
+}
+
+impl<T> Drop for JoinHandle<T> {
+    fn drop(&mut self) {
+        if let Ok(TaskState::Pending(_)) = self.state.get_mut()
+            && !std::thread::panicking()
+        {
+            panic!("No result found");
+        }
+    }
+}
+
+pub fn try_id() -> Option<Id> {
+    None
+}
+
+pub fn block_in_place<F: FnOnce() -> R, R>(f: F) -> R {
+    f()
 }
@@ -6,16 +6,22 @@ use glide_core::{
     connection_request::{NodeAddress, TlsMode},
 };
 use miri_tests::{
-    ClientType, ConnectionResponse, PushKind, close_client, create_client, free_connection_response,
+    ClientType, ConnectionResponse, PushKind, RuntimeMode, cancel_client_creation, close_client,
+    create_client, create_client_async, create_client_with_runtime, free_client_creation,
+    free_connection_response, set_push_kind_filter,
+};
+use miri_tests::{
+    Level, LogField, LogFileConfig, LogFormat, LogResult, LogRotation, free_log_result, glide_log,
+    init, init_with_config, set_log_callback,
 };
-use miri_tests::{Level, LogResult, free_log_result, glide_log, init};
 use miri_tests::{
     create_batch_otel_span, create_batch_otel_span_with_parent, create_named_otel_span,
     create_otel_span, create_otel_span_with_parent, drop_otel_span,
 };
 use protobuf::Message;
-use std::ffi::{CStr, CString, c_char};
+use std::ffi::{CStr, CString, c_char, c_void};
 use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 fn create_connection_request(port: u16) -> Vec<u8> {
     let host = "localhost";
@@ -77,6 +83,86 @@ fn create_client_test() {
     }
 }
 
+#[test]
+fn create_client_with_isolated_runtime_test() {
+    let connection_request_bytes = create_connection_request(6378);
+    let client_type_ptr = Box::into_raw(Box::new(ClientType::SyncClient));
+
+    unsafe {
+        let connection_response_ptr = create_client_with_runtime(
+            connection_request_bytes.as_ptr(),
+            connection_request_bytes.len(),
+            client_type_ptr,
+            pubsub_callback,
+            RuntimeMode::Isolated,
+        );
+        let conn_ptr = (*connection_response_ptr).conn_ptr;
+        close_client(conn_ptr);
+        free_connection_response(connection_response_ptr as *mut ConnectionResponse);
+        let _ = Box::from_raw(client_type_ptr);
+    }
+}
+
+#[test]
+fn set_push_kind_filter_test() {
+    let connection_request_bytes = create_connection_request(6378);
+    let client_type_ptr = Box::into_raw(Box::new(ClientType::SyncClient));
+
+    unsafe {
+        let connection_response_ptr = create_client(
+            connection_request_bytes.as_ptr(),
+            connection_request_bytes.len(),
+            client_type_ptr,
+            pubsub_callback,
+        );
+        let conn_ptr = (*connection_response_ptr).conn_ptr;
+        set_push_kind_filter(conn_ptr, u32::MAX);
+        close_client(conn_ptr);
+        free_connection_response(connection_response_ptr as *mut ConnectionResponse);
+        let _ = Box::from_raw(client_type_ptr);
+    }
+}
+
+static CREATED_CLIENT: AtomicUsize = AtomicUsize::new(0);
+
+unsafe extern "C-unwind" fn client_created_callback(
+    request_id: usize,
+    client_adapter_ptr: *const c_void,
+) {
+    assert_eq!(request_id, 1);
+    CREATED_CLIENT.store(client_adapter_ptr.addr(), Ordering::SeqCst);
+}
+
+unsafe extern "C-unwind" fn client_creation_failed_callback(
+    _request_id: usize,
+    _error_message: *const c_char,
+) {
+    panic!("The client creation shouldn't fail");
+}
+
+#[test]
+fn create_client_async_test() {
+    let connection_request_bytes = create_connection_request(6378);
+    let client_type_ptr = Box::into_raw(Box::new(ClientType::SyncClient));
+
+    unsafe {
+        let creation_ptr = create_client_async(
+            connection_request_bytes.as_ptr(),
+            connection_request_bytes.len(),
+            client_type_ptr,
+            pubsub_callback,
+            1,
+            client_created_callback,
+            client_creation_failed_callback,
+        );
+        // The mocked runtime runs the creation to completion when it's spawned
+        assert!(!cancel_client_creation(creation_ptr));
+        free_client_creation(creation_ptr);
+        close_client(CREATED_CLIENT.load(Ordering::SeqCst) as *const c_void);
+        let _ = Box::from_raw(client_type_ptr);
+    }
+}
+
 #[test]
 fn test_create_otel_span_miri() {
     // Test basic span creation
@@ -330,6 +416,79 @@ fn test_init_logger_with_invalid_utf8_filename() {
     }
 }
 
+#[test]
+fn test_init_logger_with_file_config() {
+    unsafe {
+        let level = Level::INFO;
+        let file_name = CString::new("output.log").unwrap();
+        let file_config = LogFileConfig {
+            rotation: LogRotation::SIZE,
+            rotation_value: 1024,
+            max_files: 5,
+            compress: true,
+        };
+        let log_result_ptr =
+            init_with_config(&level, file_name.as_ptr(), &LogFormat::JSON, &file_config);
+        assert!(!log_result_ptr.is_null());
+
+        let log_result = &*log_result_ptr;
+        assert!(log_result.log_error.is_null());
+
+        free_log_result(log_result_ptr);
+    }
+}
+
+#[test]
+fn test_init_logger_with_zero_rotation_value() {
+    unsafe {
+        let file_name = CString::new("output.log").unwrap();
+        let file_config = LogFileConfig {
+            rotation: LogRotation::INTERVAL,
+            rotation_value: 0,
+            max_files: 0,
+            compress: false,
+        };
+        let log_result_ptr =
+            init_with_config(ptr::null(), file_name.as_ptr(), ptr::null(), &file_config);
+        assert!(!log_result_ptr.is_null());
+
+        let log_result = &*log_result_ptr;
+        assert!(!log_result.log_error.is_null());
+
+        free_log_result(log_result_ptr);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe extern "C-unwind" fn log_callback(
+    _level: Level,
+    _target: *const u8,
+    _target_len: usize,
+    _identifier: *const u8,
+    _identifier_len: usize,
+    _message: *const u8,
+    _message_len: usize,
+    _fields: *const LogField,
+    _fields_len: usize,
+) {
+}
+
+#[test]
+fn test_set_log_callback() {
+    unsafe {
+        let level = Level::INFO;
+        let log_result_ptr = set_log_callback(&level, Some(log_callback));
+        assert!(!log_result_ptr.is_null());
+        let log_result = &*log_result_ptr;
+        assert!(log_result.log_error.is_null());
+        assert_eq!(log_result.level, Level::INFO);
+        free_log_result(log_result_ptr);
+
+        let log_result_ptr = set_log_callback(ptr::null(), None);
+        free_log_result(log_result_ptr);
+    }
+}
+
 #[test]
 fn test_log_with_valid_inputs() {
     unsafe {
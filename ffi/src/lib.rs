@@ -27,10 +27,12 @@ use redis::{Cmd, Pipeline, PipelineRetryStrategy, RedisResult, Value};
 use std::ffi::CStr;
 use std::future::Future;
 use std::mem::ManuallyDrop;
+use std::ops::Deref;
 use std::slice::from_raw_parts;
 use std::str;
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicU8, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
 use std::{
     ffi::{CString, c_void},
     mem,
@@ -247,13 +249,23 @@ pub type FailureCallback = unsafe extern "C-unwind" fn(
 /// # Parameters
 /// * `client_ptr`: A baton-pass back to the caller language to uniquely identify the client.
 /// * `kind`: An enum variant representing the PushKind (Message, PMessage, SMessage, etc.)
-/// * `message`: A pointer to the raw message bytes.
+/// * `message`: A pointer to the raw message bytes (null if the kind has no message).
 /// * `message_len`: The length of the message data in bytes.
-/// * `channel`: A pointer to the raw request name bytes.
+/// * `channel`: A pointer to the raw request name bytes (null if the kind has no channel).
 /// * `channel_len`: The length of the request name in bytes.
 /// * `pattern`: A pointer to the raw pattern bytes (null if no pattern).
 /// * `pattern_len`: The length of the pattern in bytes (0 if no pattern).
 ///
+/// Only messages are passed to the callback by default, see [`set_push_kind_filter`] for the other kinds.
+/// The data passed with each kind is:
+/// * Messages pass their pattern (for `PushPMessage`), channel and message.
+/// * Subscription confirmations pass the channel or pattern as channel, and the number of the connection's
+///   subscriptions as a decimal message.
+/// * `PushInvalidate` is passed once per invalidated key, with the key as message, or once with a null message
+///   when all keys were invalidated.
+/// * `PushDisconnection` passes no data.
+/// * Other kinds pass their first two values as channel and message.
+///
 /// # Safety
 /// The pointers are only valid during the callback execution and will be freed
 /// automatically when the callback returns. Any data needed beyond the callback's
@@ -269,6 +281,25 @@ pub type PubSubCallback = unsafe extern "C-unwind" fn(
     pattern_len: i64,
 ) -> ();
 
+/// Callback that is called when a client created by [`create_client_async`] connects.
+///
+/// `request_id` is the baton-pass given to [`create_client_async`].
+/// `client_adapter_ptr` is the created client, which must be closed with [`close_client`].
+pub type ClientCreatedCallback =
+    unsafe extern "C-unwind" fn(request_id: usize, client_adapter_ptr: *const c_void) -> ();
+
+/// Callback that is called when a client created by [`create_client_async`] fails to connect.
+///
+/// The callback needs to copy the given string synchronously, since it will be dropped by Rust once the callback returns.
+///
+/// `request_id` is the baton-pass given to [`create_client_async`].
+/// `error_message` is the connection error. The 'error_message' is managed by Rust and is freed when the callback returns control back to the caller.
+///
+/// # Safety
+/// `error_message` must be a valid pointer to a `c_char`.
+pub type ClientCreationFailedCallback =
+    unsafe extern "C-unwind" fn(request_id: usize, error_message: *const c_char) -> ();
+
 /// The connection response.
 ///
 /// It contains either a connection or an error. It is represented as a struct instead of a union for ease of use in the wrapper language.
@@ -414,10 +445,107 @@ pub enum ClientType {
     SyncClient,
 }
 
+/// The tokio runtime that executes a client's requests.
+///
+/// # Variants
+///
+/// - `Shared`: The client runs on the process-wide runtime shared by all clients, whose number of worker
+///   threads is set by [`configure_shared_runtime`].
+/// - `Isolated`: The client runs on its own runtime with a single worker thread, isolated from other clients.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RuntimeMode {
+    Shared,
+    Isolated,
+}
+
+/// The process-wide runtime shared by clients, created with the first client that uses it.
+static SHARED_RUNTIME: OnceLock<Runtime> = OnceLock::new();
+
+enum ClientRuntime {
+    Shared(&'static Runtime),
+    Isolated(Runtime),
+}
+
+impl Deref for ClientRuntime {
+    type Target = Runtime;
+
+    fn deref(&self) -> &Runtime {
+        match self {
+            ClientRuntime::Shared(runtime) => runtime,
+            ClientRuntime::Isolated(runtime) => runtime,
+        }
+    }
+}
+
+/// Builds a multi-threaded runtime, with the default number of worker threads (one per core) if `worker_threads` is 0.
+fn build_runtime(worker_threads: usize) -> Result<Runtime, String> {
+    let mut builder = Builder::new_multi_thread();
+    builder.enable_all().thread_name("Valkey-GLIDE thread");
+    if worker_threads > 0 {
+        builder.worker_threads(worker_threads);
+    }
+    builder.build().map_err(|err| {
+        let redis_error = err.into();
+        errors::error_message(&redis_error)
+    })
+}
+
+fn get_or_init_shared_runtime() -> Result<&'static Runtime, String> {
+    if let Some(runtime) = SHARED_RUNTIME.get() {
+        return Ok(runtime);
+    }
+    let runtime = build_runtime(0)?;
+    // If another client initialized the runtime concurrently, its runtime is used and this one is dropped.
+    Ok(SHARED_RUNTIME.get_or_init(|| runtime))
+}
+
+/// Configures the number of worker threads of the process-wide runtime shared by clients created with
+/// [`RuntimeMode::Shared`]. A `worker_threads` of 0 uses one worker thread per core, which is the default.
+///
+/// Returns `null` on success, or an error message if the shared runtime is already running, since a client
+/// was already created with it. The error message must be freed with [`free_c_string`].
+#[unsafe(no_mangle)]
+pub extern "C" fn configure_shared_runtime(worker_threads: usize) -> *const c_char {
+    let result = build_runtime(worker_threads).and_then(|runtime| {
+        SHARED_RUNTIME.set(runtime).map_err(|_| {
+            "The shared runtime can only be configured before the first client is created"
+                .to_string()
+        })
+    });
+    match result {
+        Ok(()) => std::ptr::null(),
+        Err(err) => CString::new(err)
+            .unwrap_or_else(|_| CString::new("Couldn't convert error message to C string").unwrap())
+            .into_raw(),
+    }
+}
+
 /// A `GlideClient` adapter.
 pub struct ClientAdapter {
-    runtime: Runtime,
+    runtime: ClientRuntime,
     core: Arc<CommandExecutionCore>,
+    /// The kinds of push notifications passed to the client's `pubsub_callback`.
+    push_kind_mask: Arc<AtomicU32>,
+    /// The task that passes push notifications to the client's `pubsub_callback`, stopped when the client is closed.
+    push_task: OnceLock<tokio::task::JoinHandle<()>>,
+}
+
+impl Drop for ClientAdapter {
+    fn drop(&mut self) {
+        let Some(push_task) = self.push_task.take() else {
+            return;
+        };
+        // On the shared runtime, the task would otherwise outlive the client and pass its freed pointer to the callback.
+        push_task.abort();
+        // A callback that's running isn't interrupted, so the client is freed only once it returns, unless it's the
+        // callback that closes the client.
+        if tokio::task::try_id() == Some(push_task.id()) {
+            return;
+        }
+        // The client may be closed from a request's callback, which runs on a runtime thread.
+        _ = tokio::task::block_in_place(|| self.runtime.block_on(push_task));
+    }
 }
 
 struct CommandExecutionCore {
@@ -625,8 +753,11 @@ impl ClientAdapter {
     }
 }
 
+/// The kind of a push notification passed to the [`PubSubCallback`].
+///
+/// The kinds passed to a client's callback are selected with [`set_push_kind_filter`].
 #[repr(C)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub enum PushKind {
     PushDisconnection,
     PushOther,
@@ -661,136 +792,274 @@ impl From<redis::PushKind> for PushKind {
     }
 }
 
+impl PushKind {
+    /// The bit of the kind in the mask of [`set_push_kind_filter`].
+    fn mask_bit(self) -> u32 {
+        1 << self as u32
+    }
+}
+
+/// The push notifications passed to the [`PubSubCallback`] until [`set_push_kind_filter`] is called.
+const DEFAULT_PUSH_KIND_MASK: u32 = (1 << PushKind::PushMessage as u32)
+    | (1 << PushKind::PushPMessage as u32)
+    | (1 << PushKind::PushSMessage as u32);
+
 /// Processes a push notification message and calls the provided callback function.
 ///
-/// This function converts a PushInfo message to a CommandResponse, determines the
-/// notification type, and invokes the callback with the appropriate parameters.
+/// This function splits the notification into its pattern, channel and message, and
+/// invokes the callback with each of them.
 ///
 /// # Parameters
 /// - `push_msg`: The push notification message to process.
 /// - `pubsub_callback`: The callback function to invoke with the processed notification.
 /// - `client_adapter_ptr`: A pointer to the client adapter to pass to the callback.
 ///
-/// # Returns
-/// - `true` if the message was successfully processed and the callback was called.
-/// - `false` if there was an error processing the message (e.g., conversion failed).
-///
 /// # Safety
 /// This function is unsafe because it:
-/// - Dereferences raw pointers
 /// - Calls an FFI function (`pubsub_callback`) that may have undefined behavior
 /// - Creates and destroys vectors via `Vec::from_raw_parts`
-/// - Assumes push_msg.data contains valid BulkString values
 ///
 /// The caller must ensure:
 /// - `pubsub_callback` is a valid function pointer to a properly implemented callback
 /// - `client_adapter_ptr` is a valid usize representing a client adapter pointer
-/// - Memory allocated during conversion is properly freed after the callback completes
 unsafe fn process_push_notification(
     push_msg: redis::PushInfo,
     pubsub_callback: PubSubCallback,
     client_adapter_ptr: usize,
 ) {
-    let strings: Vec<(*mut u8, i64)> = push_msg
-        .data
-        .iter()
-        .map(|v| {
-            let Value::BulkString(str) = v else {
-                unreachable!()
-            };
-            let (ptr, len) = convert_vec_to_pointer(str.clone());
-            (ptr, len)
-        })
-        .collect();
+    let kind = PushKind::from(push_msg.kind.clone());
+    for [pattern, channel, message] in push_notification_parts(&push_msg.kind, push_msg.data) {
+        let (pattern_ptr, pattern_len) = optional_vec_to_pointer(pattern);
+        let (channel_ptr, channel_len) = optional_vec_to_pointer(channel);
+        let (message_ptr, message_len) = optional_vec_to_pointer(message);
+
+        // Call the pubsub callback with the push notification data
+        unsafe {
+            pubsub_callback(
+                client_adapter_ptr,
+                kind,
+                message_ptr,
+                message_len,
+                channel_ptr,
+                channel_len,
+                pattern_ptr,
+                pattern_len,
+            );
+            // Free memory
+            for (ptr, len) in [
+                (message_ptr, message_len),
+                (channel_ptr, channel_len),
+                (pattern_ptr, pattern_len),
+            ] {
+                if !ptr.is_null() {
+                    let _ = Vec::from_raw_parts(ptr, len as usize, len as usize);
+                }
+            }
+        }
+    }
+}
 
-    let ((pattern_ptr, pattern_len), (channel, channel_len), (message_ptr, message_len)) = {
-        if strings.len() == 3 {
-            (strings[0], strings[1], strings[2])
-        } else {
-            ((std::ptr::null_mut::<u8>(), 0), strings[0], strings[1])
+/// Splits a push notification into the `[pattern, channel, message]` of each call to the pubsub callback,
+/// as documented in [`PubSubCallback`].
+fn push_notification_parts(kind: &redis::PushKind, data: Vec<Value>) -> Vec<[Option<Vec<u8>>; 3]> {
+    if *kind == redis::PushKind::Invalidate {
+        return match data.into_iter().next() {
+            Some(Value::Array(keys)) => keys
+                .into_iter()
+                .map(|key| [None, None, push_value_bytes(key)])
+                .collect(),
+            _ => vec![[None, None, None]],
+        };
+    }
+    let mut values = data.into_iter().map(push_value_bytes);
+    let mut next = || values.next().flatten();
+    match kind {
+        redis::PushKind::Disconnection => vec![[None, None, None]],
+        redis::PushKind::PMessage => vec![[next(), next(), next()]],
+        _ => vec![[None, next(), next()]],
+    }
+}
+
+/// Returns the bytes of a string or integer value of a push notification.
+fn push_value_bytes(value: Value) -> Option<Vec<u8>> {
+    match value {
+        Value::BulkString(bytes) => Some(bytes),
+        Value::SimpleString(string) => Some(string.into_bytes()),
+        Value::Int(int) => Some(int.to_string().into_bytes()),
+        _ => None,
+    }
+}
+
+fn optional_vec_to_pointer(vec: Option<Vec<u8>>) -> (*mut u8, c_long) {
+    vec.map_or((std::ptr::null_mut(), 0), convert_vec_to_pointer)
+}
+
+const CLIENT_CREATION_LOCK_ERR: &str = "Failed to acquire the client creation lock";
+
+const CLIENT_CREATION_PENDING: u8 = 0;
+const CLIENT_CREATION_COMPLETED: u8 = 1;
+const CLIENT_CREATION_CANCELLED: u8 = 2;
+
+/// A client creation started by [`create_client_async`], which can be cancelled while it's pending.
+pub struct ClientCreation {
+    state: AtomicU8,
+    task: Mutex<Option<tokio::task::JoinHandle<()>>>,
+}
+
+impl ClientCreation {
+    fn new() -> Self {
+        Self {
+            state: AtomicU8::new(CLIENT_CREATION_PENDING),
+            task: Mutex::new(None),
         }
-    };
+    }
 
-    // Call the pubsub callback with the push notification data
-    unsafe {
-        pubsub_callback(
-            client_adapter_ptr,
-            push_msg.kind.into(),
-            message_ptr,
-            message_len,
-            channel,
-            channel_len,
-            pattern_ptr,
-            pattern_len,
-        );
-        // Free memory
-        let _ = Vec::from_raw_parts(message_ptr, message_len as usize, message_len as usize);
-        let _ = Vec::from_raw_parts(channel, channel_len as usize, channel_len as usize);
-        if !pattern_ptr.is_null() {
-            let _ = Vec::from_raw_parts(pattern_ptr, pattern_len as usize, pattern_len as usize);
+    /// Marks the creation as completed, before calling its callbacks. Returns `false` if it was cancelled.
+    fn complete(&self) -> bool {
+        self.transition(CLIENT_CREATION_COMPLETED)
+    }
+
+    fn cancel(&self) -> bool {
+        if !self.transition(CLIENT_CREATION_CANCELLED) {
+            return false;
         }
+        if let Some(task) = self.task.lock().expect(CLIENT_CREATION_LOCK_ERR).take() {
+            task.abort();
+        }
+        true
+    }
+
+    fn set_task(&self, task: tokio::task::JoinHandle<()>) {
+        let mut guard = self.task.lock().expect(CLIENT_CREATION_LOCK_ERR);
+        // The creation may have been cancelled before its task was set.
+        if self.state.load(Ordering::Acquire) == CLIENT_CREATION_CANCELLED {
+            task.abort();
+        } else {
+            *guard = Some(task);
+        }
+    }
+
+    fn transition(&self, state: u8) -> bool {
+        self.state
+            .compare_exchange(
+                CLIENT_CREATION_PENDING,
+                state,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            )
+            .is_ok()
     }
 }
 
-fn create_client_internal(
-    connection_request_bytes: &[u8],
+/// A parsed connection request of a new client, with the channel of the client's push notifications.
+struct ClientSetup {
+    request: ConnectionRequest,
     client_type: ClientType,
     pubsub_callback: PubSubCallback,
-) -> Result<*const ClientAdapter, String> {
-    let request = connection_request::ConnectionRequest::parse_from_bytes(connection_request_bytes)
-        .map_err(|err| err.to_string())?;
-    // TODO: optimize this using multiple threads instead of a single worker thread (e.g. by pinning each go thread to a rust thread)
-    let runtime = Builder::new_multi_thread()
-        .enable_all()
-        .worker_threads(1)
-        .thread_name("Valkey-GLIDE thread")
-        .build()
-        .map_err(|err| {
-            let redis_error = err.into();
-            errors::error_message(&redis_error)
-        })?;
-
-    let is_subscriber = request.pubsub_subscriptions.is_some() && pubsub_callback as usize != 0;
-    let (push_tx, mut push_rx) = tokio::sync::mpsc::unbounded_channel();
-    let tx = match is_subscriber {
-        true => Some(push_tx),
-        false => None,
-    };
+    has_push_callback: bool,
+    push_tx: Option<tokio::sync::mpsc::UnboundedSender<redis::PushInfo>>,
+    push_rx: tokio::sync::mpsc::UnboundedReceiver<redis::PushInfo>,
+}
+
+impl ClientSetup {
+    fn parse(
+        connection_request_bytes: &[u8],
+        client_type: ClientType,
+        pubsub_callback: PubSubCallback,
+    ) -> Result<Self, String> {
+        let request =
+            connection_request::ConnectionRequest::parse_from_bytes(connection_request_bytes)
+                .map_err(|err| err.to_string())?;
+        // Push notifications are delivered whenever there is a callback, since the client may subscribe after it's
+        // created, or receive pushes that aren't pubsub messages, which the kind filter selects.
+        let has_push_callback = pubsub_callback as usize != 0;
+        let (push_tx, push_rx) = tokio::sync::mpsc::unbounded_channel();
+        let request = ConnectionRequest::try_from(request).map_err(|err| err.to_string())?;
+        Ok(Self {
+            request,
+            client_type,
+            pubsub_callback,
+            has_push_callback,
+            push_tx: has_push_callback.then_some(push_tx),
+            push_rx,
+        })
+    }
 
-    let client = runtime
-        .block_on(GlideClient::new(ConnectionRequest::from(request), tx))
-        .map_err(|err| err.to_string())?;
+    async fn connect(&mut self) -> Result<GlideClient, String> {
+        GlideClient::new(std::mem::take(&mut self.request), self.push_tx.take())
+            .await
+            .map_err(|err| err.to_string())
+    }
 
-    // Create the client adapter that will be returned and used as conn_ptr
-    let core = Arc::new(CommandExecutionCore {
-        client,
-        client_type,
-    });
-    let client_adapter = Arc::new(ClientAdapter { runtime, core });
-    // Clone client_adapter before moving it into the async block
-    let client_adapter_ptr = Arc::as_ptr(&client_adapter).addr();
-
-    // If pubsub_callback is provided (not null), spawn a task to handle push notifications
-    if is_subscriber {
-        client_adapter.runtime.spawn(async move {
-            while let Some(push_msg) = push_rx.recv().await {
-                if push_msg.kind == redis::PushKind::Message
-                    || push_msg.kind == redis::PushKind::PMessage
-                    || push_msg.kind == redis::PushKind::SMessage
-                {
-                    unsafe {
-                        process_push_notification(push_msg, pubsub_callback, client_adapter_ptr);
+    /// Wraps the connected `client` in the `ClientAdapter` that is returned as `conn_ptr`.
+    fn into_client_adapter(
+        self,
+        client: GlideClient,
+        runtime: ClientRuntime,
+    ) -> *const ClientAdapter {
+        let Self {
+            client_type,
+            pubsub_callback,
+            has_push_callback,
+            mut push_rx,
+            ..
+        } = self;
+        let core = Arc::new(CommandExecutionCore {
+            client,
+            client_type,
+        });
+        let push_kind_mask = Arc::new(AtomicU32::new(DEFAULT_PUSH_KIND_MASK));
+        let client_adapter = Arc::new(ClientAdapter {
+            runtime,
+            core,
+            push_kind_mask: push_kind_mask.clone(),
+            push_task: OnceLock::new(),
+        });
+        // Clone client_adapter before moving it into the async block
+        let client_adapter_ptr = Arc::as_ptr(&client_adapter).addr();
+
+        // If pubsub_callback is provided (not null), spawn a task to handle push notifications
+        if has_push_callback {
+            let push_task = client_adapter.runtime.spawn(async move {
+                while let Some(push_msg) = push_rx.recv().await {
+                    let kind_bit = PushKind::from(push_msg.kind.clone()).mask_bit();
+                    if push_kind_mask.load(Ordering::Relaxed) & kind_bit != 0 {
+                        unsafe {
+                            process_push_notification(
+                                push_msg,
+                                pubsub_callback,
+                                client_adapter_ptr,
+                            );
+                        }
                     }
                 }
-            }
-        });
+            });
+            _ = client_adapter.push_task.set(push_task);
+        }
+
+        Arc::into_raw(client_adapter)
     }
+}
 
-    Ok(Arc::into_raw(client_adapter))
+fn create_client_internal(
+    connection_request_bytes: &[u8],
+    client_type: ClientType,
+    pubsub_callback: PubSubCallback,
+    runtime_mode: RuntimeMode,
+) -> Result<*const ClientAdapter, String> {
+    let mut setup = ClientSetup::parse(connection_request_bytes, client_type, pubsub_callback)?;
+    let runtime = match runtime_mode {
+        RuntimeMode::Shared => ClientRuntime::Shared(get_or_init_shared_runtime()?),
+        RuntimeMode::Isolated => ClientRuntime::Isolated(build_runtime(1)?),
+    };
+    let client = runtime.block_on(setup.connect())?;
+    Ok(setup.into_client_adapter(client, runtime))
 }
 
 /// Creates a new `ClientAdapter` with a new `GlideClient` configured using a Protobuf `ConnectionRequest`.
 ///
+/// The client runs on the runtime shared by all clients, see [`create_client_with_runtime`] to run it on its own runtime.
+/// This function blocks until the client connects, see [`create_client_async`] for a non-blocking alternative.
 /// The returned `ConnectionResponse` will only be freed by calling [`free_connection_response`].
 ///
 /// `connection_request_bytes` is an array of bytes that will be parsed into a Protobuf `ConnectionRequest` object.
@@ -805,20 +1074,49 @@ fn create_client_internal(
 /// * The `conn_ptr` pointer in the returned `ConnectionResponse` must live while the client is open/active and must be explicitly freed by calling [`close_client``].
 /// * The `connection_error_message` pointer in the returned `ConnectionResponse` must live until the returned `ConnectionResponse` pointer is passed to [`free_connection_response``].
 /// * Both the `success_callback` and `failure_callback` function pointers need to live while the client is open/active. The caller is responsible for freeing both callbacks.
-// TODO: Consider making this async
 #[unsafe(no_mangle)]
 pub unsafe extern "C-unwind" fn create_client(
     connection_request_bytes: *const u8,
     connection_request_len: usize,
     client_type: *const ClientType,
     pubsub_callback: PubSubCallback,
+) -> *const ConnectionResponse {
+    unsafe {
+        create_client_with_runtime(
+            connection_request_bytes,
+            connection_request_len,
+            client_type,
+            pubsub_callback,
+            RuntimeMode::Shared,
+        )
+    }
+}
+
+/// Creates a new `ClientAdapter` like [`create_client`], running on the runtime selected by `runtime_mode`.
+///
+/// With [`RuntimeMode::Isolated`], the client gets its own runtime, which is shut down when the client is closed.
+///
+/// # Safety
+///
+/// Same as [`create_client`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn create_client_with_runtime(
+    connection_request_bytes: *const u8,
+    connection_request_len: usize,
+    client_type: *const ClientType,
+    pubsub_callback: PubSubCallback,
+    runtime_mode: RuntimeMode,
 ) -> *const ConnectionResponse {
     assert!(!connection_request_bytes.is_null());
     let request_bytes =
         unsafe { std::slice::from_raw_parts(connection_request_bytes, connection_request_len) };
     let client_type = unsafe { &*client_type };
-    let response = match create_client_internal(request_bytes, client_type.clone(), pubsub_callback)
-    {
+    let response = match create_client_internal(
+        request_bytes,
+        client_type.clone(),
+        pubsub_callback,
+        runtime_mode,
+    ) {
         Err(err) => ConnectionResponse {
             conn_ptr: std::ptr::null(),
             connection_error_message: CString::into_raw(
@@ -833,6 +1131,127 @@ pub unsafe extern "C-unwind" fn create_client(
     Box::into_raw(Box::new(response))
 }
 
+/// Creates a new `ClientAdapter` like [`create_client`], without blocking the calling thread while connecting.
+///
+/// Like [`create_client`], the client runs on the process-wide runtime shared by all clients. Once connected,
+/// `created_callback` is called with the client's `conn_ptr`, which must be closed with [`close_client`]. If the
+/// client fails to connect, `creation_failed_callback` is called with the connection error instead. Exactly one of the
+/// callbacks is called, possibly before this function returns, unless the creation is cancelled with
+/// [`cancel_client_creation`].
+///
+/// Returns a handle to the pending creation, which must be freed with [`free_client_creation`].
+///
+/// `request_id` is a baton-pass back to the caller language, passed to the callbacks to identify the creation.
+///
+/// # Safety
+///
+/// * `connection_request_bytes` and `connection_request_len` must satisfy the same requirements as in [`create_client`].
+/// * Both `created_callback` and `creation_failed_callback` function pointers need to live until one of them is called,
+///   or the creation is cancelled.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn create_client_async(
+    connection_request_bytes: *const u8,
+    connection_request_len: usize,
+    client_type: *const ClientType,
+    pubsub_callback: PubSubCallback,
+    request_id: usize,
+    created_callback: ClientCreatedCallback,
+    creation_failed_callback: ClientCreationFailedCallback,
+) -> *const ClientCreation {
+    assert!(!connection_request_bytes.is_null());
+    let request_bytes =
+        unsafe { std::slice::from_raw_parts(connection_request_bytes, connection_request_len) };
+    let client_type = unsafe { &*client_type };
+    let creation = Arc::new(ClientCreation::new());
+
+    let setup = ClientSetup::parse(request_bytes, client_type.clone(), pubsub_callback)
+        .and_then(|setup| Ok((setup, get_or_init_shared_runtime()?)));
+    match setup {
+        Err(err) => {
+            creation.complete();
+            unsafe { report_client_creation_failure(creation_failed_callback, request_id, err) };
+        }
+        Ok((mut setup, runtime)) => {
+            let task_creation = creation.clone();
+            let task = runtime.spawn(async move {
+                let result = setup.connect().await;
+                // A client that connected after its creation was cancelled is dropped.
+                if !task_creation.complete() {
+                    return;
+                }
+                match result {
+                    Ok(client) => {
+                        let client_adapter_ptr =
+                            setup.into_client_adapter(client, ClientRuntime::Shared(runtime));
+                        unsafe {
+                            created_callback(request_id, client_adapter_ptr as *const c_void)
+                        };
+                    }
+                    Err(err) => unsafe {
+                        report_client_creation_failure(creation_failed_callback, request_id, err)
+                    },
+                }
+            });
+            creation.set_task(task);
+        }
+    }
+    Arc::into_raw(creation)
+}
+
+/// Calls `creation_failed_callback` with `error_message`, which is freed once the callback returns.
+///
+/// # Safety
+/// Unsafe, because calls to an FFI function. See the safety documentation of [`ClientCreationFailedCallback`].
+unsafe fn report_client_creation_failure(
+    creation_failed_callback: ClientCreationFailedCallback,
+    request_id: usize,
+    error_message: String,
+) {
+    let err_ptr = CString::into_raw(
+        CString::new(error_message).expect("Couldn't convert error message to CString"),
+    );
+    unsafe { creation_failed_callback(request_id, err_ptr) };
+    _ = unsafe { CString::from_raw(err_ptr) };
+}
+
+/// Cancels a pending client creation started by [`create_client_async`], and stops its connection attempts.
+///
+/// Returns `true` if the creation was cancelled, in which case none of its callbacks will be called. Returns `false`
+/// if the creation already completed, in which case one of its callbacks was called, or is being called.
+///
+/// # Panics
+///
+/// This function panics when called with a null `client_creation_ptr`.
+///
+/// # Safety
+///
+/// * `client_creation_ptr` must be obtained from [`create_client_async`], and must not be freed yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cancel_client_creation(
+    client_creation_ptr: *const ClientCreation,
+) -> bool {
+    assert!(!client_creation_ptr.is_null());
+    unsafe { &*client_creation_ptr }.cancel()
+}
+
+/// Frees the handle of a client creation started by [`create_client_async`].
+///
+/// Freeing the handle doesn't cancel the creation, whose callbacks are still called once it completes.
+///
+/// # Panics
+///
+/// This function panics when called with a null `client_creation_ptr`.
+///
+/// # Safety
+///
+/// * `free_client_creation` can only be called once per client creation.
+/// * `client_creation_ptr` must be obtained from [`create_client_async`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn free_client_creation(client_creation_ptr: *const ClientCreation) {
+    assert!(!client_creation_ptr.is_null());
+    drop(unsafe { Arc::from_raw(client_creation_ptr) });
+}
+
 /// Closes the given `GlideClient`, freeing it from the heap.
 ///
 /// `client_adapter_ptr` is a pointer to a valid `GlideClient` returned in the `ConnectionResponse` from [`create_client`].
@@ -854,6 +1273,32 @@ pub unsafe extern "C" fn close_client(client_adapter_ptr: *const c_void) {
     unsafe { Arc::decrement_strong_count(client_adapter_ptr as *const ClientAdapter) };
 }
 
+/// Sets the kinds of push notifications that are passed to the client's `pubsub_callback`.
+///
+/// `push_kind_mask` has the bit `1 << kind` set for each [`PushKind`] `kind` that is passed to the callback.
+/// Until this function is called, only `PushMessage`, `PushPMessage` and `PushSMessage` are passed, so
+/// notifications received while the client connects, such as the confirmations of its initial subscriptions,
+/// are filtered by this default.
+///
+/// # Panics
+///
+/// This function panics when called with a null `client_adapter_ptr`.
+///
+/// # Safety
+///
+/// * `client_adapter_ptr` must be obtained from the `ConnectionResponse` returned from [`create_client`], and must not be closed yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn set_push_kind_filter(
+    client_adapter_ptr: *const c_void,
+    push_kind_mask: u32,
+) {
+    assert!(!client_adapter_ptr.is_null());
+    let client_adapter = unsafe { &*(client_adapter_ptr as *const ClientAdapter) };
+    client_adapter
+        .push_kind_mask
+        .store(push_kind_mask, Ordering::Relaxed);
+}
+
 /// Deallocates a `ConnectionResponse`.
 ///
 /// This function also frees the contained error. If the contained error is a null pointer, the function returns and only the `ConnectionResponse` is freed.
@@ -2746,6 +3191,132 @@ impl From<Level> for logger_core::Level {
     }
 }
 
+/// When the log file is rotated, see [`LogFileConfig`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogRotation {
+    /// At the start of every hour.
+    HOURLY = 0,
+    /// At the start of every day.
+    DAILY = 1,
+    /// Every `rotation_value` seconds.
+    INTERVAL = 2,
+    /// Once the file grows beyond `rotation_value` bytes.
+    SIZE = 3,
+}
+
+/// The rotation and retention of log files, passed to [`init_with_config`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct LogFileConfig {
+    pub rotation: LogRotation,
+    /// The interval in seconds of `INTERVAL` rotation, or the maximum file size in bytes of `SIZE` rotation,
+    /// which must be positive. Ignored by other rotations.
+    pub rotation_value: u64,
+    /// The maximum number of log files kept, including the one being written. 0 keeps all the files.
+    pub max_files: usize,
+    /// Whether rotated files are compressed with gzip.
+    pub compress: bool,
+}
+
+impl From<LogFileConfig> for logger_core::LogFileConfig {
+    fn from(config: LogFileConfig) -> Self {
+        let rotation = match config.rotation {
+            LogRotation::HOURLY => logger_core::LogRotation::Hourly,
+            LogRotation::DAILY => logger_core::LogRotation::Daily,
+            LogRotation::INTERVAL => logger_core::LogRotation::Interval(
+                std::time::Duration::from_secs(config.rotation_value),
+            ),
+            LogRotation::SIZE => logger_core::LogRotation::Size(config.rotation_value),
+        };
+        logger_core::LogFileConfig {
+            rotation,
+            max_files: (config.max_files > 0).then_some(config.max_files),
+            compress: config.compress,
+        }
+    }
+}
+
+/// The format of the logs written to the console or to a file, passed to [`init_with_config`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    /// Human-readable lines.
+    TEXT = 0,
+    /// JSON lines, with the timestamp, level, target, identifier, message and context of each log as fields.
+    JSON = 1,
+}
+
+impl From<LogFormat> for logger_core::LogFormat {
+    fn from(format: LogFormat) -> Self {
+        match format {
+            LogFormat::TEXT => logger_core::LogFormat::Text,
+            LogFormat::JSON => logger_core::LogFormat::Json,
+        }
+    }
+}
+
+/// A field of a log passed to a [`LogCallback`], such as the `client_id` or `connection_id` of the log's context.
+#[repr(C)]
+#[derive(Debug)]
+pub struct LogField {
+    pub name: *const u8,
+    pub name_len: usize,
+    pub value: *const u8,
+    pub value_len: usize,
+}
+
+/// Callback that receives the logs, to pass them to the logger of the host language, see [`set_log_callback`].
+///
+/// `target` is the module that wrote the log, and `fields` points to `fields_len` [`LogField`]s.
+/// The strings are UTF-8 encoded, not null-terminated, and all the pointers are only valid during the call.
+///
+/// # Safety
+/// The callback is called synchronously on the thread that writes the log, possibly from several threads at once.
+/// It must hand the log off quickly, and must not log through [`glide_log`].
+pub type LogCallback = unsafe extern "C-unwind" fn(
+    level: Level,
+    target: *const u8,
+    target_len: usize,
+    identifier: *const u8,
+    identifier_len: usize,
+    message: *const u8,
+    message_len: usize,
+    fields: *const LogField,
+    fields_len: usize,
+);
+
+/// Passes the logs of `logger_core` to a [`LogCallback`].
+struct CallbackLogSink(LogCallback);
+
+impl logger_core::LogSink for CallbackLogSink {
+    fn log(&self, record: &logger_core::LogRecord<'_>) {
+        let fields: Vec<LogField> = record
+            .fields
+            .iter()
+            .map(|(name, value)| LogField {
+                name: name.as_ptr(),
+                name_len: name.len(),
+                value: value.as_ptr(),
+                value_len: value.len(),
+            })
+            .collect();
+        unsafe {
+            (self.0)(
+                record.level.into(),
+                record.target.as_ptr(),
+                record.target.len(),
+                record.identifier.as_ptr(),
+                record.identifier.len(),
+                record.message.as_ptr(),
+                record.message.len(),
+                fields.as_ptr(),
+                fields.len(),
+            )
+        };
+    }
+}
+
 /// Logs a message using the logger backend.
 ///
 /// # Parameters
@@ -2832,6 +3403,41 @@ pub unsafe extern "C" fn glide_log(
 ///   If the string contains invalid UTF-8, an error will be returned instead of panicking.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn init(level: *const Level, file_name: *const c_char) -> *mut LogResult {
+    unsafe { init_with_config(level, file_name, std::ptr::null(), std::ptr::null()) }
+}
+
+/// Initializes the logger like [`init`], writing the logs in the given `format`, and rotating the log files and
+/// limiting their number as configured by `file_config`.
+///
+/// # Parameters
+///
+/// * `level` - A pointer to a `Level` enum value that sets the maximum log level. If null, a WARN level will be used.
+/// * `file_name` - A pointer to a null-terminated C string representing the desired log file path.
+/// * `format` - A pointer to the [`LogFormat`] of the logs. If null, the logs are written as text.
+/// * `file_config` - A pointer to a [`LogFileConfig`] for the log files. If null, the files are rotated hourly and all of them are kept.
+///   Ignored when `file_name` is null, as the logs are then written to the console.
+///
+/// # Returns
+///
+/// A pointer to a `LogResult` struct, as returned by [`init`]. `log_error` is set when the `rotation_value` of
+/// `INTERVAL` or `SIZE` rotation is 0, in which case the logger isn't changed.
+///
+/// # Safety
+///
+/// The returned pointer must be freed using [`free_log_result`].
+///
+/// * `level` may be null. If not null, it must point to a valid instance of the `Level` enum.
+/// * `file_name` may be null. If not null, it must point to a valid, null-terminated C string.
+///   If the string contains invalid UTF-8, an error will be returned instead of panicking.
+/// * `format` may be null. If not null, it must point to a valid instance of the [`LogFormat`] enum.
+/// * `file_config` may be null. If not null, it must point to a valid instance of [`LogFileConfig`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn init_with_config(
+    level: *const Level,
+    file_name: *const c_char,
+    format: *const LogFormat,
+    file_config: *const LogFileConfig,
+) -> *mut LogResult {
     let level_option = if level.is_null() {
         None
     } else {
@@ -2855,7 +3461,59 @@ pub unsafe extern "C" fn init(level: *const Level, file_name: *const c_char) ->
         }
     };
 
-    let logger_level = logger_core::init(level_option, file_name_option);
+    let format = if format.is_null() {
+        logger_core::LogFormat::Text
+    } else {
+        unsafe { *format }.into()
+    };
+    let file_config = if file_config.is_null() {
+        logger_core::LogFileConfig::default()
+    } else {
+        unsafe { *file_config }.into()
+    };
+    match logger_core::init_with_file_config(level_option, file_name_option, format, file_config) {
+        Ok(logger_level) => Box::into_raw(Box::new(LogResult {
+            log_error: std::ptr::null_mut(),
+            level: logger_level.into(),
+        })),
+        Err(err) => Box::into_raw(Box::new(LogResult {
+            log_error: CString::new(err).unwrap_or_default().into_raw(),
+            level: Level::OFF, // Default value, should be ignored when there's an error
+        })),
+    }
+}
+
+/// Passes the logs of the given level or above to `callback`, in addition to the console or file set by [`init`].
+///
+/// Calling [`init`] with an `OFF` level passes the logs only to the callback. A null `callback` stops passing the logs
+/// to the previous callback.
+///
+/// # Parameters
+///
+/// * `level` - A pointer to a `Level` enum value that sets the maximum log level. If null, a WARN level will be used.
+/// * `callback` - The [`LogCallback`] that receives the logs, or null.
+///
+/// # Returns
+///
+/// A pointer to a `LogResult` struct with the log level that was set, which must be freed using [`free_log_result`].
+///
+/// # Safety
+///
+/// * `level` may be null. If not null, it must point to a valid instance of the `Level` enum.
+/// * `callback` must live until the logs stop being passed to it, see [`LogCallback`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn set_log_callback(
+    level: *const Level,
+    callback: Option<LogCallback>,
+) -> *mut LogResult {
+    let level_option = if level.is_null() {
+        None
+    } else {
+        Some(unsafe { *level }.into())
+    };
+    let sink = callback
+        .map(|callback| Arc::new(CallbackLogSink(callback)) as Arc<dyn logger_core::LogSink>);
+    let logger_level = logger_core::set_log_sink(level_option, sink);
 
     Box::into_raw(Box::new(LogResult {
         log_error: std::ptr::null_mut(),
@@ -2873,7 +3531,8 @@ pub unsafe extern "C" fn init(level: *const Level, file_name: *const c_char) ->
 ///
 /// # Safety
 ///
-/// * `result_ptr` must be a valid pointer to a `LogResult` returned by [`glide_log`] or [`init`], or null.
+/// * `result_ptr` must be a valid pointer to a `LogResult` returned by [`glide_log`], [`init`], [`init_with_config`]
+///   or [`set_log_callback`], or null.
 /// * This function must be called exactly once for each `LogResult`.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn free_log_result(result_ptr: *mut LogResult) {
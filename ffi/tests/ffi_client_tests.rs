@@ -1,4 +1,6 @@
-use glide_core::connection_request::{ConnectionRequest, NodeAddress, TlsMode};
+use glide_core::connection_request::{
+    ConnectionRequest, ConnectionRetryStrategy, NodeAddress, PubSubChannelsOrPatterns, TlsMode,
+};
 use glide_core::errors::RequestErrorType;
 use glide_core::request_type::RequestType;
 use glide_ffi::*;
@@ -310,6 +312,177 @@ fn test_ffi_client_command_execution(#[values(false, true)] async_client: bool)
         close_client(client_ptr);
     }
 }
+#[rstest]
+fn test_ffi_client_runtime_modes(
+    #[values(RuntimeMode::Shared, RuntimeMode::Isolated)] runtime_mode: RuntimeMode,
+) {
+    let server = Server::new();
+    let connection_request_bytes = create_connection_request(server.port);
+    let client_type = Box::into_raw(Box::new(ClientType::SyncClient));
+    unsafe {
+        let response_ptr = create_client_with_runtime(
+            connection_request_bytes.as_ptr(),
+            connection_request_bytes.len(),
+            client_type,
+            std::mem::transmute::<
+                *mut c_void,
+                unsafe extern "C-unwind" fn(
+                    client_ptr: usize,
+                    kind: PushKind,
+                    message: *const u8,
+                    message_len: i64,
+                    channel: *const u8,
+                    channel_len: i64,
+                    pattern: *const u8,
+                    pattern_len: i64,
+                ),
+            >(std::ptr::null_mut()),
+            runtime_mode,
+        );
+        let client_ptr = (*response_ptr).conn_ptr;
+        assert!(!client_ptr.is_null(), "Failed to create client");
+
+        let ping_value = b"IS_WORKING";
+        let res = execute_command(client_ptr, 0, ping_value, 1_u64, RequestType::Ping)
+            .expect("Sync client should return the result");
+        assert_eq!(
+            get_sync_response(res.response),
+            String::from_utf8_lossy(ping_value)
+        );
+
+        if runtime_mode == RuntimeMode::Shared {
+            // The shared runtime is already running, so it can no longer be configured
+            let err = configure_shared_runtime(2);
+            assert!(!err.is_null());
+            free_c_string(err as *mut c_char);
+        }
+
+        free_connection_response(response_ptr as *mut ConnectionResponse);
+        close_client(client_ptr);
+        let _ = Box::from_raw(client_type);
+    }
+}
+
+/// Sends the created client's pointer, or the connection error, to the `Sender` that `request_id` points to.
+extern "C-unwind" fn client_created_callback(request_id: usize, client_adapter_ptr: *const c_void) {
+    let sender = unsafe { &*(request_id as *const std::sync::mpsc::Sender<Result<usize, String>>) };
+    sender.send(Ok(client_adapter_ptr.addr())).unwrap();
+}
+
+extern "C-unwind" fn client_creation_failed_callback(
+    request_id: usize,
+    error_message: *const c_char,
+) {
+    let sender = unsafe { &*(request_id as *const std::sync::mpsc::Sender<Result<usize, String>>) };
+    sender.send(Err(parse_error_msg(error_message))).unwrap();
+}
+
+#[test]
+fn test_ffi_client_async_creation() {
+    let server = Server::new();
+    let connection_request_bytes = create_connection_request(server.port);
+    let client_type = Box::into_raw(Box::new(ClientType::SyncClient));
+    let (sender, receiver) = std::sync::mpsc::channel::<Result<usize, String>>();
+    unsafe {
+        let creation_ptr = create_client_async(
+            connection_request_bytes.as_ptr(),
+            connection_request_bytes.len(),
+            client_type,
+            std::mem::transmute::<
+                *mut c_void,
+                unsafe extern "C-unwind" fn(
+                    client_ptr: usize,
+                    kind: PushKind,
+                    message: *const u8,
+                    message_len: i64,
+                    channel: *const u8,
+                    channel_len: i64,
+                    pattern: *const u8,
+                    pattern_len: i64,
+                ),
+            >(std::ptr::null_mut()),
+            &sender as *const _ as usize,
+            client_created_callback,
+            client_creation_failed_callback,
+        );
+        let client_ptr = receiver
+            .recv_timeout(Duration::from_secs(5))
+            .expect("The client creation should complete")
+            .expect("The client should connect") as *const c_void;
+        // The creation already completed, so it can no longer be cancelled
+        assert!(!cancel_client_creation(creation_ptr));
+        free_client_creation(creation_ptr);
+
+        let ping_value = b"IS_WORKING";
+        let res = execute_command(client_ptr, 0, ping_value, 1_u64, RequestType::Ping)
+            .expect("Sync client should return the result");
+        assert_eq!(
+            get_sync_response(res.response),
+            String::from_utf8_lossy(ping_value)
+        );
+
+        close_client(client_ptr);
+        let _ = Box::from_raw(client_type);
+    }
+}
+
+#[test]
+fn test_ffi_client_async_creation_cancelled() {
+    // The listener accepts the client's connection, but never answers it, so the creation stays pending.
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let mut request = ConnectionRequest::parse_from_bytes(&create_connection_request(
+        listener.local_addr().unwrap().port(),
+    ))
+    .unwrap();
+    request.connection_timeout = 10_000;
+    let mut retry_strategy = ConnectionRetryStrategy::new();
+    retry_strategy.number_of_retries = 1;
+    retry_strategy.factor = 10;
+    retry_strategy.exponent_base = 2;
+    request.connection_retry_strategy = Some(retry_strategy).into();
+    let connection_request_bytes = request.write_to_bytes().unwrap();
+    let client_type = Box::into_raw(Box::new(ClientType::SyncClient));
+    let (sender, receiver) = std::sync::mpsc::channel::<Result<usize, String>>();
+    unsafe {
+        let creation_ptr = create_client_async(
+            connection_request_bytes.as_ptr(),
+            connection_request_bytes.len(),
+            client_type,
+            std::mem::transmute::<
+                *mut c_void,
+                unsafe extern "C-unwind" fn(
+                    client_ptr: usize,
+                    kind: PushKind,
+                    message: *const u8,
+                    message_len: i64,
+                    channel: *const u8,
+                    channel_len: i64,
+                    pattern: *const u8,
+                    pattern_len: i64,
+                ),
+            >(std::ptr::null_mut()),
+            &sender as *const _ as usize,
+            client_created_callback,
+            client_creation_failed_callback,
+        );
+        let (connection, _) = listener.accept().unwrap();
+        assert!(cancel_client_creation(creation_ptr));
+        // The creation was already cancelled
+        assert!(!cancel_client_creation(creation_ptr));
+        free_client_creation(creation_ptr);
+
+        // The address becomes unreachable, which would fail a creation that wasn't cancelled within milliseconds.
+        drop(connection);
+        drop(listener);
+        let result = receiver.recv_timeout(Duration::from_secs(1));
+        assert!(
+            result.is_err(),
+            "No callback should be called after the creation was cancelled, got {result:?}"
+        );
+        let _ = Box::from_raw(client_type);
+    }
+}
+
 #[test]
 fn test_create_otel_span_with_parent() {
     // Test creating a parent span
@@ -423,3 +596,265 @@ fn test_create_named_otel_span() {
         drop_otel_span(child_span_ptr);
     }
 }
+
+type ReceivedPush = (usize, u32, Option<Vec<u8>>, Option<Vec<u8>>);
+
+lazy_static! {
+    static ref RECEIVED_PUSHES: std::sync::Mutex<Vec<ReceivedPush>> =
+        std::sync::Mutex::new(Vec::new());
+}
+
+fn optional_bytes(ptr: *const u8, len: i64) -> Option<Vec<u8>> {
+    (!ptr.is_null()).then(|| unsafe { std::slice::from_raw_parts(ptr, len as usize) }.to_vec())
+}
+
+/// Records the kind, channel and message of the push notifications received by each client
+extern "C-unwind" fn recording_pubsub_callback(
+    client_ptr: usize,
+    kind: PushKind,
+    message: *const u8,
+    message_len: i64,
+    channel: *const u8,
+    channel_len: i64,
+    _pattern: *const u8,
+    _pattern_len: i64,
+) {
+    RECEIVED_PUSHES.lock().unwrap().push((
+        client_ptr,
+        kind as u32,
+        optional_bytes(channel, channel_len),
+        optional_bytes(message, message_len),
+    ));
+}
+
+fn wait_for_push(client_ptr: *const c_void, expected: (PushKind, Option<&[u8]>, Option<&[u8]>)) {
+    let expected = (
+        client_ptr.addr(),
+        expected.0 as u32,
+        expected.1.map(<[u8]>::to_vec),
+        expected.2.map(<[u8]>::to_vec),
+    );
+    for _ in 0..500 {
+        if RECEIVED_PUSHES.lock().unwrap().contains(&expected) {
+            return;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    panic!("Push notification {expected:?} wasn't received");
+}
+
+fn execute_command_with_args(
+    client_ptr: *const c_void,
+    args: &[&[u8]],
+    command_type: RequestType,
+) -> Box<CommandResult> {
+    let args_ptrs: Vec<*const u8> = args.iter().map(|arg| arg.as_ptr()).collect();
+    let args_lens: Vec<c_ulong> = args.iter().map(|arg| arg.len() as c_ulong).collect();
+    let command_res_ptr = unsafe {
+        command(
+            client_ptr,
+            0,
+            command_type,
+            args.len() as c_ulong,
+            args_ptrs.as_ptr() as *const usize,
+            args_lens.as_ptr(),
+            std::ptr::null(),
+            0,
+            0,
+        )
+    };
+    assert!(!command_res_ptr.is_null());
+    unsafe { Box::from_raw(command_res_ptr) }
+}
+
+#[test]
+fn test_ffi_client_push_kind_filter() {
+    let server = Server::new();
+    let client_type = Box::into_raw(Box::new(ClientType::SyncClient));
+    let publisher_request_bytes = create_connection_request(server.port);
+    let mut subscriber_request =
+        ConnectionRequest::parse_from_bytes(&create_connection_request(server.port)).unwrap();
+    let mut channels = PubSubChannelsOrPatterns::new();
+    channels
+        .channels_or_patterns
+        .push(b"channel".to_vec().into());
+    subscriber_request
+        .pubsub_subscriptions
+        .mut_or_insert_default()
+        .channels_or_patterns_by_type
+        .insert(0, channels);
+    let subscriber_request_bytes = subscriber_request.write_to_bytes().unwrap();
+    unsafe {
+        let subscriber_response = create_client(
+            subscriber_request_bytes.as_ptr(),
+            subscriber_request_bytes.len(),
+            client_type,
+            recording_pubsub_callback,
+        );
+        let subscriber_ptr = (*subscriber_response).conn_ptr;
+        assert!(!subscriber_ptr.is_null(), "Failed to create subscriber");
+        let publisher_response = create_client(
+            publisher_request_bytes.as_ptr(),
+            publisher_request_bytes.len(),
+            client_type,
+            recording_pubsub_callback,
+        );
+        let publisher_ptr = (*publisher_response).conn_ptr;
+        assert!(!publisher_ptr.is_null(), "Failed to create publisher");
+
+        set_push_kind_filter(subscriber_ptr, u32::MAX);
+        execute_command_with_args(
+            publisher_ptr,
+            &[b"channel", b"message"],
+            RequestType::Publish,
+        );
+        wait_for_push(
+            subscriber_ptr,
+            (PushKind::PushMessage, Some(b"channel"), Some(b"message")),
+        );
+
+        // The subscriber is notified when it loses its connection, and when it's subscribed again
+        execute_command_with_args(
+            publisher_ptr,
+            &[b"CLIENT", b"KILL", b"TYPE", b"pubsub"],
+            RequestType::CustomCommand,
+        );
+        wait_for_push(subscriber_ptr, (PushKind::PushDisconnection, None, None));
+        wait_for_push(
+            subscriber_ptr,
+            (PushKind::PushSubscribe, Some(b"channel"), Some(b"1")),
+        );
+
+        free_connection_response(publisher_response as *mut ConnectionResponse);
+        free_connection_response(subscriber_response as *mut ConnectionResponse);
+        close_client(publisher_ptr);
+        close_client(subscriber_ptr);
+        let _ = Box::from_raw(client_type);
+    }
+}
+
+#[test]
+fn test_ffi_client_closed_subscriber_on_shared_runtime_gets_no_pushes() {
+    let server = Server::new();
+    let client_type = Box::into_raw(Box::new(ClientType::SyncClient));
+    let publisher_request_bytes = create_connection_request(server.port);
+    let mut subscriber_request =
+        ConnectionRequest::parse_from_bytes(&create_connection_request(server.port)).unwrap();
+    let mut channels = PubSubChannelsOrPatterns::new();
+    channels
+        .channels_or_patterns
+        .push(b"closed_channel".to_vec().into());
+    subscriber_request
+        .pubsub_subscriptions
+        .mut_or_insert_default()
+        .channels_or_patterns_by_type
+        .insert(0, channels);
+    let subscriber_request_bytes = subscriber_request.write_to_bytes().unwrap();
+    unsafe {
+        let subscriber_response = create_client_with_runtime(
+            subscriber_request_bytes.as_ptr(),
+            subscriber_request_bytes.len(),
+            client_type,
+            recording_pubsub_callback,
+            RuntimeMode::Shared,
+        );
+        let subscriber_ptr = (*subscriber_response).conn_ptr;
+        assert!(!subscriber_ptr.is_null(), "Failed to create subscriber");
+        let publisher_response = create_client(
+            publisher_request_bytes.as_ptr(),
+            publisher_request_bytes.len(),
+            client_type,
+            recording_pubsub_callback,
+        );
+        let publisher_ptr = (*publisher_response).conn_ptr;
+        assert!(!publisher_ptr.is_null(), "Failed to create publisher");
+
+        execute_command_with_args(
+            publisher_ptr,
+            &[b"closed_channel", b"before close"],
+            RequestType::Publish,
+        );
+        wait_for_push(
+            subscriber_ptr,
+            (
+                PushKind::PushMessage,
+                Some(b"closed_channel"),
+                Some(b"before close"),
+            ),
+        );
+
+        // The shared runtime keeps running, but the closed client's push task must not call the callback anymore
+        free_connection_response(subscriber_response as *mut ConnectionResponse);
+        close_client(subscriber_ptr);
+        execute_command_with_args(
+            publisher_ptr,
+            &[b"closed_channel", b"after close"],
+            RequestType::Publish,
+        );
+        std::thread::sleep(Duration::from_millis(200));
+        assert!(
+            !RECEIVED_PUSHES
+                .lock()
+                .unwrap()
+                .iter()
+                .any(
+                    |(client_ptr, _, _, message)| *client_ptr == subscriber_ptr.addr()
+                        && message.as_deref() == Some(b"after close".as_slice())
+                ),
+        );
+
+        free_connection_response(publisher_response as *mut ConnectionResponse);
+        close_client(publisher_ptr);
+        let _ = Box::from_raw(client_type);
+    }
+}
+
+#[test]
+fn test_ffi_client_without_initial_subscriptions_gets_pushes() {
+    let server = Server::new();
+    let client_type = Box::into_raw(Box::new(ClientType::SyncClient));
+    let request_bytes = create_connection_request(server.port);
+    unsafe {
+        let subscriber_response = create_client(
+            request_bytes.as_ptr(),
+            request_bytes.len(),
+            client_type,
+            recording_pubsub_callback,
+        );
+        let subscriber_ptr = (*subscriber_response).conn_ptr;
+        assert!(!subscriber_ptr.is_null(), "Failed to create subscriber");
+        let publisher_response = create_client(
+            request_bytes.as_ptr(),
+            request_bytes.len(),
+            client_type,
+            recording_pubsub_callback,
+        );
+        let publisher_ptr = (*publisher_response).conn_ptr;
+        assert!(!publisher_ptr.is_null(), "Failed to create publisher");
+
+        execute_command_with_args(
+            subscriber_ptr,
+            &[b"runtime_channel"],
+            RequestType::Subscribe,
+        );
+        execute_command_with_args(
+            publisher_ptr,
+            &[b"runtime_channel", b"message"],
+            RequestType::Publish,
+        );
+        wait_for_push(
+            subscriber_ptr,
+            (
+                PushKind::PushMessage,
+                Some(b"runtime_channel"),
+                Some(b"message"),
+            ),
+        );
+
+        free_connection_response(publisher_response as *mut ConnectionResponse);
+        free_connection_response(subscriber_response as *mut ConnectionResponse);
+        close_client(publisher_ptr);
+        close_client(subscriber_ptr);
+        let _ = Box::from_raw(client_type);
+    }
+}
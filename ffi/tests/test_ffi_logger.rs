@@ -0,0 +1,181 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use glide_ffi::{
+    Level, LogField, LogFileConfig, LogFormat, LogResult, LogRotation, free_log_result, glide_log,
+    init_with_config, set_log_callback,
+};
+use lazy_static::lazy_static;
+use std::ffi::{CStr, CString};
+use std::path::Path;
+
+type ReceivedLog = (Level, String, String, Vec<(String, String)>);
+
+lazy_static! {
+    static ref RECEIVED_LOGS: std::sync::Mutex<Vec<ReceivedLog>> =
+        std::sync::Mutex::new(Vec::new());
+}
+
+fn to_string(ptr: *const u8, len: usize) -> String {
+    String::from_utf8(unsafe { std::slice::from_raw_parts(ptr, len) }.to_vec()).unwrap()
+}
+
+/// Records the level, identifier, message and fields of the received logs
+#[allow(clippy::too_many_arguments)]
+unsafe extern "C-unwind" fn recording_log_callback(
+    level: Level,
+    _target: *const u8,
+    _target_len: usize,
+    identifier: *const u8,
+    identifier_len: usize,
+    message: *const u8,
+    message_len: usize,
+    fields: *const LogField,
+    fields_len: usize,
+) {
+    let fields = unsafe { std::slice::from_raw_parts(fields, fields_len) }
+        .iter()
+        .map(|field| {
+            (
+                to_string(field.name, field.name_len),
+                to_string(field.value, field.value_len),
+            )
+        })
+        .collect();
+    RECEIVED_LOGS.lock().unwrap().push((
+        level,
+        to_string(identifier, identifier_len),
+        to_string(message, message_len),
+        fields,
+    ));
+}
+
+fn log(level: Level, identifier: &str, message: &str) {
+    let identifier = CString::new(identifier).unwrap();
+    let message = CString::new(message).unwrap();
+    let result = unsafe { glide_log(level, identifier.as_ptr(), message.as_ptr()) };
+    assert!(unsafe { &*result }.log_error.is_null());
+    unsafe { free_log_result(result) };
+}
+
+fn check_log_result(result: *mut LogResult, expected_level: Level) {
+    let log_result = unsafe { &*result };
+    assert!(log_result.log_error.is_null());
+    assert_eq!(log_result.level, expected_level);
+    unsafe { free_log_result(result) };
+}
+
+#[test]
+#[serial_test::serial]
+fn test_log_callback_receives_logs_of_its_level() {
+    check_log_result(
+        unsafe { set_log_callback(&Level::INFO, Some(recording_log_callback)) },
+        Level::INFO,
+    );
+    log(Level::INFO, "callback_test", "info message");
+    log(Level::DEBUG, "callback_test", "debug message");
+    check_log_result(
+        unsafe { set_log_callback(std::ptr::null(), None) },
+        Level::WARN,
+    );
+    log(Level::ERROR, "callback_test", "message after reset");
+
+    let received: Vec<_> = RECEIVED_LOGS
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|(_, identifier, _, _)| identifier == "callback_test")
+        .cloned()
+        .collect();
+    assert_eq!(
+        received,
+        vec![(
+            Level::INFO,
+            "callback_test".to_string(),
+            "info message".to_string(),
+            vec![]
+        )]
+    );
+}
+
+#[test]
+#[serial_test::serial]
+fn test_json_log_format() {
+    let file_name = CString::new("ffi_json_format.log").unwrap();
+    check_log_result(
+        unsafe {
+            init_with_config(
+                &Level::INFO,
+                file_name.as_ptr(),
+                &LogFormat::JSON,
+                std::ptr::null(),
+            )
+        },
+        Level::INFO,
+    );
+    log(Level::INFO, "json_test", "json message");
+
+    let log_file = std::fs::read_dir(Path::new("glide-logs"))
+        .unwrap()
+        .filter_map(Result::ok)
+        .find(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .starts_with("ffi_json_format.log")
+        })
+        .expect("The log file wasn't created")
+        .path();
+    let contents = std::fs::read_to_string(log_file).unwrap();
+    let log_line: serde_json::Value = contents
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .find(|log: &serde_json::Value| log["identifier"] == "json_test")
+        .unwrap_or_else(|| panic!("No JSON log in {contents}"));
+    assert_eq!(log_line["level"], "INFO");
+    assert_eq!(log_line["message"], "json message");
+}
+
+#[test]
+#[serial_test::serial]
+fn test_zero_rotation_value_is_rejected() {
+    let file_name = CString::new("ffi_zero_rotation.log").unwrap();
+    for rotation in [LogRotation::INTERVAL, LogRotation::SIZE] {
+        let file_config = LogFileConfig {
+            rotation,
+            rotation_value: 0,
+            max_files: 0,
+            compress: false,
+        };
+        let result = unsafe {
+            init_with_config(
+                &Level::INFO,
+                file_name.as_ptr(),
+                std::ptr::null(),
+                &file_config,
+            )
+        };
+        let log_error = unsafe { &*result }.log_error;
+        assert!(!log_error.is_null(), "{rotation:?} with 0 wasn't rejected");
+        let error = unsafe { CStr::from_ptr(log_error) }.to_str().unwrap();
+        assert!(error.contains("must be positive"), "Error: {error}");
+        unsafe { free_log_result(result) };
+    }
+
+    let file_config = LogFileConfig {
+        rotation: LogRotation::SIZE,
+        rotation_value: 1024,
+        max_files: 0,
+        compress: false,
+    };
+    check_log_result(
+        unsafe {
+            init_with_config(
+                &Level::INFO,
+                file_name.as_ptr(),
+                std::ptr::null(),
+                &file_config,
+            )
+        },
+        Level::INFO,
+    );
+}
@@ -1,7 +1,7 @@
 //! Adds async IO support to redis.
 use crate::cmd::{cmd, Cmd};
 use crate::connection::{
-    get_resp3_hello_command_error, PubSubSubscriptionKind, RedisConnectionInfo,
+    get_resp3_hello_command_error, ClientTrackingMode, PubSubSubscriptionKind, RedisConnectionInfo,
 };
 use crate::pipeline::PipelineRetryStrategy;
 use crate::types::{
@@ -94,6 +94,39 @@ pub trait ConnectionLike {
     fn set_az(&mut self, _az: Option<String>) {}
 }
 
+/// The number of commands sent for a command with surrounding commands.
+const SURROUNDED_COMMAND_COUNT: usize = 3;
+
+/// Sends `cmd` on `con`, together with its surrounding commands if it has any, and returns the response of `cmd`.
+///
+/// The surrounding commands are pipelined with `cmd`, so that the server executes them right before and right after it.
+/// Important - this function is meant for internal usage by glide-core, which sends its standalone commands with it.
+#[doc(hidden)]
+pub async fn req_command_with_surroundings<C>(con: &mut C, cmd: &Cmd) -> RedisResult<Value>
+where
+    C: ConnectionLike + ?Sized,
+{
+    let Some((before, after)) = cmd.surrounding_commands() else {
+        return con.req_packed_command(cmd).await;
+    };
+    let mut pipeline = crate::Pipeline::with_capacity(SURROUNDED_COMMAND_COUNT);
+    pipeline
+        .add_command(before.clone())
+        .add_command(cmd.clone())
+        .add_command(after.clone());
+    let values = con
+        .req_packed_commands(&pipeline, 0, SURROUNDED_COMMAND_COUNT, None)
+        .await?;
+    match values.into_iter().nth(1) {
+        Some(Value::ServerError(err)) => Err(err.into()),
+        Some(value) => Ok(value),
+        None => Err(RedisError::from((
+            ErrorKind::ResponseError,
+            "Received too few responses for a surrounded command",
+        ))),
+    }
+}
+
 /// Implements ability to notify about disconnection events
 #[async_trait]
 pub trait DisconnectNotifier: Send + Sync {
@@ -232,10 +265,26 @@ where
             .query_async(con)
             .await;
 
-    // resubscribe
     if connection_info.protocol != ProtocolVersion::RESP3 {
         return Ok(());
     }
+
+    if let Some(client_tracking) = connection_info.client_tracking {
+        let mut command = cmd("CLIENT");
+        command.arg("TRACKING").arg("ON");
+        if client_tracking == ClientTrackingMode::Broadcast {
+            command.arg("BCAST");
+        }
+        match command.query_async(con).await {
+            Ok(Value::Okay) => {}
+            _ => fail!((
+                ErrorKind::ResponseError,
+                "Redis server refused to enable client tracking"
+            )),
+        }
+    }
+
+    // resubscribe
     static KIND_TO_COMMAND: [(PubSubSubscriptionKind, &str); 3] = [
         (PubSubSubscriptionKind::Exact, "SUBSCRIBE"),
         (PubSubSubscriptionKind::Pattern, "PSUBSCRIBE"),
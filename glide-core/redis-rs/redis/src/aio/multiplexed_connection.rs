@@ -524,6 +524,15 @@ impl MultiplexedConnection {
             }
         };
 
+        if connection_info.redis.client_tracking.is_some() {
+            // Invalidations of keys that were tracked by a previous connection might have been missed,
+            // so cached values are flushed the same way the server requests it after FLUSHALL.
+            con.push_manager.try_send_raw(&Value::Push {
+                kind: PushKind::Invalidate,
+                data: vec![Value::Nil],
+            });
+        }
+
         Ok((con, driver))
     }
 
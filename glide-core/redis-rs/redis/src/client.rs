@@ -4,7 +4,10 @@ use std::time::Duration;
 use crate::aio::DisconnectNotifier;
 
 use crate::{
-    connection::{connect, Connection, ConnectionInfo, ConnectionLike, IntoConnectionInfo},
+    connection::{
+        add_pubsub_subscriptions, connect, remove_pubsub_subscriptions, Connection, ConnectionInfo,
+        ConnectionLike, IntoConnectionInfo, PubSubChannelOrPattern, PubSubSubscriptionKind,
+    },
     push_manager::PushInfo,
     retry_strategies::RetryStrategy,
     types::{RedisResult, Value},
@@ -588,6 +591,37 @@ impl Client {
     pub fn update_client_name(&mut self, client_name: Option<String>) {
         self.connection_info.redis.client_name = client_name;
     }
+
+    /// Adds pubsub subscriptions to connection_info, which will be restored when connecting.
+    pub fn add_pubsub_subscriptions(
+        &mut self,
+        kind: PubSubSubscriptionKind,
+        channels_or_patterns: &[PubSubChannelOrPattern],
+    ) {
+        add_pubsub_subscriptions(
+            self.connection_info
+                .redis
+                .pubsub_subscriptions
+                .get_or_insert_with(Default::default),
+            kind,
+            channels_or_patterns,
+        );
+    }
+
+    /// Removes pubsub subscriptions from connection_info. If `channels_or_patterns` is empty, all subscriptions of `kind` are removed.
+    /// Returns the removed channels/patterns.
+    pub fn remove_pubsub_subscriptions(
+        &mut self,
+        kind: PubSubSubscriptionKind,
+        channels_or_patterns: &[PubSubChannelOrPattern],
+    ) -> Vec<PubSubChannelOrPattern> {
+        match self.connection_info.redis.pubsub_subscriptions.as_mut() {
+            Some(subscriptions) => {
+                remove_pubsub_subscriptions(subscriptions, kind, channels_or_patterns)
+            }
+            None => Vec::new(),
+        }
+    }
 }
 
 #[cfg(feature = "aio")]
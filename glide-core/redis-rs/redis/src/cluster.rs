@@ -35,7 +35,7 @@
 //!     .expire(key, 60).ignore()
 //!     .query(&mut connection).unwrap();
 //! ```
-pub use crate::cluster_client::{ClusterClient, ClusterClientBuilder};
+pub use crate::cluster_client::{AddressMapper, ClusterClient, ClusterClientBuilder};
 use crate::cluster_pipeline::UNROUTABLE_ERROR;
 pub use crate::cluster_pipeline::{cluster_pipe, ClusterPipeline};
 use crate::cluster_routing::{
@@ -382,7 +382,13 @@ where
                 ErrorKind::ClientError,
                 "can't parse node address",
             )))?;
-            match parse_and_count_slots(&value, self.cluster_params.tls, addr).map(|slots_data| {
+            match parse_and_count_slots(
+                &value,
+                self.cluster_params.tls,
+                addr,
+                self.cluster_params.address_mapper.as_deref(),
+            )
+            .map(|slots_data| {
                 SlotMap::new(slots_data.1, self.cluster_params.read_from_replicas.clone())
             }) {
                 Ok(new_slots) => {
@@ -709,6 +715,7 @@ where
                         Redirect::Moved(addr) => (addr, false),
                         Redirect::Ask(addr, should_exec_asking) => (addr, should_exec_asking),
                     };
+                    let addr = self.cluster_params.map_address(&addr);
                     let conn = self.get_connection_by_addr(&mut connections, &addr)?;
                     if is_asking {
                         // if we are in asking mode we want to feed a single
@@ -995,6 +1002,7 @@ pub(crate) fn get_connection_info(
             protocol: cluster_params.protocol,
             db: cluster_params.database_id,
             pubsub_subscriptions: cluster_params.pubsub_subscriptions,
+            client_tracking: cluster_params.client_tracking,
         },
     })
 }
@@ -1028,6 +1036,24 @@ pub(crate) fn slot_cmd() -> Cmd {
     cmd
 }
 
+#[cfg(feature = "cluster-async")]
+pub(crate) fn shards_cmd() -> Cmd {
+    let mut cmd = Cmd::new();
+    cmd.arg("CLUSTER").arg("SHARDS");
+    cmd
+}
+
+/// Returns `true` if `err` shows that `CLUSTER SHARDS` can't be used to discover the topology, since the server
+/// doesn't know the command or the user isn't permitted to run it.
+#[cfg(feature = "cluster-async")]
+pub(crate) fn is_shards_cmd_unsupported(err: &RedisError) -> bool {
+    err.code() == Some("NOPERM")
+        || err.detail().is_some_and(|detail| {
+            let detail = detail.to_ascii_lowercase();
+            detail.contains("unknown command") || detail.contains("unknown subcommand")
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1070,4 +1096,33 @@ mod tests {
             );
         }
     }
+
+    #[cfg(feature = "cluster-async")]
+    #[test]
+    fn shards_cmd_unsupported_errors() {
+        let server_error = |response: &[u8]| -> RedisError {
+            match parse_redis_value(response) {
+                Ok(Value::ServerError(err)) => err.into(),
+                other => panic!("Expected a server error, got {other:?}"),
+            }
+        };
+
+        for response in [
+            b"-ERR unknown subcommand 'SHARDS'\r\n".as_slice(),
+            b"-ERR unknown command 'CLUSTER', with args beginning with: 'SHARDS'\r\n",
+            b"-NOPERM User default has no permissions to run the 'cluster|shards' command\r\n",
+        ] {
+            assert!(is_shards_cmd_unsupported(&server_error(response)));
+        }
+
+        assert!(!is_shards_cmd_unsupported(&server_error(
+            b"-LOADING Valkey is loading the dataset in memory\r\n"
+        )));
+        assert!(!is_shards_cmd_unsupported(&server_error(
+            b"-ERR This instance has cluster support disabled\r\n"
+        )));
+        assert!(!is_shards_cmd_unsupported(&RedisError::from(
+            std::io::Error::from(std::io::ErrorKind::ConnectionReset)
+        )));
+    }
 }
@@ -0,0 +1,384 @@
+//! Per-node circuit breaker used by the cluster connection.
+//!
+//! Each node starts in the `Closed` state. After `failure_threshold` consecutive failures within
+//! `failure_window`, the node's circuit opens and requests to it fail fast with
+//! [`ErrorKind::CircuitOpen`], or are routed to another node of the shard when they're reads.
+//! Once `open_duration` passes, a single probe request is let through (`HalfOpen`): its success
+//! closes the circuit, and its failure opens it again.
+//! A request that doesn't complete, e.g. because it timed out, counts as a failure.
+
+use crate::{ErrorKind, RedisError, RedisResult};
+use dashmap::DashMap;
+use logger_core::log_warn;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use telemetrylib::{CircuitBreakerState, GlideOpenTelemetry};
+use tracing::debug;
+
+/// Configuration of the per-node circuit breaker.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CircuitBreakerConfig {
+    /// Number of consecutive failures after which the circuit of a node opens.
+    pub failure_threshold: u32,
+    /// The window in which the consecutive failures must occur for the circuit to open.
+    pub failure_window: Duration,
+    /// How long the circuit stays open before a probe request is allowed to the node.
+    pub open_duration: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            failure_window: Duration::from_secs(10),
+            open_duration: Duration::from_secs(5),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum CircuitState {
+    Closed {
+        consecutive_failures: u32,
+        first_failure_at: Option<Instant>,
+    },
+    Open {
+        since: Instant,
+    },
+    /// The probe request is in flight.
+    HalfOpen,
+}
+
+impl CircuitState {
+    fn closed() -> Self {
+        CircuitState::Closed {
+            consecutive_failures: 0,
+            first_failure_at: None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            CircuitState::Closed { .. } => "closed",
+            CircuitState::Open { .. } => "open",
+            CircuitState::HalfOpen => "half_open",
+        }
+    }
+
+    fn telemetry_state(&self) -> CircuitBreakerState {
+        match self {
+            CircuitState::Closed { .. } => CircuitBreakerState::Closed,
+            CircuitState::Open { .. } => CircuitBreakerState::Open,
+            CircuitState::HalfOpen => CircuitBreakerState::HalfOpen,
+        }
+    }
+}
+
+/// Tracks the circuit state of every node in the cluster.
+pub(crate) struct CircuitBreakers {
+    config: CircuitBreakerConfig,
+    states: DashMap<String, CircuitState>,
+}
+
+impl CircuitBreakers {
+    pub(crate) fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            states: DashMap::new(),
+        }
+    }
+
+    /// Returns the current circuit state of the node in `address`.
+    pub(crate) fn state(&self, address: &str) -> CircuitState {
+        self.states
+            .get(address)
+            .map(|state| *state.value())
+            .unwrap_or_else(CircuitState::closed)
+    }
+
+    /// Returns true if a request to the node in `address` would currently be let through.
+    /// Unlike [`Self::try_acquire`], this doesn't change the state of the circuit.
+    pub(crate) fn is_available(&self, address: &str) -> bool {
+        match self.state(address) {
+            CircuitState::Closed { .. } => true,
+            CircuitState::Open { since } => since.elapsed() >= self.config.open_duration,
+            CircuitState::HalfOpen => false,
+        }
+    }
+
+    /// Reserves a request to the node in `address`. If the circuit is open, fails fast with
+    /// [`ErrorKind::CircuitOpen`], unless the open duration has passed, in which case the request
+    /// is used as the half-open probe.
+    /// The outcome of the request must be reported through the returned permit.
+    pub(crate) fn try_acquire(self: &Arc<Self>, address: &str) -> RedisResult<CircuitPermit> {
+        let mut transition = None;
+        {
+            let mut state = self
+                .states
+                .entry(address.to_string())
+                .or_insert_with(CircuitState::closed);
+            match *state {
+                CircuitState::Closed { .. } => {}
+                CircuitState::Open { since } if since.elapsed() >= self.config.open_duration => {
+                    *state = CircuitState::HalfOpen;
+                    transition = Some(*state);
+                }
+                CircuitState::Open { .. } | CircuitState::HalfOpen => {
+                    return Err(RedisError::from((
+                        ErrorKind::CircuitOpen,
+                        "Circuit breaker is open for node",
+                        address.to_string(),
+                    )));
+                }
+            }
+        }
+        if let Some(state) = transition {
+            Self::report_transition(address, state);
+        }
+        Ok(CircuitPermit {
+            breakers: self.clone(),
+            address: address.to_string(),
+            completed: false,
+        })
+    }
+
+    /// Removes the state of every node for which `keep` returns false.
+    pub(crate) fn retain(&self, mut keep: impl FnMut(&str) -> bool) {
+        self.states.retain(|address, _| keep(address));
+    }
+
+    fn record_success(&self, address: &str) {
+        let transition = {
+            let Some(mut state) = self.states.get_mut(address) else {
+                return;
+            };
+            match *state {
+                // Requests that were sent before the circuit opened don't close it, only the probe does.
+                CircuitState::Open { .. } => None,
+                CircuitState::Closed { .. } => {
+                    *state = CircuitState::closed();
+                    None
+                }
+                CircuitState::HalfOpen => {
+                    *state = CircuitState::closed();
+                    Some(*state)
+                }
+            }
+        };
+        if let Some(state) = transition {
+            Self::report_transition(address, state);
+        }
+    }
+
+    fn record_failure(&self, address: &str) {
+        let now = Instant::now();
+        let transition = {
+            let mut state = self
+                .states
+                .entry(address.to_string())
+                .or_insert_with(CircuitState::closed);
+            match *state {
+                CircuitState::Open { .. } => None,
+                CircuitState::HalfOpen => {
+                    *state = CircuitState::Open { since: now };
+                    Some(*state)
+                }
+                CircuitState::Closed {
+                    consecutive_failures,
+                    first_failure_at,
+                } => {
+                    let (consecutive_failures, first_failure_at) = match first_failure_at {
+                        Some(first) if now.duration_since(first) <= self.config.failure_window => {
+                            (consecutive_failures.saturating_add(1), first)
+                        }
+                        _ => (1, now),
+                    };
+                    if consecutive_failures >= self.config.failure_threshold {
+                        *state = CircuitState::Open { since: now };
+                        Some(*state)
+                    } else {
+                        *state = CircuitState::Closed {
+                            consecutive_failures,
+                            first_failure_at: Some(first_failure_at),
+                        };
+                        None
+                    }
+                }
+            }
+        };
+        if let Some(state) = transition {
+            Self::report_transition(address, state);
+        }
+    }
+
+    fn report_transition(address: &str, state: CircuitState) {
+        debug!("circuit breaker of node {address} is now {}", state.name());
+        if let Err(e) =
+            GlideOpenTelemetry::record_circuit_breaker_state(address, state.telemetry_state())
+        {
+            log_warn(
+                "OpenTelemetry:circuit_breaker_error",
+                format!("Failed to record circuit breaker state: {e}"),
+            );
+        }
+    }
+}
+
+/// A request that was let through the circuit breaker of a node.
+/// A permit that is dropped without reporting an outcome, e.g. because the request timed out, counts as a failure.
+pub(crate) struct CircuitPermit {
+    breakers: Arc<CircuitBreakers>,
+    address: String,
+    completed: bool,
+}
+
+impl CircuitPermit {
+    /// Reports the outcome of the request. Only connection and IO errors, which indicate the node
+    /// itself is unhealthy, count as failures.
+    pub(crate) fn complete<T>(mut self, result: &RedisResult<T>) {
+        self.completed = true;
+        match result {
+            Err(err) if err.is_io_error() || err.is_connection_dropped() => {
+                self.breakers.record_failure(&self.address)
+            }
+            _ => self.breakers.record_success(&self.address),
+        }
+    }
+}
+
+impl Drop for CircuitPermit {
+    fn drop(&mut self) {
+        if !self.completed {
+            self.breakers.record_failure(&self.address);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+
+    const ADDRESS: &str = "node1:6379";
+
+    fn breakers(failure_threshold: u32, open_duration: Duration) -> Arc<CircuitBreakers> {
+        Arc::new(CircuitBreakers::new(CircuitBreakerConfig {
+            failure_threshold,
+            failure_window: Duration::from_secs(60),
+            open_duration,
+        }))
+    }
+
+    fn io_failure() -> RedisResult<()> {
+        Err(io::Error::from(io::ErrorKind::ConnectionReset).into())
+    }
+
+    #[test]
+    fn circuit_opens_after_consecutive_failures() {
+        let breakers = breakers(3, Duration::from_secs(60));
+        for _ in 0..2 {
+            breakers
+                .try_acquire(ADDRESS)
+                .unwrap()
+                .complete(&io_failure());
+        }
+        assert!(breakers.is_available(ADDRESS));
+
+        breakers
+            .try_acquire(ADDRESS)
+            .unwrap()
+            .complete(&io_failure());
+        assert!(matches!(breakers.state(ADDRESS), CircuitState::Open { .. }));
+        assert!(!breakers.is_available(ADDRESS));
+        let err = breakers.try_acquire(ADDRESS).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::CircuitOpen);
+    }
+
+    #[test]
+    fn success_resets_consecutive_failures() {
+        let breakers = breakers(2, Duration::from_secs(60));
+        breakers
+            .try_acquire(ADDRESS)
+            .unwrap()
+            .complete(&io_failure());
+        breakers.try_acquire(ADDRESS).unwrap().complete(&Ok(()));
+        breakers
+            .try_acquire(ADDRESS)
+            .unwrap()
+            .complete(&io_failure());
+        assert!(matches!(
+            breakers.state(ADDRESS),
+            CircuitState::Closed {
+                consecutive_failures: 1,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn server_errors_are_not_failures() {
+        let breakers = breakers(1, Duration::from_secs(60));
+        let server_error: RedisResult<()> = Err((ErrorKind::TypeError, "WRONGTYPE").into());
+        breakers
+            .try_acquire(ADDRESS)
+            .unwrap()
+            .complete(&server_error);
+        assert!(breakers.is_available(ADDRESS));
+    }
+
+    #[test]
+    fn dropped_permit_is_a_failure() {
+        let breakers = breakers(1, Duration::from_secs(60));
+        drop(breakers.try_acquire(ADDRESS).unwrap());
+        assert!(matches!(breakers.state(ADDRESS), CircuitState::Open { .. }));
+    }
+
+    #[test]
+    fn dropped_probe_reopens_the_circuit() {
+        let breakers = breakers(1, Duration::from_millis(1));
+        breakers
+            .try_acquire(ADDRESS)
+            .unwrap()
+            .complete(&io_failure());
+        std::thread::sleep(Duration::from_millis(5));
+
+        drop(breakers.try_acquire(ADDRESS).unwrap());
+        assert!(matches!(breakers.state(ADDRESS), CircuitState::Open { .. }));
+    }
+
+    #[test]
+    fn half_open_allows_a_single_probe() {
+        let breakers = breakers(1, Duration::from_millis(1));
+        breakers
+            .try_acquire(ADDRESS)
+            .unwrap()
+            .complete(&io_failure());
+        std::thread::sleep(Duration::from_millis(5));
+
+        let probe = breakers.try_acquire(ADDRESS).unwrap();
+        assert_eq!(breakers.state(ADDRESS), CircuitState::HalfOpen);
+        assert_eq!(
+            breakers.try_acquire(ADDRESS).err().unwrap().kind(),
+            ErrorKind::CircuitOpen
+        );
+
+        probe.complete(&Ok(()));
+        assert_eq!(breakers.state(ADDRESS), CircuitState::closed());
+    }
+
+    #[test]
+    fn failed_probe_reopens_the_circuit() {
+        let breakers = breakers(1, Duration::from_millis(1));
+        breakers
+            .try_acquire(ADDRESS)
+            .unwrap()
+            .complete(&io_failure());
+        std::thread::sleep(Duration::from_millis(5));
+
+        breakers
+            .try_acquire(ADDRESS)
+            .unwrap()
+            .complete(&io_failure());
+        assert!(matches!(breakers.state(ADDRESS), CircuitState::Open { .. }));
+    }
+}
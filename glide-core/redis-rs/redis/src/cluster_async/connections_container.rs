@@ -1,3 +1,4 @@
+use crate::cluster_async::circuit_breaker::CircuitBreakers;
 use crate::cluster_async::ConnectionFuture;
 use crate::cluster_routing::{Route, ShardAddrs, SlotAddr};
 use crate::cluster_slotmap::{ReadFromReplicaStrategy, SlotMap, SlotMapValue};
@@ -5,10 +6,11 @@ use crate::cluster_topology::TopologyHash;
 use dashmap::DashMap;
 use futures::FutureExt;
 use rand::seq::IteratorRandom;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::net::IpAddr;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::Duration;
 use telemetrylib::Telemetry;
 
 use tracing::debug;
@@ -31,6 +33,9 @@ macro_rules! count_connections {
     }};
 }
 
+/// The weight given to a new latency sample when updating a node's smoothed latency.
+const LATENCY_SMOOTHING_FACTOR: f64 = 0.2;
+
 /// A struct that encapsulates a network connection along with its associated IP address and AZ.
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub struct ConnectionDetails<Connection> {
@@ -290,12 +295,66 @@ impl RefreshConnectionStates {
     }
 }
 
+/// The maximum number of keys remembered as moved, for each migrating slot.
+const MAX_MOVED_KEYS_PER_SLOT: usize = 1024;
+
+struct MigratingSlot {
+    // The node the slot is migrating to
+    target: String,
+    // Keys that were already moved to the target node
+    moved_keys: HashSet<Vec<u8>>,
+}
+
+// Slots that are being migrated, as learned from `ASK` errors.
+// Requests for keys that are known to have been moved are sent with `ASKING` directly to the target node,
+// saving the round-trip to the source node. Only the keys themselves are remembered, since the source node
+// still serves the slot's other keys until they're moved.
+#[derive(Default)]
+pub(crate) struct MigratingSlots(DashMap<u16, MigratingSlot>);
+
+impl MigratingSlots {
+    // Records that `key` in `slot` was moved to `target`, as reported by an `ASK` error.
+    pub(crate) fn record_moved_key(&self, slot: u16, key: &[u8], target: &str) {
+        let mut migrating_slot = self.0.entry(slot).or_insert_with(|| MigratingSlot {
+            target: target.to_string(),
+            moved_keys: HashSet::new(),
+        });
+        // A different target means the slot is now migrating elsewhere, so its previously moved keys are stale.
+        if migrating_slot.target != target {
+            migrating_slot.target = target.to_string();
+            migrating_slot.moved_keys.clear();
+        }
+        if migrating_slot.moved_keys.len() < MAX_MOVED_KEYS_PER_SLOT {
+            migrating_slot.moved_keys.insert(key.to_vec());
+        }
+    }
+
+    // Returns the node that `key` in `slot` was moved to, if it's known to have been moved.
+    pub(crate) fn target_for_key(&self, slot: u16, key: &[u8]) -> Option<String> {
+        self.0
+            .get(&slot)
+            .filter(|migrating_slot| migrating_slot.moved_keys.contains(key))
+            .map(|migrating_slot| migrating_slot.target.clone())
+    }
+
+    // Forgets the migration of `slot`, once its owner has changed.
+    pub(crate) fn remove_slot(&self, slot: u16) {
+        self.0.remove(&slot);
+    }
+}
+
 pub(crate) struct ConnectionsContainer<Connection> {
     connection_map: DashMap<String, ClusterNode<Connection>>,
     pub(crate) slot_map: SlotMap,
     read_from_replica_strategy: ReadFromReplicaStrategy,
     topology_hash: TopologyHash,
     pub(crate) refresh_conn_state: RefreshConnectionStates,
+    // Smoothed round-trip latency of each node, used by the `LowestLatency` read strategy
+    pub(crate) node_latencies: DashMap<String, Duration>,
+    // Circuit state of each node, when the circuit breaker is enabled
+    pub(crate) circuit_breakers: Option<Arc<CircuitBreakers>>,
+    // Keys of migrating slots that were moved, cleared whenever the slot map changes
+    pub(crate) migrating_slots: MigratingSlots,
 }
 
 impl<Connection> Drop for ConnectionsContainer<Connection> {
@@ -313,6 +372,9 @@ impl<Connection> Default for ConnectionsContainer<Connection> {
             read_from_replica_strategy: ReadFromReplicaStrategy::AlwaysFromPrimary,
             topology_hash: 0,
             refresh_conn_state: Default::default(),
+            node_latencies: Default::default(),
+            circuit_breakers: None,
+            migrating_slots: Default::default(),
         }
     }
 }
@@ -341,6 +403,9 @@ where
             read_from_replica_strategy,
             topology_hash,
             refresh_conn_state: Default::default(),
+            node_latencies: Default::default(),
+            circuit_breakers: None,
+            migrating_slots: Default::default(),
         }
     }
 
@@ -378,6 +443,23 @@ where
         self.connection_for_address(address).is_some() && self.slot_map.is_primary(address)
     }
 
+    /// Returns the smoothed round-trip latency measured for the node in `address`, if any.
+    pub(crate) fn latency_for_address(&self, address: &str) -> Option<Duration> {
+        self.node_latencies.get(address).map(|item| *item.value())
+    }
+
+    /// Records a new round-trip latency sample for the node in `address`,
+    /// using an exponentially weighted moving average to smooth out spikes.
+    pub(crate) fn update_node_latency(&self, address: &str, sample: Duration) {
+        self.node_latencies
+            .entry(address.to_string())
+            .and_modify(|latency| {
+                *latency = latency.mul_f64(1.0 - LATENCY_SMOOTHING_FACTOR)
+                    + sample.mul_f64(LATENCY_SMOOTHING_FACTOR);
+            })
+            .or_insert(sample);
+    }
+
     fn round_robin_read_from_replica(
         &self,
         slot_map_value: &SlotMapValue,
@@ -480,6 +562,26 @@ where
         self.round_robin_read_from_replica(slot_map_value)
     }
 
+    /// Returns the connection of the replica with the lowest measured latency.
+    /// If no latency was measured yet for any of the connected replicas, falls back to round robin.
+    pub(crate) fn lowest_latency_read_from_replica(
+        &self,
+        slot_map_value: &SlotMapValue,
+    ) -> Option<ConnectionAndAddress<Connection>> {
+        slot_map_value
+            .addrs
+            .replicas()
+            .iter()
+            .filter_map(|replica| {
+                let latency = self.latency_for_address(replica.as_str())?;
+                let connection = self.connection_for_address(replica.as_str())?;
+                Some((latency, connection))
+            })
+            .min_by_key(|(latency, _)| *latency)
+            .map(|(_, connection)| connection)
+            .or_else(|| self.round_robin_read_from_replica(slot_map_value))
+    }
+
     fn lookup_route(&self, route: &Route) -> Option<ConnectionAndAddress<Connection>> {
         let slot_map_value = self.slot_map.slot_value_for_route(route)?;
         let addrs = &slot_map_value.addrs;
@@ -498,6 +600,9 @@ where
                 ReadFromReplicaStrategy::RoundRobin => {
                     self.round_robin_read_from_replica(slot_map_value)
                 }
+                ReadFromReplicaStrategy::LowestLatency => {
+                    self.lowest_latency_read_from_replica(slot_map_value)
+                }
                 ReadFromReplicaStrategy::AZAffinity(az) => self
                     .round_robin_read_from_replica_with_az_awareness(
                         slot_map_value,
@@ -521,6 +626,9 @@ where
                         slot_map_value,
                         az.to_string(),
                     ),
+                ReadFromReplicaStrategy::LowestLatency => {
+                    self.lowest_latency_read_from_replica(slot_map_value)
+                }
                 _ => self.round_robin_read_from_replica(slot_map_value),
             },
         }
@@ -530,13 +638,44 @@ where
         &self,
         route: &Route,
     ) -> Option<ConnectionAndAddress<Connection>> {
-        self.lookup_route(route).or_else(|| {
+        let connection = self.lookup_route(route).or_else(|| {
             if route.slot_addr() != SlotAddr::Master {
                 self.lookup_route(&Route::new(route.slot(), SlotAddr::Master))
             } else {
                 None
             }
-        })
+        })?;
+        if route.slot_addr() == SlotAddr::Master || self.is_circuit_available(&connection.0) {
+            return Some(connection);
+        }
+        // The circuit of the chosen node is open, so route the read to another node of the shard
+        self.available_connection_for_read(route)
+            .or(Some(connection))
+    }
+
+    /// Returns true if the circuit breaker of the node in `address` lets requests through.
+    pub(crate) fn is_circuit_available(&self, address: &str) -> bool {
+        match &self.circuit_breakers {
+            Some(circuit_breakers) => circuit_breakers.is_available(address),
+            None => true,
+        }
+    }
+
+    /// Returns a connection to any node serving the route's slot whose circuit is available,
+    /// preferring replicas over the primary.
+    fn available_connection_for_read(
+        &self,
+        route: &Route,
+    ) -> Option<ConnectionAndAddress<Connection>> {
+        let slot_map_value = self.slot_map.slot_value_for_route(route)?;
+        let addrs = &slot_map_value.addrs;
+        let primary = addrs.primary();
+        addrs
+            .replicas()
+            .iter()
+            .chain(std::iter::once(&primary))
+            .filter(|address| self.is_circuit_available(address.as_str()))
+            .find_map(|address| self.connection_for_address(address.as_str()))
     }
 
     // Fetches the master address for a given route.
@@ -685,6 +824,7 @@ where
     }
 
     pub(crate) fn remove_node(&self, address: &String) -> Option<ClusterNode<Connection>> {
+        self.node_latencies.remove(address);
         if let Some((_key, old_conn)) = self.connection_map.remove(address) {
             Telemetry::decr_total_connections(old_conn.connections_count());
             Some(old_conn)
@@ -837,6 +977,9 @@ mod tests {
                 .unwrap_or(ReadFromReplicaStrategy::AZAffinity("use-1a".to_string())),
             topology_hash: 0,
             refresh_conn_state: Default::default(),
+            node_latencies: Default::default(),
+            circuit_breakers: None,
+            migrating_slots: Default::default(),
         }
     }
 
@@ -894,6 +1037,9 @@ mod tests {
             read_from_replica_strategy: strategy,
             topology_hash: 0,
             refresh_conn_state: Default::default(),
+            node_latencies: Default::default(),
+            circuit_breakers: None,
+            migrating_slots: Default::default(),
         }
     }
 
@@ -1035,6 +1181,119 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn get_lowest_latency_replica_connection_for_replica_route() {
+        let container =
+            create_container_with_strategy(ReadFromReplicaStrategy::LowestLatency, false);
+        container.update_node_latency("replica3-1", Duration::from_millis(5));
+        container.update_node_latency("replica3-2", Duration::from_millis(1));
+
+        for _ in 0..3 {
+            assert_eq!(
+                32,
+                container
+                    .connection_for_route(&Route::new(2001, SlotAddr::ReplicaOptional))
+                    .unwrap()
+                    .1
+            );
+        }
+
+        // A removed replica's latency is dropped, so the remaining replica is used.
+        container.remove_node(&"replica3-2".into());
+        assert_eq!(
+            31,
+            container
+                .connection_for_route(&Route::new(2001, SlotAddr::ReplicaOptional))
+                .unwrap()
+                .1
+        );
+    }
+
+    #[test]
+    fn get_connection_skips_nodes_with_open_circuit_for_reads() {
+        let mut container = create_container();
+        let circuit_breakers = Arc::new(CircuitBreakers::new(
+            crate::cluster_async::CircuitBreakerConfig {
+                failure_threshold: 1,
+                ..Default::default()
+            },
+        ));
+        container.circuit_breakers = Some(circuit_breakers.clone());
+        let open_circuit = |address| {
+            let failure: crate::RedisResult<()> =
+                Err(std::io::Error::from(std::io::ErrorKind::ConnectionReset).into());
+            circuit_breakers
+                .try_acquire(address)
+                .unwrap()
+                .complete(&failure);
+        };
+        open_circuit("replica3-1");
+
+        for _ in 0..3 {
+            assert_eq!(
+                32,
+                container
+                    .connection_for_route(&Route::new(2001, SlotAddr::ReplicaOptional))
+                    .unwrap()
+                    .1
+            );
+        }
+
+        // With the circuits of all replicas open, reads fall back to the primary.
+        open_circuit("replica3-2");
+        assert_eq!(
+            3,
+            container
+                .connection_for_route(&Route::new(2001, SlotAddr::ReplicaOptional))
+                .unwrap()
+                .1
+        );
+
+        // Writes are never rerouted, so they're rejected by the circuit breaker itself.
+        open_circuit("primary3");
+        assert_eq!(
+            3,
+            container
+                .connection_for_route(&Route::new(2001, SlotAddr::Master))
+                .unwrap()
+                .1
+        );
+    }
+
+    #[test]
+    fn get_replica_connection_for_lowest_latency_strategy_without_measured_latencies() {
+        let container =
+            create_container_with_strategy(ReadFromReplicaStrategy::LowestLatency, false);
+
+        assert!(one_of(
+            container.connection_for_route(&Route::new(2001, SlotAddr::ReplicaOptional)),
+            &[31, 32],
+        ));
+
+        remove_nodes(&container, &["replica3-1", "replica3-2"]);
+        assert_eq!(
+            3,
+            container
+                .connection_for_route(&Route::new(2001, SlotAddr::ReplicaOptional))
+                .unwrap()
+                .1
+        );
+    }
+
+    #[test]
+    fn update_node_latency_smooths_samples() {
+        let container =
+            create_container_with_strategy(ReadFromReplicaStrategy::LowestLatency, false);
+        container.update_node_latency("replica3-1", Duration::from_millis(10));
+        container.update_node_latency("replica3-1", Duration::from_millis(20));
+
+        assert_eq!(
+            Some(Duration::from_millis(12)),
+            container.latency_for_address("replica3-1")
+        );
+        assert_eq!(None, container.latency_for_address("replica3-2"));
+    }
+
     #[test]
     fn get_primary_connection_for_replica_route_if_all_replicas_were_removed() {
         let container = create_container();
@@ -1560,6 +1819,30 @@ mod tests {
         assert!(!container.is_primary(&address));
     }
 
+    #[test]
+    fn test_migrating_slots_remember_moved_keys() {
+        let migrating_slots = MigratingSlots::default();
+        migrating_slots.record_moved_key(1, b"moved", "target1:6379");
+        assert_eq!(
+            migrating_slots.target_for_key(1, b"moved"),
+            Some("target1:6379".to_string())
+        );
+        // Other keys of the slot may still be in the source node
+        assert_eq!(migrating_slots.target_for_key(1, b"not_moved"), None);
+        assert_eq!(migrating_slots.target_for_key(2, b"moved"), None);
+
+        // The slot migrates to another node
+        migrating_slots.record_moved_key(1, b"other", "target2:6379");
+        assert_eq!(migrating_slots.target_for_key(1, b"moved"), None);
+        assert_eq!(
+            migrating_slots.target_for_key(1, b"other"),
+            Some("target2:6379".to_string())
+        );
+
+        migrating_slots.remove_slot(1);
+        assert_eq!(migrating_slots.target_for_key(1, b"other"), None);
+    }
+
     #[test]
     fn test_extend_connection_map() {
         let mut container = create_container();
@@ -392,9 +392,10 @@ where
 {
     let connection_timeout = params.connection_timeout;
     let response_timeout = params.response_timeout;
-    // ignore pubsub subscriptions and push notifications for management connections
+    // ignore pubsub subscriptions, key tracking and push notifications for management connections
     if is_management {
         params.pubsub_subscriptions = None;
+        params.client_tracking = None;
     }
     let info = get_connection_info(node, params)?;
     // management connection does not require notifications or disconnect notifications
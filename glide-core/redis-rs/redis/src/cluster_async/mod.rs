@@ -22,6 +22,7 @@
 //! }
 //! ```
 
+mod circuit_breaker;
 mod connections_container;
 mod connections_logic;
 mod pipeline_routing;
@@ -32,16 +33,18 @@ pub mod testing {
 }
 use crate::{
     client::GlideConnectionOptions,
-    cluster_routing::{Routable, RoutingInfo, ShardUpdateResult},
+    cluster_routing::{single_key, Routable, RoutingInfo, ShardUpdateResult},
     cluster_topology::{
         calculate_topology, get_slot, SlotRefreshState, DEFAULT_NUMBER_OF_REFRESH_SLOTS_RETRIES,
         DEFAULT_REFRESH_SLOTS_RETRY_BASE_DURATION_MILLIS, DEFAULT_REFRESH_SLOTS_RETRY_BASE_FACTOR,
     },
     cmd,
     commands::cluster_scan::{cluster_scan, ClusterScanArgs, ScanStateRC},
-    types::ServerError,
-    FromRedisValue, InfoDict, PipelineRetryStrategy,
+    types::{request_outcome, ServerError},
+    CommandRetryPolicy, FromRedisValue, InfoDict, PipelineRetryStrategy,
 };
+pub use circuit_breaker::CircuitBreakerConfig;
+use circuit_breaker::CircuitBreakers;
 use connections_container::{RefreshTaskNotifier, RefreshTaskState, RefreshTaskStatus};
 use dashmap::DashMap;
 use pipeline_routing::{
@@ -58,11 +61,11 @@ use std::{
     net::{IpAddr, SocketAddr},
     pin::Pin,
     sync::{
-        atomic::{self, AtomicUsize, Ordering},
+        atomic::{self, AtomicU32, AtomicUsize, Ordering},
         Arc, Mutex,
     },
     task::{self, Poll},
-    time::SystemTime,
+    time::{Instant, SystemTime},
 };
 use strum_macros::Display;
 #[cfg(feature = "tokio-comp")]
@@ -70,11 +73,14 @@ use tokio::task::JoinHandle;
 
 #[cfg(feature = "tokio-comp")]
 use crate::aio::DisconnectNotifier;
-use telemetrylib::{GlideOpenTelemetry, Telemetry};
+use telemetrylib::{GlideOpenTelemetry, GlideSpan, Telemetry};
 
 use crate::{
-    aio::{get_socket_addrs, ConnectionLike, MultiplexedConnection, Runtime},
-    cluster::slot_cmd,
+    aio::{
+        get_socket_addrs, req_command_with_surroundings, ConnectionLike, MultiplexedConnection,
+        Runtime,
+    },
+    cluster::{is_shards_cmd_unsupported, shards_cmd, slot_cmd},
     cluster_async::connections_logic::{
         get_host_and_port_from_addr, get_or_create_conn, ConnectionFuture, RefreshConnectionType,
     },
@@ -83,7 +89,10 @@ use crate::{
         self, MultipleNodeRoutingInfo, Redirect, ResponsePolicy, Route, SingleNodeRoutingInfo,
         SlotAddr,
     },
-    connection::{PubSubSubscriptionInfo, PubSubSubscriptionKind},
+    connection::{
+        add_pubsub_subscriptions, remove_pubsub_subscriptions, PubSubChannelOrPattern,
+        PubSubSubscriptionInfo, PubSubSubscriptionKind,
+    },
     push_manager::PushInfo,
     Cmd, ConnectionInfo, ErrorKind, IntoConnectionInfo, RedisError, RedisFuture, RedisResult,
     Value,
@@ -222,6 +231,7 @@ where
             .send(Message {
                 cmd: CmdArg::ClusterScan { cluster_scan_args },
                 sender,
+                retries: None,
             })
             .await
             .map_err(|e| {
@@ -240,7 +250,9 @@ where
             })
             .map(|response| match response {
                 Response::ClusterScanResult(new_scan_state_ref, key) => (new_scan_state_ref, key),
-                Response::Single(_) | Response::Multiple(_) => unreachable!(),
+                Response::Single(_) | Response::Multiple(_) => {
+                    unreachable!()
+                }
             })
     }
 
@@ -250,24 +262,42 @@ where
         cmd: &Cmd,
         routing: cluster_routing::RoutingInfo,
     ) -> RedisResult<Value> {
+        self.route_command_with_retry_policy(cmd, routing, None)
+            .await
+            .0
+    }
+
+    /// Send a command to the given `routing`, retrying it according to `retry_policy` instead of the client's defaults.
+    /// Returns the result, along with the number of times the command was retried, whether it succeeded or not.
+    pub async fn route_command_with_retry_policy(
+        &mut self,
+        cmd: &Cmd,
+        routing: cluster_routing::RoutingInfo,
+        retry_policy: Option<CommandRetryPolicy>,
+    ) -> (RedisResult<Value>, u32) {
         trace!("route_command");
         let (sender, receiver) = oneshot::channel();
-        self.0
+        let retries = Arc::new(AtomicU32::new(0));
+        if let Err(e) = self
+            .0
             .send(Message {
                 cmd: CmdArg::Cmd {
                     cmd: Arc::new(cmd.clone()),
                     routing: routing.into(),
+                    retry_policy,
                 },
                 sender,
+                retries: Some(retries.clone()),
             })
             .await
-            .map_err(|e| {
-                RedisError::from(io::Error::new(
-                    io::ErrorKind::BrokenPipe,
-                    format!("Cluster: Error occurred while trying to send command to internal sender. {e:?}"),
-                ))
-            })?;
-        receiver
+        {
+            let err = RedisError::from(io::Error::new(
+                io::ErrorKind::BrokenPipe,
+                format!("Cluster: Error occurred while trying to send command to internal sender. {e:?}"),
+            ));
+            return (Err(err), 0);
+        }
+        let result = receiver
             .await
             .unwrap_or_else(|e| {
                 Err(RedisError::from(io::Error::new(
@@ -280,7 +310,8 @@ where
             .map(|response| match response {
                 Response::Single(value) => value,
                 Response::ClusterScanResult(..) | Response::Multiple(_) => unreachable!(),
-            })
+            });
+        (result, retries.load(Ordering::Relaxed))
     }
 
     /// Send commands in `pipeline` to the given `route`. If `route` is [None], it will be computed from `pipeline`.
@@ -308,6 +339,7 @@ where
                     pipeline_retry_strategy: pipeline_retry_strategy.unwrap_or_default(),
                 },
                 sender,
+                retries: None,
             })
             .await
             .map_err(|err| {
@@ -356,6 +388,29 @@ where
         self.route_operation_request(Operation::GetUsername).await
     }
 
+    /// Subscribe to the given channels or patterns.
+    /// The subscriptions are tracked, and follow their slots across reconnects and slot migrations.
+    /// Requires RESP3.
+    pub async fn subscribe(
+        &mut self,
+        kind: PubSubSubscriptionKind,
+        channels_or_patterns: Vec<PubSubChannelOrPattern>,
+    ) -> RedisResult<Value> {
+        self.route_operation_request(Operation::Subscribe(kind, channels_or_patterns))
+            .await
+    }
+
+    /// Unsubscribe from the given channels or patterns, or from all subscriptions of `kind` if `channels_or_patterns` is empty.
+    /// Requires RESP3.
+    pub async fn unsubscribe(
+        &mut self,
+        kind: PubSubSubscriptionKind,
+        channels_or_patterns: Vec<PubSubChannelOrPattern>,
+    ) -> RedisResult<Value> {
+        self.route_operation_request(Operation::Unsubscribe(kind, channels_or_patterns))
+            .await
+    }
+
     /// Routes an operation request to the appropriate handler.
     async fn route_operation_request(
         &mut self,
@@ -366,6 +421,7 @@ where
             .send(Message {
                 cmd: CmdArg::OperationRequest(operation_request),
                 sender,
+                retries: None,
             })
             .await
             .map_err(|_| RedisError::from(io::Error::from(io::ErrorKind::BrokenPipe)))?;
@@ -463,6 +519,14 @@ where
             .map_err(|_| RedisError::from((ErrorKind::ClientError, MUTEX_WRITE_ERR)))
     }
 
+    /// Returns the address to connect to for `addr`, which was announced by a cluster node in a redirect.
+    fn map_address(&self, addr: &str) -> String {
+        self.cluster_params
+            .read()
+            .expect(MUTEX_READ_ERR)
+            .map_address(addr)
+    }
+
     // return epoch of node
     pub(crate) async fn address_epoch(&self, node_address: &str) -> Result<u64, RedisError> {
         let command = cmd("CLUSTER").arg("INFO").to_owned();
@@ -645,6 +709,8 @@ enum CmdArg<C> {
     Cmd {
         cmd: Arc<Cmd>,
         routing: InternalRoutingInfo<C>,
+        /// Overrides the client's retry behavior for this command.
+        retry_policy: Option<CommandRetryPolicy>,
     },
     Pipeline {
         pipeline: Arc<crate::Pipeline>,
@@ -672,6 +738,47 @@ enum Operation {
     UpdateConnectionDatabase(i64),
     UpdateConnectionClientName(Option<String>),
     GetUsername,
+    Subscribe(PubSubSubscriptionKind, Vec<PubSubChannelOrPattern>),
+    Unsubscribe(PubSubSubscriptionKind, Vec<PubSubChannelOrPattern>),
+}
+
+/// Records the latency of a single attempt of a request to the node in `address`.
+fn record_node_request_latency<T>(
+    command: &str,
+    address: &str,
+    result: &RedisResult<T>,
+    started: Instant,
+) {
+    if let Err(e) = GlideOpenTelemetry::record_node_request_latency(
+        command,
+        address,
+        request_outcome(result),
+        started.elapsed(),
+    ) {
+        log_error(
+            "OpenTelemetry:node_request_latency_error",
+            format!("Failed to record node request latency: {e}"),
+        );
+    }
+}
+
+/// Records `name` as a child span of the request's span, if the request is traced.
+fn trace_request_event(span: Option<GlideSpan>, name: &str, attributes: &[(&str, &str)]) {
+    let Some(span) = span else {
+        return;
+    };
+    match span.add_span(name) {
+        Ok(child_span) => {
+            for (key, value) in attributes {
+                child_span.set_attribute(key, value);
+            }
+            child_span.end();
+        }
+        Err(e) => log_warn(
+            "OpenTelemetry:span_error",
+            format!("Failed to create child span `{name}`: {e}"),
+        ),
+    }
 }
 
 fn boxed_sleep(duration: Duration) -> BoxFuture<'static, ()> {
@@ -722,6 +829,8 @@ impl RedirectNode {
 struct Message<C: Sized> {
     cmd: CmdArg<C>,
     sender: oneshot::Sender<RedisResult<Response>>,
+    /// Set to the number of times the request was retried, before it's answered.
+    retries: Option<Arc<AtomicU32>>,
 }
 
 enum RecoverFuture {
@@ -754,6 +863,22 @@ struct RequestInfo<C> {
 }
 
 impl<C> RequestInfo<C> {
+    /// Returns the span of the traced command or pipeline, if any.
+    fn span(&self) -> Option<GlideSpan> {
+        match &self.cmd {
+            CmdArg::Cmd { cmd, .. } => cmd.span(),
+            CmdArg::Pipeline { pipeline, .. } => pipeline.span(),
+            _ => None,
+        }
+    }
+
+    fn retry_policy(&self) -> Option<CommandRetryPolicy> {
+        match &self.cmd {
+            CmdArg::Cmd { retry_policy, .. } => *retry_policy,
+            _ => None,
+        }
+    }
+
     fn set_redirect(&mut self, redirect: Option<Redirect>) {
         if let Some(redirect) = redirect {
             match &mut self.cmd {
@@ -854,6 +979,8 @@ pin_project! {
 struct PendingRequest<C> {
     retry: u32,
     sender: oneshot::Sender<RedisResult<Response>>,
+    /// Set to `retry` before the request is answered.
+    retries: Option<Arc<AtomicU32>>,
     info: RequestInfo<C>,
 }
 
@@ -937,8 +1064,15 @@ impl<C> Future for Request<C> {
             }
             Err((target, err)) => {
                 let request = this.request.as_mut().unwrap();
+                let retry_policy = request.info.retry_policy();
+                let max_retries = retry_policy
+                    .map_or(this.retry_params.number_of_retries, |policy| {
+                        policy.max_retries(this.retry_params.number_of_retries)
+                    });
                 // TODO - would be nice if we didn't need to repeat this code twice, with & without retries.
-                if request.retry >= this.retry_params.number_of_retries {
+                if request.retry >= max_retries
+                    || retry_policy.is_some_and(|policy| !policy.allows(err.retry_method()))
+                {
                     let retry_method = err.retry_method();
                     let next = if err.kind() == ErrorKind::AllConnectionsUnavailable {
                         Next::ReconnectToInitialNodes { request: None }.into()
@@ -977,6 +1111,14 @@ impl<C> Future for Request<C> {
                         format!("Failed to record retry attempt: {e}"),
                     );
                 }
+                trace_request_event(
+                    request.info.span(),
+                    "retry",
+                    &[
+                        ("retry.attempt", &request.retry.to_string()),
+                        ("error.type", err.code().unwrap_or(err.category())),
+                    ],
+                );
 
                 if err.kind() == ErrorKind::AllConnectionsUnavailable {
                     return Next::ReconnectToInitialNodes {
@@ -1028,6 +1170,13 @@ impl<C> Future for Request<C> {
                     RetryMethod::MovedRedirect => {
                         let mut request = this.request.take().unwrap();
                         let redirect_node = err.redirect_node();
+                        if let Some((node, _slot)) = redirect_node {
+                            trace_request_event(
+                                request.info.span(),
+                                "moved_redirect",
+                                &[("redirect.address", node)],
+                            );
+                        }
                         request.info.set_redirect(
                             err.redirect_node()
                                 .map(|(node, _slot)| Redirect::Moved(node.to_string())),
@@ -1052,8 +1201,15 @@ impl<C> Future for Request<C> {
                         // TODO should we reset the redirect here?
                         request.info.reset_routing();
                         warn!("disconnected from {:?}", address);
+                        trace_request_event(
+                            request.info.span(),
+                            "reconnect",
+                            &[("server.address", &address)],
+                        );
                         let should_retry =
-                            matches!(err.retry_method(), RetryMethod::ReconnectAndRetry);
+                            matches!(err.retry_method(), RetryMethod::ReconnectAndRetry)
+                                || retry_policy
+                                    .is_some_and(|policy| policy.retry_connection_errors);
                         Next::Reconnect {
                             request: should_retry.then_some(request),
                             target: address,
@@ -1083,14 +1239,16 @@ impl<C> Future for Request<C> {
 
 impl<C> Request<C> {
     fn respond(self: Pin<&mut Self>, msg: RedisResult<Response>) {
-        // If `send` errors the receiver has dropped and thus does not care about the message
-        let _ = self
+        let request = self
             .project()
             .request
             .take()
-            .expect("Result should only be sent once")
-            .sender
-            .send(msg);
+            .expect("Result should only be sent once");
+        if let Some(retries) = request.retries {
+            retries.store(request.retry, Ordering::Relaxed);
+        }
+        // If `send` errors the receiver has dropped and thus does not care about the message
+        let _ = request.sender.send(msg);
     }
 }
 
@@ -1143,13 +1301,17 @@ where
 
         let topology_checks_interval = cluster_params.topology_checks_interval;
         let slots_refresh_rate_limiter = cluster_params.slots_refresh_rate_limit;
+        let mut connections_container = ConnectionsContainer::new(
+            Default::default(),
+            connections,
+            cluster_params.read_from_replicas.clone(),
+            0,
+        );
+        connections_container.circuit_breakers = cluster_params
+            .circuit_breaker
+            .map(|config| Arc::new(CircuitBreakers::new(config)));
         let inner = Arc::new(InnerCore {
-            conn_lock: StdRwLock::new(ConnectionsContainer::new(
-                Default::default(),
-                connections,
-                cluster_params.read_from_replicas.clone(),
-                0,
-            )),
+            conn_lock: StdRwLock::new(connections_container),
             cluster_params: StdRwLock::new(cluster_params.clone()),
             pending_requests: Mutex::new(Vec::new()),
             slot_refresh_state: SlotRefreshState::new(slots_refresh_rate_limiter),
@@ -1331,6 +1493,10 @@ where
                     return;
                 }
             };
+            inner
+                .slot_refresh_state
+                .cluster_shards_unsupported
+                .store(false, Ordering::Relaxed);
             inner
                 .conn_lock
                 .write()
@@ -1969,6 +2135,7 @@ where
             in_progress,
             last_run,
             rate_limiter,
+            ..
         } = &inner.slot_refresh_state;
         // Ensure only a single slot refresh operation occurs at a time
         if in_progress
@@ -2089,7 +2256,168 @@ where
             }
 
             Self::validate_all_user_connections(inner.clone()).await;
+
+            let measure_latency = inner
+                .get_cluster_param(|params| {
+                    params.read_from_replicas
+                        == crate::cluster_slotmap::ReadFromReplicaStrategy::LowestLatency
+                })
+                .unwrap_or(false);
+            if measure_latency {
+                Self::measure_node_latencies(inner.clone()).await;
+            }
+        }
+    }
+
+    // Sends a PING to every node, preferring the management connections so user requests aren't delayed,
+    // and records the round-trip time of each successful response.
+    // Nodes that fail to respond within the connection timeout don't get a new sample.
+    async fn measure_node_latencies(inner: Arc<InnerCore<C>>) {
+        let connection_timeout = match inner.get_cluster_param(|params| params.connection_timeout)
+        {
+            Ok(connection_timeout) => connection_timeout,
+            Err(err) => {
+                warn!("Failed to get cluster params: {}", err);
+                return;
+            }
+        };
+        let connections: Vec<_> = {
+            let connections_container = inner.conn_lock.read().expect(MUTEX_READ_ERR);
+            connections_container
+                .connection_map()
+                .iter()
+                .filter_map(|item| {
+                    connections_container.management_connection_for_address(item.key())
+                })
+                .collect()
+        };
+
+        let samples = future::join_all(connections.into_iter().map(|(address, conn)| async move {
+            let mut conn = conn.await;
+            let start = Instant::now();
+            match tokio::time::timeout(connection_timeout, conn.req_packed_command(&cmd("PING")))
+                .await
+            {
+                Ok(Ok(_)) => Some((address, start.elapsed())),
+                _ => None,
+            }
+        }))
+        .await;
+
+        let connections_container = inner.conn_lock.read().expect(MUTEX_READ_ERR);
+        for (address, latency) in samples.into_iter().flatten() {
+            trace!("measure_node_latencies: {address} responded in {latency:?}");
+            connections_container.update_node_latency(&address, latency);
+        }
+    }
+
+    fn check_subscriptions_protocol(inner: &InnerCore<C>) -> RedisResult<()> {
+        if inner.cluster_params.read().expect(MUTEX_READ_ERR).protocol
+            != crate::types::ProtocolVersion::RESP3
+        {
+            return Err(RedisError::from((
+                ErrorKind::InvalidClientConfig,
+                "Subscriptions can only be tracked with RESP3",
+            )));
+        }
+        Ok(())
+    }
+
+    /// Adds the subscriptions as unassigned, and lets `refresh_pubsub_subscriptions` assign them to the nodes serving their slots.
+    async fn subscribe(
+        inner: Arc<InnerCore<C>>,
+        kind: PubSubSubscriptionKind,
+        channels_or_patterns: Vec<PubSubChannelOrPattern>,
+    ) -> RedisResult<()> {
+        Self::check_subscriptions_protocol(&inner)?;
+        {
+            let subs_by_address_guard = inner.subscriptions_by_address.read().await;
+            let mut unassigned_subs_guard = inner.unassigned_subscriptions.write().await;
+            // already assigned subscriptions shouldn't cause their node to reconnect
+            let new_channels_or_patterns: Vec<_> = channels_or_patterns
+                .into_iter()
+                .filter(|channel_pattern| {
+                    !subs_by_address_guard.values().any(|address_subs| {
+                        address_subs
+                            .get(&kind)
+                            .is_some_and(|subs| subs.contains(channel_pattern))
+                    })
+                })
+                .collect();
+            add_pubsub_subscriptions(&mut unassigned_subs_guard, kind, &new_channels_or_patterns);
         }
+        Self::refresh_pubsub_subscriptions(inner).await;
+        Ok(())
+    }
+
+    /// Removes the subscriptions, and unsubscribes the user connections that hold them.
+    /// The other connections, and the requests in flight on them, are left untouched.
+    /// A connection that fails to unsubscribe is reconnected, which clears its subscriptions in the server,
+    /// and the remaining ones are restored in `setup_connection()`.
+    async fn unsubscribe(
+        inner: Arc<InnerCore<C>>,
+        kind: PubSubSubscriptionKind,
+        channels_or_patterns: Vec<PubSubChannelOrPattern>,
+    ) -> RedisResult<()> {
+        Self::check_subscriptions_protocol(&inner)?;
+        let mut removed_by_address: Vec<(String, Vec<PubSubChannelOrPattern>)> = Vec::new();
+        {
+            let mut subs_by_address_guard = inner.subscriptions_by_address.write().await;
+            let mut unassigned_subs_guard = inner.unassigned_subscriptions.write().await;
+            subs_by_address_guard.retain(|address, address_subs| {
+                let removed =
+                    remove_pubsub_subscriptions(address_subs, kind, &channels_or_patterns);
+                if !removed.is_empty() {
+                    removed_by_address.push((address.clone(), removed));
+                }
+                !address_subs.is_empty()
+            });
+            remove_pubsub_subscriptions(&mut unassigned_subs_guard, kind, &channels_or_patterns);
+        }
+
+        let connections: Vec<_> = {
+            let connections_container = inner.conn_lock.read().expect(MUTEX_READ_ERR);
+            removed_by_address
+                .into_iter()
+                .filter_map(|(address, removed)| {
+                    let (_, conn) = connections_container.connection_for_address(&address)?;
+                    Some((address, conn, removed))
+                })
+                .collect()
+        };
+        let command_name = match kind {
+            PubSubSubscriptionKind::Exact => "UNSUBSCRIBE",
+            PubSubSubscriptionKind::Pattern => "PUNSUBSCRIBE",
+            PubSubSubscriptionKind::Sharded => "SUNSUBSCRIBE",
+        };
+        let failed_addresses = future::join_all(connections.into_iter().map(
+            |(address, conn, removed)| async move {
+                let mut conn = conn.await;
+                // Each channel gets its own command, since every channel is confirmed by a separate push message.
+                for channel_or_pattern in removed {
+                    let mut unsubscribe_command = cmd(command_name);
+                    unsubscribe_command.arg(channel_or_pattern);
+                    if let Err(err) = conn.req_packed_command(&unsubscribe_command).await {
+                        warn!("unsubscribe: failed to unsubscribe on {address}: {err}");
+                        return Some(address);
+                    }
+                }
+                None
+            },
+        ))
+        .await;
+        let addrs_to_refresh: HashSet<String> = failed_addresses.into_iter().flatten().collect();
+
+        if !addrs_to_refresh.is_empty() {
+            Self::refresh_and_update_connections(
+                inner,
+                addrs_to_refresh,
+                RefreshConnectionType::OnlyUserConnection,
+                false,
+            )
+            .await;
+        }
+        Ok(())
     }
 
     async fn refresh_pubsub_subscriptions(inner: Arc<InnerCore<C>>) {
@@ -2192,7 +2520,7 @@ where
         )
         .await;
 
-        if let Ok((_, found_topology_hash)) = res {
+        if let Ok((_, found_topology_hash, _)) = res {
             if inner
                 .conn_lock
                 .read()
@@ -2224,6 +2552,7 @@ where
         let mut last_run_wlock = inner.slot_refresh_state.last_run.write().await;
         *last_run_wlock = Some(now);
         drop(last_run_wlock);
+        Telemetry::incr_total_topology_refreshes(1);
         Self::refresh_slots_inner(inner, curr_retry).await
     }
 
@@ -2232,7 +2561,7 @@ where
         let num_of_nodes = inner.conn_lock.read().expect(MUTEX_READ_ERR).len();
         const MAX_REQUESTED_NODES: usize = 10;
         let num_of_nodes_to_query = std::cmp::min(num_of_nodes, MAX_REQUESTED_NODES);
-        let (new_slots, topology_hash) =
+        let (new_slots, topology_hash, node_azs) =
             calculate_topology_from_random_nodes(&inner, num_of_nodes_to_query, curr_retry)
                 .await
                 .0?;
@@ -2290,15 +2619,25 @@ where
                     let subs_guard = inner.subscriptions_by_address.read().await;
                     cluster_params.pubsub_subscriptions = subs_guard.get(&addr).cloned();
                     drop(subs_guard);
+                    let az = node_azs.get(&addr).cloned();
+                    let mut glide_connection_options = inner.glide_connection_options.clone();
+                    // The node's availability zone is already known from the topology, so new connections don't need to query it.
+                    glide_connection_options.discover_az &= az.is_none();
                     let node = get_or_create_conn(
                         &addr,
                         node,
                         &cluster_params,
                         RefreshConnectionType::AllConnections,
-                        inner.glide_connection_options.clone(),
+                        glide_connection_options,
                     )
                     .await;
-                    if let Ok(node) = node {
+                    if let Ok(mut node) = node {
+                        if az.is_some() {
+                            if let Some(management_connection) = &mut node.management_connection {
+                                management_connection.az = az.clone();
+                            }
+                            node.user_connection.az = az;
+                        }
                         connections.0.insert(addr, node);
                     }
                     connections
@@ -2315,12 +2654,28 @@ where
         let read_from_replicas = inner
             .get_cluster_param(|params| params.read_from_replicas.clone())
             .expect(MUTEX_READ_ERR);
+        // Keep the measured latencies of nodes that are still part of the topology
+        let node_latencies = mem::take(&mut write_guard.node_latencies);
+        node_latencies.retain(|address, _| new_connections.0.contains_key(address));
+        // Likewise, keep the circuit state of these nodes
+        let circuit_breakers = write_guard.circuit_breakers.take();
+        if let Some(circuit_breakers) = &circuit_breakers {
+            circuit_breakers.retain(|address| new_connections.0.contains_key(address));
+        }
+        if write_guard.get_current_topology_hash() != topology_hash {
+            inner
+                .slot_refresh_state
+                .cluster_shards_unsupported
+                .store(false, Ordering::Relaxed);
+        }
         *write_guard = ConnectionsContainer::new(
             new_slots,
             new_connections,
             read_from_replicas,
             topology_hash,
         );
+        write_guard.node_latencies = node_latencies;
+        write_guard.circuit_breakers = circuit_breakers;
         Ok(())
     }
 
@@ -2349,12 +2704,13 @@ where
         slot: u16,
         new_primary: Arc<String>,
     ) -> RedisResult<()> {
-        let curr_shard_addrs = inner
-            .conn_lock
-            .read()
-            .expect(MUTEX_READ_ERR)
-            .slot_map
-            .shard_addrs_for_slot(slot);
+        let new_primary = Arc::new(inner.map_address(&new_primary));
+        let curr_shard_addrs = {
+            let conn_lock = inner.conn_lock.read().expect(MUTEX_READ_ERR);
+            // The slot's owner changed, so its migration is either complete or was aborted.
+            conn_lock.migrating_slots.remove_slot(slot);
+            conn_lock.slot_map.shard_addrs_for_slot(slot)
+        };
         // let curr_shard_addrs = connections_container.slot_map.shard_addrs_for_slot(slot);
         // Check if the new primary is part of the current shard and update if required
         if let Some(curr_shard_addrs) = curr_shard_addrs {
@@ -2405,6 +2761,7 @@ where
         routing: &'a MultipleNodeRoutingInfo,
         core: Core<C>,
         response_policy: Option<ResponsePolicy>,
+        retry_policy: Option<CommandRetryPolicy>,
     ) -> OperationResult {
         trace!("execute_on_multiple_nodes");
 
@@ -2416,6 +2773,8 @@ where
             iterator: impl Iterator<
                 Item = Option<(Arc<Cmd>, ConnectionAndAddress<ConnectionFuture<C>>)>,
             >,
+            retry_policy: Option<CommandRetryPolicy>,
+            is_circuit_available: impl Fn(&str) -> bool,
         ) -> (
             Vec<(Option<String>, Receiver<Result<Response, RedisError>>)>,
             Vec<Option<PendingRequest<C>>>,
@@ -2426,11 +2785,22 @@ where
                     if let Some((cmd, conn, address)) =
                         tuple_opt.map(|(cmd, (address, conn))| (cmd, conn, address))
                     {
+                        // Nodes whose circuit is open fail fast, without queueing their request.
+                        if !is_circuit_available(&address) {
+                            let _ = sender.send(Err((
+                                ErrorKind::CircuitOpen,
+                                "Circuit breaker is open for node",
+                                address.clone(),
+                            )
+                                .into()));
+                            return ((Some(address), receiver), None);
+                        }
                         (
                             (Some(address.clone()), receiver),
                             Some(PendingRequest {
                                 retry: 0,
                                 sender,
+                                retries: None,
                                 info: RequestInfo {
                                     cmd: CmdArg::Cmd {
                                         cmd,
@@ -2439,6 +2809,7 @@ where
                                             conn,
                                         }
                                         .into(),
+                                        retry_policy,
                                     },
                                 },
                             }),
@@ -2468,19 +2839,25 @@ where
                 ));
             }
 
+            let is_circuit_available =
+                |address: &str| connections_container.is_circuit_available(address);
             (receivers, requests) = match routing {
                 MultipleNodeRoutingInfo::AllNodes => into_channels(
                     connections_container
                         .all_node_connections()
                         .map(|tuple| Some((cmd.clone(), tuple))),
+                    retry_policy,
+                    is_circuit_available,
                 ),
                 MultipleNodeRoutingInfo::AllMasters => into_channels(
                     connections_container
                         .all_primary_connections()
                         .map(|tuple| Some((cmd.clone(), tuple))),
+                    retry_policy,
+                    is_circuit_available,
                 ),
-                MultipleNodeRoutingInfo::MultiSlot((slots, _)) => {
-                    into_channels(slots.iter().map(|(route, indices)| {
+                MultipleNodeRoutingInfo::MultiSlot((slots, _)) => into_channels(
+                    slots.iter().map(|(route, indices)| {
                         connections_container
                             .connection_for_route(route)
                             .map(|tuple| {
@@ -2491,8 +2868,10 @@ where
                                     );
                                 (Arc::new(new_cmd), tuple)
                             })
-                    }))
-                }
+                    }),
+                    retry_policy,
+                    is_circuit_available,
+                ),
             };
         }
         core.pending_requests
@@ -2509,6 +2888,7 @@ where
     pub(crate) async fn try_cmd_request(
         cmd: Arc<Cmd>,
         routing: InternalRoutingInfo<C>,
+        retry_policy: Option<CommandRetryPolicy>,
         core: Core<C>,
     ) -> OperationResult {
         let routing = match routing {
@@ -2519,6 +2899,7 @@ where
                     &multi_node_routing,
                     core,
                     response_policy,
+                    retry_policy,
                 )
                 .await;
             }
@@ -2527,34 +2908,141 @@ where
         };
         trace!("route request to single node");
 
+        let span = cmd.span();
+        let database_id = span
+            .as_ref()
+            .and_then(|_| core.get_cluster_param(|params| params.database_id).ok());
+        let circuit_breakers = core
+            .conn_lock
+            .read()
+            .expect(MUTEX_READ_ERR)
+            .circuit_breakers
+            .clone();
+        // Keys moved by `ASK` redirects are remembered only for single key commands, since the other keys of a
+        // multi-key command might not have been moved yet.
+        let key = single_key(&*cmd);
+        let routing = Self::route_to_migrating_slot(routing, key, &core);
+        // `ASKING` only applies to the command right after it, so a command that follows it can't be surrounded.
+        let asking = matches!(
+            routing,
+            InternalSingleNodeRouting::Redirect {
+                redirect: Redirect::Ask(_, true),
+                ..
+            }
+        );
         // if we reached this point, we're sending the command only to single node, and we need to find the
         // right connection to the node.
-        let (address, mut conn) = Self::get_connection(routing, core, Some(cmd.clone()))
+        let (address, mut conn) = Self::get_connection(routing, core.clone(), Some(cmd.clone()))
             .await
             .map_err(|err| (OperationTarget::NotFound, err))?;
-        conn.req_packed_command(&cmd)
-            .await
+        if let Some(span) = &span {
+            span.set_attribute("server.address", &address);
+            if let Some(database_id) = database_id {
+                span.set_attribute("db.namespace", &database_id.to_string());
+            }
+        }
+        let permit = circuit_breakers
+            .map(|circuit_breakers| circuit_breakers.try_acquire(&address))
+            .transpose()
+            .map_err(|err| (OperationTarget::FatalError, err))?;
+        let started = Instant::now();
+        let result = if asking {
+            conn.req_packed_command(&cmd).await
+        } else {
+            req_command_with_surroundings(&mut conn, &cmd).await
+        };
+        let command = cmd.command().unwrap_or_default();
+        record_node_request_latency(
+            &String::from_utf8_lossy(&command),
+            &address,
+            &result,
+            started,
+        );
+        if let Some(permit) = permit {
+            permit.complete(&result);
+        }
+        if let (Err(err), Some(key)) = (&result, key) {
+            if let (RetryMethod::AskRedirect, Some((target, slot))) =
+                (err.retry_method(), err.redirect_node())
+            {
+                core.conn_lock
+                    .read()
+                    .expect(MUTEX_READ_ERR)
+                    .migrating_slots
+                    .record_moved_key(slot, key, target);
+            }
+        }
+        result
             .map(Response::Single)
             .map_err(|err| (address.into(), err))
     }
 
+    /// Redirects a request for `key` with `ASKING` to the target node of its slot's migration, if the key is known
+    /// to have been moved there by a previous `ASK` error.
+    fn route_to_migrating_slot(
+        routing: InternalSingleNodeRouting<C>,
+        key: Option<&[u8]>,
+        core: &Core<C>,
+    ) -> InternalSingleNodeRouting<C> {
+        let (InternalSingleNodeRouting::SpecificNode(route), Some(key)) = (&routing, key) else {
+            return routing;
+        };
+        let target = core
+            .conn_lock
+            .read()
+            .expect(MUTEX_READ_ERR)
+            .migrating_slots
+            .target_for_key(route.slot(), key);
+        match target {
+            Some(target) => InternalSingleNodeRouting::Redirect {
+                redirect: Redirect::Ask(target, true),
+                previous_routing: Box::new(routing),
+            },
+            None => routing,
+        }
+    }
+
     async fn try_pipeline_request(
         pipeline: Arc<crate::Pipeline>,
         offset: usize,
         count: usize,
         conn: impl Future<Output = RedisResult<(String, C)>>,
+        circuit_breakers: Option<Arc<CircuitBreakers>>,
     ) -> OperationResult {
         trace!("try_pipeline_request");
         let (address, mut conn) = conn.await.map_err(|err| (OperationTarget::NotFound, err))?;
-        conn.req_packed_commands(&pipeline, offset, count, None)
-            .await
+        if let Some(span) = pipeline.span() {
+            span.set_attribute("server.address", &address);
+        }
+        let permit = circuit_breakers
+            .map(|circuit_breakers| circuit_breakers.try_acquire(&address))
+            .transpose()
+            .map_err(|err| (OperationTarget::FatalError, err))?;
+        let started = Instant::now();
+        let result = conn
+            .req_packed_commands(&pipeline, offset, count, None)
+            .await;
+        let command = if pipeline.is_atomic() {
+            "TRANSACTION"
+        } else {
+            "PIPELINE"
+        };
+        record_node_request_latency(command, &address, &result, started);
+        if let Some(permit) = permit {
+            permit.complete(&result);
+        }
+        result
             .map(Response::Multiple)
             .map_err(|err| (OperationTarget::Node { address }, err))
     }
 
     async fn try_request(info: RequestInfo<C>, core: Core<C>) -> OperationResult {
         match info.cmd {
-            CmdArg::Cmd { cmd, routing } => Self::try_cmd_request(cmd, routing, core).await,
+            CmdArg::Cmd {
+                cmd,
+                routing,
+                retry_policy,
+            } => Self::try_cmd_request(cmd, routing, retry_policy, core).await,
             CmdArg::Pipeline {
                 pipeline,
                 offset,
@@ -2565,6 +3053,12 @@ where
             } => {
                 if pipeline.is_atomic() || sub_pipeline {
                     // If the pipeline is atomic (i.e., a transaction) or if the pipeline is already splitted into sub-pipelines (i.e., the pipeline is already routed to a specific node), we can send it as is, with no need to split it into sub-pipelines.
+                    let circuit_breakers = core
+                        .conn_lock
+                        .read()
+                        .expect(MUTEX_READ_ERR)
+                        .circuit_breakers
+                        .clone();
                     Self::try_pipeline_request(
                         pipeline,
                         offset,
@@ -2574,6 +3068,7 @@ where
                             core,
                             None,
                         ),
+                        circuit_breakers,
                     )
                     .await
                 } else {
@@ -2628,6 +3123,18 @@ where
                     };
                     Ok(Response::Single(username))
                 }
+                Operation::Subscribe(kind, channels_or_patterns) => {
+                    Self::subscribe(core, kind, channels_or_patterns)
+                        .await
+                        .map(|_| Response::Single(Value::Okay))
+                        .map_err(|err| (OperationTarget::FatalError, err))
+                }
+                Operation::Unsubscribe(kind, channels_or_patterns) => {
+                    Self::unsubscribe(core, kind, channels_or_patterns)
+                        .await
+                        .map(|_| Response::Single(Value::Okay))
+                        .map_err(|err| (OperationTarget::FatalError, err))
+                }
             },
         }
     }
@@ -2783,20 +3290,23 @@ where
             InternalSingleNodeRouting::Redirect {
                 redirect: Redirect::Moved(moved_addr),
                 ..
-            } => core
-                .conn_lock
-                .read()
-                .expect(MUTEX_READ_ERR)
-                .connection_for_address(moved_addr.as_str())
-                .map_or(
-                    ConnectionCheck::OnlyAddress(moved_addr),
-                    ConnectionCheck::Found,
-                ),
+            } => {
+                let moved_addr = core.map_address(&moved_addr);
+                core.conn_lock
+                    .read()
+                    .expect(MUTEX_READ_ERR)
+                    .connection_for_address(moved_addr.as_str())
+                    .map_or(
+                        ConnectionCheck::OnlyAddress(moved_addr),
+                        ConnectionCheck::Found,
+                    )
+            }
             InternalSingleNodeRouting::Redirect {
                 redirect: Redirect::Ask(ask_addr, should_exec_asking),
                 ..
             } => {
                 asking = should_exec_asking;
+                let ask_addr = core.map_address(&ask_addr);
                 core.conn_lock
                     .read()
                     .expect(MUTEX_READ_ERR)
@@ -3304,7 +3814,11 @@ where
     }
 
     fn start_send(self: Pin<&mut Self>, msg: Message<C>) -> Result<(), Self::Error> {
-        let Message { cmd, sender } = msg;
+        let Message {
+            cmd,
+            sender,
+            retries,
+        } = msg;
 
         let info = RequestInfo { cmd };
 
@@ -3315,6 +3829,7 @@ where
             .push(PendingRequest {
                 retry: 0,
                 sender,
+                retries,
                 info,
             });
         Ok(())
@@ -3474,6 +3989,7 @@ async fn calculate_topology_from_random_nodes<C>(
     RedisResult<(
         crate::cluster_slotmap::SlotMap,
         crate::cluster_topology::TopologyHash,
+        crate::cluster_topology::NodeAzs,
     )>,
     std::collections::HashSet<String>,
 )
@@ -3513,9 +4029,28 @@ where
         }
     };
 
+    let cluster_shards_unsupported = &inner.slot_refresh_state.cluster_shards_unsupported;
+    let use_cluster_shards = inner
+        .get_cluster_param(|params| params.cluster_shards_discovery)
+        .unwrap_or(false)
+        && !cluster_shards_unsupported.load(Ordering::Relaxed);
+
     let topology_join_results =
         futures::future::join_all(requested_nodes.into_iter().map(|(addr, conn)| async move {
             let mut conn: C = conn.await;
+            if use_cluster_shards {
+                match conn.req_packed_command(&shards_cmd()).await {
+                    // Servers that don't support CLUSTER SHARDS, or users that aren't permitted to run it, get an
+                    // error, so fall back to CLUSTER SLOTS.
+                    Err(err) if is_shards_cmd_unsupported(&err) => {
+                        debug!(
+                            "CLUSTER SHARDS can't be used with {addr}, using CLUSTER SLOTS: {err}"
+                        );
+                        cluster_shards_unsupported.store(true, Ordering::Relaxed);
+                    }
+                    res => return (addr, res),
+                }
+            }
             let res = conn.req_packed_command(&slot_cmd()).await;
             (addr, res)
         }))
@@ -3542,6 +4077,9 @@ where
     let read_from_replicas = inner
         .get_cluster_param(|params| params.read_from_replicas.clone())
         .expect(MUTEX_READ_ERR);
+    let address_mapper = inner
+        .get_cluster_param(|params| params.address_mapper.clone())
+        .expect(MUTEX_READ_ERR);
     (
         calculate_topology(
             topology_values,
@@ -3549,6 +4087,7 @@ where
             tls_mode,
             num_of_nodes_to_query,
             read_from_replicas,
+            address_mapper.as_deref(),
         ),
         failed_addresses,
     )
@@ -441,6 +441,7 @@ where
         pending_requests.push(PendingRequest {
             retry,
             sender,
+            retries: None,
             info: RequestInfo {
                 cmd: CmdArg::Pipeline {
                     count: context.pipeline.len(),
@@ -3,19 +3,23 @@ use crate::cluster_slotmap::ReadFromReplicaStrategy;
 use crate::cluster_topology::{
     DEFAULT_SLOTS_REFRESH_MAX_JITTER_MILLI, DEFAULT_SLOTS_REFRESH_WAIT_DURATION,
 };
-use crate::connection::{ConnectionAddr, ConnectionInfo, IntoConnectionInfo};
+use crate::connection::{ClientTrackingMode, ConnectionAddr, ConnectionInfo, IntoConnectionInfo};
 use crate::types::{ErrorKind, ProtocolVersion, RedisError, RedisResult};
 use crate::{cluster, cluster::TlsMode};
 use crate::{PubSubSubscriptionInfo, PushInfo, RetryStrategy};
 use rand::Rng;
+use std::collections::HashMap;
 #[cfg(feature = "cluster-async")]
 use std::ops::Add;
+use std::sync::Arc;
 use std::time::Duration;
 
 use crate::tls::TlsConnParams;
 
 #[cfg(feature = "cluster-async")]
 use crate::cluster_async;
+#[cfg(feature = "cluster-async")]
+use crate::cluster_async::CircuitBreakerConfig;
 
 use crate::tls::{retrieve_tls_certificates, TlsCertificates};
 
@@ -39,13 +43,19 @@ struct BuilderParams {
     connections_validation_interval: Option<Duration>,
     #[cfg(feature = "cluster-async")]
     slots_refresh_rate_limit: SlotsRefreshRateLimit,
+    #[cfg(feature = "cluster-async")]
+    circuit_breaker: Option<CircuitBreakerConfig>,
     client_name: Option<String>,
     lib_name: Option<String>,
     response_timeout: Option<Duration>,
     protocol: ProtocolVersion,
     pubsub_subscriptions: Option<PubSubSubscriptionInfo>,
+    client_tracking: Option<ClientTrackingMode>,
     reconnect_retry_strategy: Option<RetryStrategy>,
     refresh_topology_from_initial_nodes: bool,
+    #[cfg(feature = "cluster-async")]
+    cluster_shards_discovery: bool,
+    address_mapper: Option<Arc<dyn AddressMapper>>,
     database_id: i64,
 }
 
@@ -140,6 +150,8 @@ pub struct ClusterParams {
     pub(crate) slots_refresh_rate_limit: SlotsRefreshRateLimit,
     #[cfg(feature = "cluster-async")]
     pub(crate) connections_validation_interval: Option<Duration>,
+    #[cfg(feature = "cluster-async")]
+    pub(crate) circuit_breaker: Option<CircuitBreakerConfig>,
     pub(crate) tls_params: Option<TlsConnParams>,
     pub(crate) client_name: Option<String>,
     pub(crate) lib_name: Option<String>,
@@ -147,8 +159,12 @@ pub struct ClusterParams {
     pub(crate) response_timeout: Duration,
     pub(crate) protocol: ProtocolVersion,
     pub(crate) pubsub_subscriptions: Option<PubSubSubscriptionInfo>,
+    pub(crate) client_tracking: Option<ClientTrackingMode>,
     pub(crate) reconnect_retry_strategy: Option<RetryStrategy>,
     pub(crate) refresh_topology_from_initial_nodes: bool,
+    #[cfg(feature = "cluster-async")]
+    pub(crate) cluster_shards_discovery: bool,
+    pub(crate) address_mapper: Option<Arc<dyn AddressMapper>>,
     pub(crate) database_id: i64,
 }
 
@@ -173,19 +189,54 @@ impl ClusterParams {
             slots_refresh_rate_limit: value.slots_refresh_rate_limit,
             #[cfg(feature = "cluster-async")]
             connections_validation_interval: value.connections_validation_interval,
+            #[cfg(feature = "cluster-async")]
+            circuit_breaker: value.circuit_breaker,
             tls_params,
             client_name: value.client_name,
             lib_name: value.lib_name,
             response_timeout: value.response_timeout.unwrap_or(Duration::MAX),
             protocol: value.protocol,
             pubsub_subscriptions: value.pubsub_subscriptions,
+            client_tracking: value.client_tracking,
             reconnect_retry_strategy: value.reconnect_retry_strategy,
             refresh_topology_from_initial_nodes: value.refresh_topology_from_initial_nodes,
+            #[cfg(feature = "cluster-async")]
+            cluster_shards_discovery: value.cluster_shards_discovery,
+            address_mapper: value.address_mapper,
             database_id: value.database_id,
         })
     }
 }
 
+impl ClusterParams {
+    /// Returns the address to connect to for `addr`, a `host:port` address announced by a cluster node.
+    pub(crate) fn map_address(&self, addr: &str) -> String {
+        let Some(address_mapper) = &self.address_mapper else {
+            return addr.to_string();
+        };
+        addr.rsplit_once(':')
+            .and_then(|(host, port)| address_mapper.map_address(host, port.parse().ok()?))
+            .map_or_else(|| addr.to_string(), |(host, port)| format!("{host}:{port}"))
+    }
+}
+
+/// Maps the addresses that cluster nodes announce to the addresses that the client connects to.
+///
+/// Cluster nodes announce their own addresses in the topology and in `MOVED` and `ASK` redirects. When the
+/// cluster is reached through NAT, SSH tunnels or port-forwards, these addresses aren't reachable from the
+/// client, and need to be mapped before connecting.
+pub trait AddressMapper: Send + Sync {
+    /// Returns the address to connect to instead of `host`:`port`, or `None` to connect to `host`:`port`.
+    fn map_address(&self, host: &str, port: u16) -> Option<(String, u16)>;
+}
+
+/// A static mapping from announced addresses to the addresses to connect to. Other addresses aren't mapped.
+impl AddressMapper for HashMap<(String, u16), (String, u16)> {
+    fn map_address(&self, host: &str, port: u16) -> Option<(String, u16)> {
+        self.get(&(host.to_string(), port)).cloned()
+    }
+}
+
 /// Used to configure and build a [`ClusterClient`].
 pub struct ClusterClientBuilder {
     initial_nodes: RedisResult<Vec<ConnectionInfo>>,
@@ -204,7 +255,11 @@ impl ClusterClientBuilder {
                 .into_iter()
                 .map(|x| x.into_connection_info())
                 .collect(),
-            builder_params: Default::default(),
+            builder_params: BuilderParams {
+                #[cfg(feature = "cluster-async")]
+                cluster_shards_discovery: true,
+                ..Default::default()
+            },
         }
     }
 
@@ -401,6 +456,8 @@ impl ClusterClientBuilder {
     /// `ReadFromReplicaStrategy::AZAffinityReplicasAndPrimary(availability_zone)` - attempt to access nodes in the same availability zone.
     ///  prioritizing local replicas, then the local primary, and falling back to any replica or the primary if needed.
     /// `ReadFromReplicaStrategy::RoundRobin` - reads are distributed across replicas for load balancing using round-robin algorithm. Falling back to primary if needed.
+    /// `ReadFromReplicaStrategy::LowestLatency` - reads are sent to the replica with the lowest measured round-trip latency.
+    ///  Latencies are sampled during the periodic connections checks, so `periodic_connections_checks` should be enabled.
     /// `ReadFromReplicaStrategy::AlwaysFromPrimary` ensures all read and write queries are directed to the primary node.
     ///
     /// # Parameters
@@ -482,6 +539,20 @@ impl ClusterClientBuilder {
         self
     }
 
+    /// Enables a circuit breaker per node.
+    ///
+    /// After `failure_threshold` consecutive connection errors or timeouts from a node within
+    /// `failure_window`, requests to that node fail fast with [`ErrorKind::CircuitOpen`], while
+    /// reads are routed to another node of the same shard. After `open_duration`, a single probe
+    /// request is sent to the node, and the circuit closes once it succeeds.
+    ///
+    /// The circuit breaker is disabled by default.
+    #[cfg(feature = "cluster-async")]
+    pub fn circuit_breaker(mut self, config: CircuitBreakerConfig) -> ClusterClientBuilder {
+        self.builder_params.circuit_breaker = Some(config);
+        self
+    }
+
     /// Enables refreshing the cluster topology from seed nodes.
     ///
     /// When enabled, the client will periodically query the seed nodes (the nodes provided when
@@ -495,6 +566,35 @@ impl ClusterClientBuilder {
         self
     }
 
+    /// Enables discovering the cluster topology with `CLUSTER SHARDS`.
+    ///
+    /// When enabled, the topology is discovered with `CLUSTER SHARDS`, which also reports the health and
+    /// availability zone of each node: replicas that are loading or failed aren't used for reads, and
+    /// connections don't need to query the availability zone of their node. Nodes that don't support
+    /// `CLUSTER SHARDS` are queried with `CLUSTER SLOTS` instead.
+    ///
+    /// Enabled by default.
+    #[cfg(feature = "cluster-async")]
+    pub fn cluster_shards_discovery(
+        mut self,
+        cluster_shards_discovery: bool,
+    ) -> ClusterClientBuilder {
+        self.builder_params.cluster_shards_discovery = cluster_shards_discovery;
+        self
+    }
+
+    /// Sets the mapper of the addresses that cluster nodes announce, in the topology and in redirects, to the
+    /// addresses that the client connects to.
+    ///
+    /// The initial nodes aren't mapped. By default, addresses aren't mapped.
+    pub fn address_mapper(
+        mut self,
+        address_mapper: impl AddressMapper + 'static,
+    ) -> ClusterClientBuilder {
+        self.builder_params.address_mapper = Some(Arc::new(address_mapper));
+        self
+    }
+
     /// Enables timing out on slow connection time.
     ///
     /// If enabled, the cluster will only wait the given time on each connection attempt to each node.
@@ -551,6 +651,12 @@ impl ClusterClientBuilder {
         self.builder_params.pubsub_subscriptions = Some(pubsub_subscriptions);
         self
     }
+
+    /// Enables key tracking with the given mode on the connections of the new ClusterClient, for client side caching.
+    pub fn client_tracking(mut self, client_tracking: ClientTrackingMode) -> ClusterClientBuilder {
+        self.builder_params.client_tracking = Some(client_tracking);
+        self
+    }
 }
 
 /// This is a Redis Cluster client.
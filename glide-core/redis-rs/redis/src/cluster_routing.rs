@@ -584,6 +584,9 @@ enum RouteBy {
     AllNodes,
     AllPrimaries,
     FirstKey,
+    /// Routed by the first key, like [`RouteBy::FirstKey`], but might have additional keys, which must
+    /// belong to the same slot.
+    FirstOfSeveralKeys,
     MultiShard(MultiSlotArgPattern),
     Random,
     SecondArg,
@@ -741,6 +744,13 @@ fn base_routing(cmd: &[u8]) -> RouteBy {
         | b"TFUNCTION LOAD"
         | b"TIME" => RouteBy::Random,
 
+        b"BLMOVE" | b"BLPOP" | b"BRPOP" | b"BRPOPLPUSH" | b"BZPOPMAX" | b"BZPOPMIN" | b"COPY"
+        | b"GEORADIUS" | b"GEORADIUSBYMEMBER" | b"GEOSEARCHSTORE" | b"LCS" | b"LMOVE"
+        | b"MSETNX" | b"PFCOUNT" | b"PFMERGE" | b"RENAME" | b"RENAMENX" | b"RPOPLPUSH"
+        | b"SDIFF" | b"SDIFFSTORE" | b"SINTER" | b"SINTERSTORE" | b"SMOVE" | b"SORT"
+        | b"SUNION" | b"SUNIONSTORE" | b"ZDIFFSTORE" | b"ZINTERSTORE" | b"ZRANGESTORE"
+        | b"ZUNIONSTORE" => RouteBy::FirstOfSeveralKeys,
+
         b"CLUSTER ADDSLOTS"
         | b"CLUSTER COUNTKEYSINSLOT"
         | b"CLUSTER DELSLOTS"
@@ -764,6 +774,7 @@ impl RoutingInfo {
     pub fn is_key_routing_command(cmd: &[u8]) -> bool {
         match base_routing(cmd) {
             RouteBy::FirstKey
+            | RouteBy::FirstOfSeveralKeys
             | RouteBy::SecondArg
             | RouteBy::SecondArgAfterKeyCount
             | RouteBy::ThirdArgAfterKeyCount
@@ -851,7 +862,7 @@ impl RoutingInfo {
                     )))
                 }),
 
-            RouteBy::FirstKey => match r.arg_idx(1) {
+            RouteBy::FirstKey | RouteBy::FirstOfSeveralKeys => match r.arg_idx(1) {
                 Some(key) => Some(RoutingInfo::for_key(cmd, key)),
                 None => Some(RoutingInfo::SingleNode(SingleNodeRoutingInfo::Random)),
             },
@@ -876,6 +887,85 @@ pub fn is_readonly(routable: &impl Routable) -> bool {
     }
 }
 
+/// Returns the key that routes the given `routable`, following the same rules as [`RoutingInfo::for_routable`].
+/// For commands whose keys may belong to multiple slots, the first key is returned.
+pub fn first_key<R>(routable: &R) -> Option<&[u8]>
+where
+    R: Routable + ?Sized,
+{
+    let cmd = routable.command()?;
+    let key_count = |idx| {
+        routable
+            .arg_idx(idx)
+            .and_then(|x| std::str::from_utf8(x).ok())
+            .and_then(|x| x.parse::<u64>().ok())
+            .filter(|key_count| *key_count > 0)
+    };
+    match base_routing(&cmd) {
+        RouteBy::FirstKey | RouteBy::FirstOfSeveralKeys | RouteBy::MultiShard(_) => {
+            routable.arg_idx(1)
+        }
+        RouteBy::SecondArg => routable.arg_idx(2),
+        RouteBy::SecondArgAfterKeyCount => key_count(1).and_then(|_| routable.arg_idx(2)),
+        RouteBy::ThirdArgAfterKeyCount => key_count(2).and_then(|_| routable.arg_idx(3)),
+        RouteBy::StreamsIndex => routable
+            .position(b"STREAMS")
+            .and_then(|streams_position| routable.arg_idx(streams_position + 1)),
+        RouteBy::AllNodes
+        | RouteBy::AllPrimaries
+        | RouteBy::Random
+        | RouteBy::SecondArgSlot
+        | RouteBy::Undefined => None,
+    }
+}
+
+/// Returns the key of the given `routable` if it's the command's only key, or `None` if the command has no keys
+/// or might have several of them.
+pub fn single_key<R>(routable: &R) -> Option<&[u8]>
+where
+    R: Routable + ?Sized,
+{
+    let cmd = routable.command()?;
+    let arg_count = (0..)
+        .take_while(|idx| routable.arg_idx(*idx).is_some())
+        .count();
+    let key_count = |idx| {
+        routable
+            .arg_idx(idx)
+            .and_then(|x| std::str::from_utf8(x).ok())
+            .and_then(|x| x.parse::<u64>().ok())
+    };
+    let is_single_key = match base_routing(&cmd) {
+        RouteBy::MultiShard(pattern) => {
+            let args_per_key = match pattern {
+                MultiSlotArgPattern::KeysOnly => 1,
+                MultiSlotArgPattern::KeyValuePairs | MultiSlotArgPattern::KeysAndLastArg => 2,
+                MultiSlotArgPattern::KeyWithTwoArgTriples => 3,
+            };
+            arg_count == 1 + args_per_key
+        }
+        RouteBy::SecondArgAfterKeyCount => key_count(1) == Some(1),
+        RouteBy::ThirdArgAfterKeyCount => key_count(2) == Some(1),
+        // A single stream is followed by a single ID.
+        RouteBy::StreamsIndex => routable
+            .position(b"STREAMS")
+            .is_some_and(|streams_position| arg_count == streams_position + 3),
+        RouteBy::SecondArg => cmd.as_slice() != b"BITOP",
+        RouteBy::FirstKey => true,
+        RouteBy::FirstOfSeveralKeys
+        | RouteBy::AllNodes
+        | RouteBy::AllPrimaries
+        | RouteBy::Random
+        | RouteBy::SecondArgSlot
+        | RouteBy::Undefined => false,
+    };
+    if is_single_key {
+        first_key(routable)
+    } else {
+        None
+    }
+}
+
 /// Returns `true` if the given `cmd` is a readonly command.
 pub fn is_readonly_cmd(cmd: &[u8]) -> bool {
     matches!(
@@ -1430,8 +1520,9 @@ fn random_slot() -> u16 {
 #[cfg(test)]
 mod tests_routing {
     use super::{
-        command_for_multi_slot_indices, AggregateOp, MultiSlotArgPattern, MultipleNodeRoutingInfo,
-        ResponsePolicy, Route, RoutingInfo, ShardAddrs, SingleNodeRoutingInfo, SlotAddr,
+        command_for_multi_slot_indices, first_key, single_key, AggregateOp, MultiSlotArgPattern,
+        MultipleNodeRoutingInfo, ResponsePolicy, Route, RoutingInfo, ShardAddrs,
+        SingleNodeRoutingInfo, SlotAddr,
     };
     use crate::cluster_routing::ShardUpdateResult;
     use crate::{cluster_topology::slot, cmd, parser::parse_redis_value, Value};
@@ -1683,6 +1774,70 @@ mod tests_routing {
             ]).unwrap()), Some(RoutingInfo::SingleNode(SingleNodeRoutingInfo::SpecificNode(Route(slot, SlotAddr::Master)))) if slot == 5210));
     }
 
+    #[test]
+    fn test_first_key() {
+        let mut get = cmd("GET");
+        get.arg("foo");
+        assert_eq!(first_key(&get), Some(&b"foo"[..]));
+
+        let mut mget = cmd("MGET");
+        mget.arg("foo").arg("bar");
+        assert_eq!(first_key(&mget), Some(&b"foo"[..]));
+
+        let mut eval = cmd("EVAL");
+        eval.arg("script").arg(1).arg("foo");
+        assert_eq!(first_key(&eval), Some(&b"foo"[..]));
+
+        let mut eval_without_keys = cmd("EVAL");
+        eval_without_keys.arg("script").arg(0);
+        assert_eq!(first_key(&eval_without_keys), None);
+
+        let mut xread = cmd("XREAD");
+        xread.arg("COUNT").arg(2).arg("STREAMS").arg("foo").arg(0);
+        assert_eq!(first_key(&xread), Some(&b"foo"[..]));
+
+        assert_eq!(first_key(&cmd("FLUSHALL")), None);
+    }
+
+    #[test]
+    fn test_single_key() {
+        let mut get = cmd("GET");
+        get.arg("foo");
+        assert_eq!(single_key(&get), Some(&b"foo"[..]));
+
+        let mut mget = cmd("MGET");
+        mget.arg("foo");
+        assert_eq!(single_key(&mget), Some(&b"foo"[..]));
+        mget.arg("bar");
+        assert_eq!(single_key(&mget), None);
+
+        let mut mset = cmd("MSET");
+        mset.arg("foo").arg("bar");
+        assert_eq!(single_key(&mset), Some(&b"foo"[..]));
+        mset.arg("baz").arg("qux");
+        assert_eq!(single_key(&mset), None);
+
+        let mut eval = cmd("EVAL");
+        eval.arg("script").arg(1).arg("foo");
+        assert_eq!(single_key(&eval), Some(&b"foo"[..]));
+        let mut eval = cmd("EVAL");
+        eval.arg("script").arg(2).arg("foo").arg("bar");
+        assert_eq!(single_key(&eval), None);
+
+        let mut xread = cmd("XREAD");
+        xread.arg("STREAMS").arg("foo").arg(0);
+        assert_eq!(single_key(&xread), Some(&b"foo"[..]));
+        let mut xread = cmd("XREAD");
+        xread.arg("STREAMS").arg("foo").arg("bar").arg(0).arg(0);
+        assert_eq!(single_key(&xread), None);
+
+        let mut rename = cmd("RENAME");
+        rename.arg("foo").arg("bar");
+        assert_eq!(single_key(&rename), None);
+
+        assert_eq!(single_key(&cmd("FLUSHALL")), None);
+    }
+
     #[test]
     fn test_multi_shard_keys_only() {
         let mut cmd = cmd("DEL");
@@ -35,6 +35,9 @@ pub enum ReadFromReplicaStrategy {
     /// Spread the read requests among nodes within the client's Availability Zone (AZ) in a round robin manner,
     /// prioritizing local replicas, then the local primary, and falling back to any replica or the primary if needed.
     AZAffinityReplicasAndPrimary(String),
+    /// Route the read requests to the replica with the lowest measured round-trip latency,
+    /// falling back to round robin until latencies are measured, and to the primary if no replica is available.
+    LowestLatency,
 }
 
 #[derive(Debug, Default)]
@@ -55,7 +58,8 @@ fn get_address_from_slot(
     }
     match read_from_replica {
         ReadFromReplicaStrategy::AlwaysFromPrimary => addrs.primary(),
-        ReadFromReplicaStrategy::RoundRobin => {
+        // Latency is only tracked by the async cluster client.
+        ReadFromReplicaStrategy::RoundRobin | ReadFromReplicaStrategy::LowestLatency => {
             let index = slot
                 .last_used_replica
                 .fetch_add(1, std::sync::atomic::Ordering::Relaxed)
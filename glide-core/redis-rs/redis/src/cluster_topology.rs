@@ -1,11 +1,12 @@
 //! This module provides the functionality to refresh and calculate the cluster topology for Redis Cluster.
 
 use crate::cluster::get_connection_addr;
+use crate::cluster_client::AddressMapper;
 #[cfg(feature = "cluster-async")]
 use crate::cluster_client::SlotsRefreshRateLimit;
 use crate::cluster_routing::Slot;
 use crate::cluster_slotmap::{ReadFromReplicaStrategy, SlotMap};
-use crate::{cluster::TlsMode, ErrorKind, RedisError, RedisResult, Value};
+use crate::{cluster::TlsMode, from_redis_value, ErrorKind, RedisError, RedisResult, Value};
 #[cfg(all(feature = "cluster-async", not(feature = "tokio-comp")))]
 use async_std::sync::RwLock;
 use std::collections::{hash_map::DefaultHasher, HashMap};
@@ -34,6 +35,8 @@ pub const DEFAULT_SLOTS_REFRESH_MAX_JITTER_MILLI: u64 = 15 * 1000; // 15 seconds
 
 pub(crate) const SLOT_SIZE: u16 = 16384;
 pub(crate) type TopologyHash = u64;
+/// The availability zones of the nodes, by their address.
+pub(crate) type NodeAzs = HashMap<String, String>;
 
 /// Represents the state of slot refresh operations.
 #[cfg(feature = "cluster-async")]
@@ -43,6 +46,10 @@ pub(crate) struct SlotRefreshState {
     /// The last slot refresh run timestamp
     pub(crate) last_run: Arc<RwLock<Option<SystemTime>>>,
     pub(crate) rate_limiter: SlotsRefreshRateLimit,
+    /// Set once a node rejects `CLUSTER SHARDS`, after which the topology is discovered with `CLUSTER SLOTS` only.
+    /// Cleared when the topology changes or the client reconnects to the initial nodes, since the nodes might have
+    /// been upgraded or their ACL changed.
+    pub(crate) cluster_shards_unsupported: AtomicBool,
 }
 
 #[cfg(feature = "cluster-async")]
@@ -52,6 +59,7 @@ impl SlotRefreshState {
             in_progress: AtomicBool::new(false),
             last_run: Arc::new(RwLock::new(None)),
             rate_limiter,
+            cluster_shards_unsupported: AtomicBool::new(false),
         }
     }
 }
@@ -61,6 +69,8 @@ pub(crate) struct TopologyView {
     pub(crate) hash_value: TopologyHash,
     pub(crate) nodes_count: u16,
     slots_and_count: (u16, Vec<Slot>),
+    // Not part of the hash, since a change of availability zones doesn't change the routing.
+    node_azs: NodeAzs,
 }
 
 impl PartialEq for TopologyView {
@@ -94,12 +104,26 @@ pub fn get_slot(key: &[u8]) -> u16 {
     slot(key)
 }
 
+// Returns the address to connect to for the node that announced `hostname`:`port`.
+fn node_addr(
+    hostname: String,
+    port: u16,
+    tls: Option<TlsMode>,
+    address_mapper: Option<&dyn AddressMapper>,
+) -> String {
+    let (hostname, port) = address_mapper
+        .and_then(|address_mapper| address_mapper.map_address(&hostname, port))
+        .unwrap_or((hostname, port));
+    get_connection_addr(hostname, port, tls, None).to_string()
+}
+
 // Parse slot data from raw redis value.
 pub(crate) fn parse_and_count_slots(
     raw_slot_resp: &Value,
     tls: Option<TlsMode>,
     // The DNS address of the node from which `raw_slot_resp` was received.
     addr_of_answering_node: &str,
+    address_mapper: Option<&dyn AddressMapper>,
 ) -> RedisResult<(u16, Vec<Slot>)> {
     // Parse response.
     let mut slots = Vec::with_capacity(2);
@@ -158,9 +182,7 @@ pub(crate) fn parse_and_count_slots(
                         } else {
                             return None;
                         };
-                        Some(
-                            get_connection_addr(hostname.into_owned(), port, tls, None).to_string(),
-                        )
+                        Some(node_addr(hostname.into_owned(), port, tls, address_mapper))
                     } else {
                         None
                     }
@@ -190,6 +212,142 @@ pub(crate) fn parse_and_count_slots(
     Ok((count, slots))
 }
 
+/// Returns the fields of an entry in a `CLUSTER SHARDS` reply, which is a map in RESP3, and a flat array of
+/// alternating field names and values in RESP2.
+fn shards_reply_fields(entry: &Value) -> Option<Vec<(String, &Value)>> {
+    match entry {
+        Value::Map(fields) => fields
+            .iter()
+            .map(|(name, value)| Some((from_redis_value(name).ok()?, value)))
+            .collect(),
+        Value::Array(items) if items.len() % 2 == 0 => items
+            .chunks_exact(2)
+            .map(|pair| Some((from_redis_value(&pair[0]).ok()?, &pair[1])))
+            .collect(),
+        _ => None,
+    }
+}
+
+fn shards_reply_field<'a>(fields: &[(String, &'a Value)], name: &str) -> Option<&'a Value> {
+    fields
+        .iter()
+        .find_map(|(field_name, value)| (field_name == name).then_some(*value))
+}
+
+fn shards_reply_string(fields: &[(String, &Value)], name: &str) -> Option<String> {
+    shards_reply_field(fields, name).and_then(|value| from_redis_value(value).ok())
+}
+
+// Returns true if `topology_view` is a `CLUSTER SHARDS` reply, whose entries are maps of fields, rather than
+// a `CLUSTER SLOTS` reply, whose entries are arrays that start with the slot range.
+fn is_shards_reply(topology_view: &Value) -> bool {
+    let Value::Array(entries) = topology_view else {
+        return false;
+    };
+    entries.first().is_some_and(|entry| match entry {
+        Value::Map(_) => true,
+        Value::Array(items) => !matches!(items.first(), Some(Value::Int(_))),
+        _ => false,
+    })
+}
+
+// Parse slot data from a raw `CLUSTER SHARDS` reply, along with the availability zones of the nodes.
+// Replicas that aren't online, e.g. since they are loading or failed, are left out.
+pub(crate) fn parse_and_count_shards(
+    raw_shards_resp: &Value,
+    tls: Option<TlsMode>,
+    // The DNS address of the node from which `raw_shards_resp` was received.
+    addr_of_answering_node: &str,
+    address_mapper: Option<&dyn AddressMapper>,
+) -> RedisResult<((u16, Vec<Slot>), NodeAzs)> {
+    let mut slots = Vec::with_capacity(2);
+    let mut count = 0;
+    let mut node_azs = NodeAzs::new();
+
+    let shards = match raw_shards_resp {
+        Value::Array(shards) => shards.as_slice(),
+        _ => &[],
+    };
+    for shard in shards {
+        let Some(shard) = shards_reply_fields(shard) else {
+            continue;
+        };
+        let slot_ranges: Vec<u16> = match shards_reply_field(&shard, "slots") {
+            Some(Value::Array(bounds)) => bounds
+                .iter()
+                .filter_map(|bound| from_redis_value::<u16>(bound).ok())
+                .collect(),
+            _ => continue,
+        };
+        let Some(Value::Array(nodes)) = shards_reply_field(&shard, "nodes") else {
+            continue;
+        };
+
+        let mut primary = None;
+        let mut replicas = Vec::new();
+        for node in nodes {
+            let Some(node) = shards_reply_fields(node) else {
+                continue;
+            };
+            // As in CLUSTER SLOTS, an empty endpoint stands for the responding node, and "?" for an unknown node.
+            let hostname = match shards_reply_string(&node, "endpoint") {
+                Some(endpoint) if endpoint == "?" => continue,
+                Some(endpoint) if !endpoint.is_empty() => endpoint,
+                _ => addr_of_answering_node.to_string(),
+            };
+            if hostname.is_empty() {
+                continue;
+            }
+            let port = tls
+                .and_then(|_| shards_reply_field(&node, "tls-port"))
+                .or_else(|| shards_reply_field(&node, "port"))
+                .and_then(|port| from_redis_value::<u16>(port).ok());
+            let Some(port) = port else {
+                continue;
+            };
+            let addr = node_addr(hostname, port, tls, address_mapper);
+
+            match shards_reply_string(&node, "role").as_deref() {
+                Some("master") | Some("primary") => primary = Some(addr.clone()),
+                Some("replica") | Some("slave")
+                    if shards_reply_string(&node, "health").as_deref() == Some("online") =>
+                {
+                    replicas.push(addr.clone())
+                }
+                _ => continue,
+            }
+            if let Some(az) = shards_reply_string(&node, "availability-zone") {
+                if !az.is_empty() {
+                    node_azs.insert(addr, az);
+                }
+            }
+        }
+
+        let Some(primary) = primary else {
+            continue;
+        };
+        // we sort the replicas, because different nodes in a cluster might return the same shard
+        // with different order of the replicas, which might cause the views to be considered evaluated as not equal.
+        replicas.sort_unstable();
+        for range in slot_ranges.chunks_exact(2) {
+            let (start, end) = (range[0], range[1]);
+            count += end - start;
+            slots.push(Slot::new(start, end, primary.clone(), replicas.clone()));
+        }
+    }
+    if slots.is_empty() {
+        return Err(RedisError::from((
+            ErrorKind::ResponseError,
+            "Error parsing shards: No healthy node found",
+            format!("Raw shards response: {raw_shards_resp:?}"),
+        )));
+    }
+    // Shards are listed in no particular order, so the slots are sorted to get the same hash for the same view.
+    slots.sort_unstable_by_key(|slot| slot.start);
+
+    Ok(((count, slots), node_azs))
+}
+
 fn calculate_hash<T: Hash>(t: &T) -> u64 {
     let mut s = DefaultHasher::new();
     t.hash(&mut s);
@@ -202,15 +360,23 @@ pub(crate) fn calculate_topology<'a>(
     tls_mode: Option<TlsMode>,
     num_of_queried_nodes: usize,
     read_from_replica: ReadFromReplicaStrategy,
-) -> RedisResult<(SlotMap, TopologyHash)> {
+    address_mapper: Option<&dyn AddressMapper>,
+) -> RedisResult<(SlotMap, TopologyHash, NodeAzs)> {
     let mut hash_view_map = HashMap::new();
     for (host, view) in topology_views {
-        if let Ok(slots_and_count) = parse_and_count_slots(view, tls_mode, host) {
+        let parsed_view = if is_shards_reply(view) {
+            parse_and_count_shards(view, tls_mode, host, address_mapper)
+        } else {
+            parse_and_count_slots(view, tls_mode, host, address_mapper)
+                .map(|slots_and_count| (slots_and_count, NodeAzs::new()))
+        };
+        if let Ok((slots_and_count, node_azs)) = parsed_view {
             let hash_value = calculate_hash(&slots_and_count);
             let topology_entry = hash_view_map.entry(hash_value).or_insert(TopologyView {
                 hash_value,
                 nodes_count: 0,
                 slots_and_count,
+                node_azs,
             });
             topology_entry.nodes_count += 1;
         }
@@ -259,6 +425,7 @@ pub(crate) fn calculate_topology<'a>(
         Ok((
             SlotMap::new(slots_data, read_from_replica),
             most_frequent_topology.hash_value,
+            most_frequent_topology.node_azs,
         ))
     };
 
@@ -386,8 +553,8 @@ mod tests {
             ),
         ]);
 
-        let res1 = parse_and_count_slots(&view1, None, "foo").unwrap();
-        let res2 = parse_and_count_slots(&view2, None, "foo").unwrap();
+        let res1 = parse_and_count_slots(&view1, None, "foo", None).unwrap();
+        let res2 = parse_and_count_slots(&view2, None, "foo", None).unwrap();
         assert_eq!(calculate_hash(&res1), calculate_hash(&res2));
         assert_eq!(res1.0, res2.0);
         assert_eq!(res1.1.len(), res2.1.len());
@@ -403,11 +570,29 @@ mod tests {
     fn parse_slots_returns_slots_with_host_name_if_missing() {
         let view = Value::Array(vec![slot_value(0, 4000, "", 6379)]);
 
-        let (slot_count, slots) = parse_and_count_slots(&view, None, "node").unwrap();
+        let (slot_count, slots) = parse_and_count_slots(&view, None, "node", None).unwrap();
         assert_eq!(slot_count, 4000);
         assert_eq!(slots[0].master(), "node:6379");
     }
 
+    #[test]
+    fn parse_slots_maps_announced_addresses() {
+        let view = Value::Array(vec![slot_value_with_replicas(
+            0,
+            16383,
+            vec![("internal1", 6379), ("internal2", 6379)],
+        )]);
+        let address_mapper = HashMap::from([(
+            ("internal1".to_string(), 6379),
+            ("localhost".to_string(), 7000),
+        )]);
+
+        let (_, slots) = parse_and_count_slots(&view, None, "node", Some(&address_mapper)).unwrap();
+        assert_eq!(slots[0].master(), "localhost:7000");
+        // Addresses without a mapping are kept as announced
+        assert_eq!(slots[0].replicas(), vec!["internal2:6379".to_string()]);
+    }
+
     #[test]
     fn should_parse_and_hash_regardless_of_missing_host_name_and_replicas_order() {
         let view1 = Value::Array(vec![
@@ -440,8 +625,8 @@ mod tests {
             ),
         ]);
 
-        let res1 = parse_and_count_slots(&view1, None, "node1").unwrap();
-        let res2 = parse_and_count_slots(&view2, None, "node3").unwrap();
+        let res1 = parse_and_count_slots(&view1, None, "node1", None).unwrap();
+        let res2 = parse_and_count_slots(&view2, None, "node3", None).unwrap();
 
         assert_eq!(calculate_hash(&res1), calculate_hash(&res2));
         assert_eq!(res1.0, res2.0);
@@ -460,6 +645,158 @@ mod tests {
         assert!(replicas_check);
     }
 
+    fn shards_fields(fields: Vec<(&str, Value)>, resp3: bool) -> Value {
+        let fields = fields
+            .into_iter()
+            .map(|(name, value)| (Value::BulkString(name.as_bytes().to_vec()), value));
+        if resp3 {
+            Value::Map(fields.collect())
+        } else {
+            Value::Array(fields.flat_map(|(name, value)| [name, value]).collect())
+        }
+    }
+
+    fn shards_node(
+        endpoint: &str,
+        port: u16,
+        role: &str,
+        health: &str,
+        az: &str,
+    ) -> Vec<(&'static str, Value)> {
+        vec![
+            (
+                "id",
+                Value::BulkString(format!("{endpoint}-{port}").into_bytes()),
+            ),
+            ("port", Value::Int(port as i64)),
+            ("endpoint", Value::BulkString(endpoint.as_bytes().to_vec())),
+            ("role", Value::BulkString(role.as_bytes().to_vec())),
+            ("replication-offset", Value::Int(100)),
+            ("health", Value::BulkString(health.as_bytes().to_vec())),
+            (
+                "availability-zone",
+                Value::BulkString(az.as_bytes().to_vec()),
+            ),
+        ]
+    }
+
+    fn shards_entry(slots: &[u16], nodes: Vec<Vec<(&str, Value)>>, resp3: bool) -> Value {
+        shards_fields(
+            vec![
+                (
+                    "slots",
+                    Value::Array(slots.iter().map(|slot| Value::Int(*slot as i64)).collect()),
+                ),
+                (
+                    "nodes",
+                    Value::Array(
+                        nodes
+                            .into_iter()
+                            .map(|node| shards_fields(node, resp3))
+                            .collect(),
+                    ),
+                ),
+            ],
+            resp3,
+        )
+    }
+
+    fn shards_view(resp3: bool) -> Value {
+        Value::Array(vec![
+            shards_entry(
+                &[8001, 16383],
+                vec![
+                    shards_node("replica2_1", 6379, "replica", "online", "az-b"),
+                    shards_node("primary2", 6379, "master", "online", "az-a"),
+                    shards_node("replica2_2", 6379, "replica", "loading", "az-a"),
+                ],
+                resp3,
+            ),
+            shards_entry(
+                &[0, 4000, 4001, 8000],
+                vec![
+                    shards_node("", 6379, "master", "online", ""),
+                    shards_node("replica1_1", 6379, "replica", "failed", "az-b"),
+                    shards_node("?", 6379, "replica", "online", "az-b"),
+                ],
+                resp3,
+            ),
+        ])
+    }
+
+    #[test]
+    fn parse_shards_leaves_out_unhealthy_replicas_and_returns_azs() {
+        for resp3 in [true, false] {
+            let view = shards_view(resp3);
+            assert!(is_shards_reply(&view));
+            let ((slot_count, slots), node_azs) =
+                parse_and_count_shards(&view, None, "primary1", None).unwrap();
+            assert_eq!(slot_count, 16381);
+            let slots: Vec<_> = slots
+                .iter()
+                .map(|slot| (slot.start, slot.end, slot.master(), slot.replicas()))
+                .collect();
+            assert_eq!(
+                slots,
+                vec![
+                    (0, 4000, "primary1:6379", vec![]),
+                    (4001, 8000, "primary1:6379", vec![]),
+                    (
+                        8001,
+                        16383,
+                        "primary2:6379",
+                        vec!["replica2_1:6379".to_string()]
+                    ),
+                ]
+            );
+            assert_eq!(
+                node_azs,
+                NodeAzs::from([
+                    ("primary2:6379".to_string(), "az-a".to_string()),
+                    ("replica2_1:6379".to_string(), "az-b".to_string()),
+                ])
+            );
+        }
+    }
+
+    #[test]
+    fn parse_shards_and_slots_return_the_same_view() {
+        let slots_view = Value::Array(vec![
+            slot_value(0, 4000, "primary1", 6379),
+            slot_value(4001, 8000, "primary1", 6379),
+            slot_value_with_replicas(8001, 16383, vec![("primary2", 6379), ("replica2_1", 6379)]),
+        ]);
+        assert!(!is_shards_reply(&slots_view));
+        let slots_and_count = parse_and_count_slots(&slots_view, None, "primary1", None).unwrap();
+        let (shards_and_count, _) =
+            parse_and_count_shards(&shards_view(true), None, "primary1", None).unwrap();
+        assert_eq!(
+            calculate_hash(&slots_and_count),
+            calculate_hash(&shards_and_count)
+        );
+    }
+
+    #[test]
+    fn test_topology_calculator_returns_azs_of_shards_view() {
+        let topology_results = [
+            ("primary1", shards_view(true)),
+            ("primary2", shards_view(false)),
+        ];
+        let (_, _, node_azs) = calculate_topology(
+            topology_results.iter().map(|(addr, value)| (*addr, value)),
+            1,
+            None,
+            2,
+            ReadFromReplicaStrategy::AlwaysFromPrimary,
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            node_azs.get("primary2:6379").map(String::as_str),
+            Some("az-a")
+        );
+    }
+
     enum ViewType {
         SingleNodeViewFullCoverage,
         SingleNodeViewMissingSlots,
@@ -520,12 +857,13 @@ mod tests {
             get_view(&ViewType::TwoNodesViewFullCoverage),
         ];
 
-        let (topology_view, _) = calculate_topology(
+        let (topology_view, _, _) = calculate_topology(
             topology_results.iter().map(|(addr, value)| (*addr, value)),
             1,
             None,
             queried_nodes,
             ReadFromReplicaStrategy::AlwaysFromPrimary,
+            None,
         )
         .unwrap();
         let res = collect_shard_addrs(&topology_view);
@@ -549,6 +887,7 @@ mod tests {
             None,
             queried_nodes,
             ReadFromReplicaStrategy::AlwaysFromPrimary,
+            None,
         );
         assert!(topology_view.is_err());
     }
@@ -562,12 +901,13 @@ mod tests {
             get_view(&ViewType::TwoNodesViewFullCoverage),
             get_view(&ViewType::TwoNodesViewMissingSlots),
         ];
-        let (topology_view, _) = calculate_topology(
+        let (topology_view, _, _) = calculate_topology(
             topology_results.iter().map(|(addr, value)| (*addr, value)),
             3,
             None,
             queried_nodes,
             ReadFromReplicaStrategy::AlwaysFromPrimary,
+            None,
         )
         .unwrap();
         let res = collect_shard_addrs(&topology_view);
@@ -585,12 +925,13 @@ mod tests {
             get_view(&ViewType::TwoNodesViewFullCoverage),
             get_view(&ViewType::TwoNodesViewMissingSlots),
         ];
-        let (topology_view, _) = calculate_topology(
+        let (topology_view, _, _) = calculate_topology(
             topology_results.iter().map(|(addr, value)| (*addr, value)),
             1,
             None,
             queried_nodes,
             ReadFromReplicaStrategy::AlwaysFromPrimary,
+            None,
         )
         .unwrap();
         let res = collect_shard_addrs(&topology_view);
@@ -609,12 +950,13 @@ mod tests {
             get_view(&ViewType::SingleNodeViewMissingSlots),
             get_view(&ViewType::TwoNodesViewMissingSlots),
         ];
-        let (topology_view, _) = calculate_topology(
+        let (topology_view, _, _) = calculate_topology(
             topology_results.iter().map(|(addr, value)| (*addr, value)),
             1,
             None,
             queried_nodes,
             ReadFromReplicaStrategy::AlwaysFromPrimary,
+            None,
         )
         .unwrap();
         let res = collect_shard_addrs(&topology_view);
@@ -633,12 +975,13 @@ mod tests {
             get_view(&ViewType::TwoNodesViewMissingSlots),
             get_view(&ViewType::SingleNodeViewMissingSlots),
         ];
-        let (topology_view, _) = calculate_topology(
+        let (topology_view, _, _) = calculate_topology(
             topology_results.iter().map(|(addr, value)| (*addr, value)),
             1,
             None,
             queried_nodes,
             ReadFromReplicaStrategy::AlwaysFromPrimary,
+            None,
         )
         .unwrap();
         let res = collect_shard_addrs(&topology_view);
@@ -6,6 +6,7 @@ use futures_util::{
 };
 #[cfg(feature = "aio")]
 use std::pin::Pin;
+use std::sync::Arc;
 use std::{borrow::Borrow, fmt, io};
 
 use crate::connection::ConnectionLike;
@@ -33,6 +34,8 @@ pub struct Cmd {
     no_response: bool,
     /// The span associated with this command
     span: Option<GlideSpan>,
+    /// Commands sent on the same connection right before and right after this command
+    surrounding_commands: Option<Arc<(Cmd, Cmd)>>,
 }
 
 /// Represents a redis iterator.
@@ -326,6 +329,7 @@ impl Cmd {
             cursor: None,
             no_response: false,
             span: None,
+            surrounding_commands: None,
         }
     }
 
@@ -337,6 +341,7 @@ impl Cmd {
             cursor: None,
             no_response: false,
             span: None,
+            surrounding_commands: None,
         }
     }
 
@@ -376,6 +381,19 @@ impl Cmd {
         self
     }
 
+    /// Sets the commands that are sent on the same connection right before and right after this command, for
+    /// example to tag the connection while the command executes. The responses of the surrounding commands
+    /// are discarded.
+    ///
+    /// Only single commands that are sent with [`crate::aio::req_command_with_surroundings`] are surrounded.
+    /// Important - this function is meant for internal usage by glide-core's trace context propagation.
+    #[doc(hidden)]
+    #[inline]
+    pub fn set_surrounding_commands(&mut self, commands: Option<(Cmd, Cmd)>) -> &mut Cmd {
+        self.surrounding_commands = commands.map(Arc::new);
+        self
+    }
+
     /// Works similar to `arg` but adds a cursor argument.  This is always
     /// an integer and also flips the command implementation to support a
     /// different mode for the iterators where the iterator will ask for
@@ -604,6 +622,13 @@ impl Cmd {
     pub fn span(&self) -> Option<GlideSpan> {
         self.span.clone()
     }
+
+    /// Returns the commands sent right before and right after this command, if any.
+    #[doc(hidden)]
+    #[inline]
+    pub fn surrounding_commands(&self) -> Option<&(Cmd, Cmd)> {
+        self.surrounding_commands.as_deref()
+    }
 }
 
 impl fmt::Debug for Cmd {
@@ -219,6 +219,57 @@ pub type PubSubChannelOrPattern = Vec<u8>;
 /// Type for pubsub channels/patterns
 pub type PubSubSubscriptionInfo = HashMap<PubSubSubscriptionKind, HashSet<PubSubChannelOrPattern>>;
 
+/// Adds the given channels/patterns of `kind` to `subscriptions`.
+pub(crate) fn add_pubsub_subscriptions(
+    subscriptions: &mut PubSubSubscriptionInfo,
+    kind: PubSubSubscriptionKind,
+    channels_or_patterns: &[PubSubChannelOrPattern],
+) {
+    if channels_or_patterns.is_empty() {
+        return;
+    }
+    subscriptions
+        .entry(kind)
+        .or_default()
+        .extend(channels_or_patterns.iter().cloned());
+}
+
+/// Removes the given channels/patterns of `kind` from `subscriptions`, or all of them if `channels_or_patterns` is empty,
+/// the same way an `UNSUBSCRIBE` without arguments does.
+/// Returns the removed channels/patterns.
+pub(crate) fn remove_pubsub_subscriptions(
+    subscriptions: &mut PubSubSubscriptionInfo,
+    kind: PubSubSubscriptionKind,
+    channels_or_patterns: &[PubSubChannelOrPattern],
+) -> Vec<PubSubChannelOrPattern> {
+    let Some(current) = subscriptions.get_mut(&kind) else {
+        return Vec::new();
+    };
+    let removed = if channels_or_patterns.is_empty() {
+        current.drain().collect()
+    } else {
+        channels_or_patterns
+            .iter()
+            .filter(|channel_or_pattern| current.remove(*channel_or_pattern))
+            .cloned()
+            .collect()
+    };
+    if current.is_empty() {
+        subscriptions.remove(&kind);
+    }
+    removed
+}
+
+/// Mode of the server-assisted client side caching, enabled on each connection with `CLIENT TRACKING ON`.
+/// See <https://valkey.io/topics/client-side-caching/> for more details.
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub enum ClientTrackingMode {
+    /// The server remembers the keys read by the connection, and sends invalidation messages only for them.
+    Default,
+    /// The server sends invalidation messages for every modified key, regardless of the keys read by the connection.
+    Broadcast,
+}
+
 /// Redis specific/connection independent information used to establish a connection to redis.
 #[derive(Clone, Debug, Default)]
 pub struct RedisConnectionInfo {
@@ -236,6 +287,8 @@ pub struct RedisConnectionInfo {
     pub lib_name: Option<String>,
     /// Optionally a pubsub subscriptions that should be used for connection
     pub pubsub_subscriptions: Option<PubSubSubscriptionInfo>,
+    /// Optionally enables key tracking for client side caching. Requires RESP3, since invalidations are received as push messages.
+    pub client_tracking: Option<ClientTrackingMode>,
 }
 
 impl FromStr for ConnectionInfo {
@@ -395,6 +448,7 @@ fn url_to_tcp_connection_info(url: url::Url) -> RedisResult<ConnectionInfo> {
             client_name: None,
             lib_name: None,
             pubsub_subscriptions: None,
+            client_tracking: None,
         },
     })
 }
@@ -429,6 +483,7 @@ fn url_to_unix_connection_info(url: url::Url) -> RedisResult<ConnectionInfo> {
             client_name: None,
             lib_name: None,
             pubsub_subscriptions: None,
+            client_tracking: None,
         },
     })
 }
@@ -1733,6 +1788,39 @@ pub fn get_resp3_hello_command_error(err: RedisError) -> RedisError {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_remove_pubsub_subscriptions() {
+        let mut subscriptions = PubSubSubscriptionInfo::new();
+        add_pubsub_subscriptions(
+            &mut subscriptions,
+            PubSubSubscriptionKind::Exact,
+            &[b"foo".to_vec(), b"bar".to_vec()],
+        );
+        add_pubsub_subscriptions(
+            &mut subscriptions,
+            PubSubSubscriptionKind::Pattern,
+            &[b"news.*".to_vec()],
+        );
+
+        let removed = remove_pubsub_subscriptions(
+            &mut subscriptions,
+            PubSubSubscriptionKind::Exact,
+            &[b"foo".to_vec(), b"unknown".to_vec()],
+        );
+        assert_eq!(removed, vec![b"foo".to_vec()]);
+        assert_eq!(
+            subscriptions.get(&PubSubSubscriptionKind::Exact),
+            Some(&HashSet::from([b"bar".to_vec()]))
+        );
+
+        // Without channels, all subscriptions of the kind are removed.
+        let removed =
+            remove_pubsub_subscriptions(&mut subscriptions, PubSubSubscriptionKind::Exact, &[]);
+        assert_eq!(removed, vec![b"bar".to_vec()]);
+        assert!(!subscriptions.contains_key(&PubSubSubscriptionKind::Exact));
+        assert!(subscriptions.contains_key(&PubSubSubscriptionKind::Pattern));
+    }
+
     #[test]
     fn test_client_set_info_pipeline_default_lib_name() {
         let pipeline = client_set_info_pipeline(None);
@@ -1877,6 +1965,7 @@ mod tests {
                         client_name: None,
                         lib_name: None,
                         pubsub_subscriptions: None,
+                        client_tracking: None,
                     },
                 },
             ),
@@ -683,6 +683,25 @@ impl Sentinel {
         }
         try_connect_to_first_replica(&addresses, Some(self.replica_start_index))
     }
+
+    /// Returns the `(ip, port)` of the master with the given name, along with the addresses
+    /// of its replicas, as reported by the sentinels. Unlike [Sentinel::async_master_for],
+    /// the nodes' roles aren't verified, leaving it to the caller to connect to the nodes.
+    pub async fn async_master_and_replica_addresses_for(
+        &mut self,
+        service_name: &str,
+    ) -> RedisResult<((String, u16), Vec<(String, u16)>)> {
+        let masters = self.async_get_sentinel_masters().await?;
+        let Some(master) = valid_addrs(masters, |m| is_master_valid(m, service_name)).next()
+        else {
+            fail!((
+                ErrorKind::MasterNameNotFoundBySentinel,
+                "Master with given name not found in sentinel",
+            ))
+        };
+        let replicas = self.async_get_sentinel_replicas(service_name).await?;
+        Ok((master, valid_addrs(replicas, is_replica_valid).collect()))
+    }
 }
 
 /// Enum defining the server types from a sentinel's point of view.
@@ -1,5 +1,8 @@
 use std::io::{Error, ErrorKind as IOErrorKind};
+use std::sync::Arc;
 
+use rustls::crypto::CryptoProvider;
+use rustls::sign::CertifiedKey;
 use rustls::RootCertStore;
 use rustls_pki_types::pem::PemObject;
 use rustls_pki_types::{CertificateDer, PrivateKeyDer};
@@ -89,6 +92,8 @@ pub fn retrieve_tls_certificates(certificates: TlsCertificates) -> RedisResult<T
             )
         })?;
 
+        validate_client_key_matches_certificate(&client_cert_chain, &client_key)?;
+
         Some(ClientTlsParams {
             client_cert_chain,
             client_key,
@@ -126,6 +131,32 @@ pub fn retrieve_tls_certificates(certificates: TlsCertificates) -> RedisResult<T
     })
 }
 
+/// Fails early on a client certificate and key that don't belong together, instead of failing
+/// each TLS handshake later on.
+fn validate_client_key_matches_certificate(
+    client_cert_chain: &[CertificateDer<'static>],
+    client_key: &PrivateKeyDer<'static>,
+) -> RedisResult<()> {
+    if client_cert_chain.is_empty() {
+        return Err(RedisError::from((
+            ErrorKind::InvalidClientConfig,
+            "No client certificate found",
+        )));
+    }
+    let provider = CryptoProvider::get_default()
+        .cloned()
+        .unwrap_or_else(|| Arc::new(rustls::crypto::aws_lc_rs::default_provider()));
+    CertifiedKey::from_der(client_cert_chain.to_vec(), client_key.clone_key(), &provider)
+        .map(|_| ())
+        .map_err(|err| {
+            RedisError::from((
+                ErrorKind::InvalidClientConfig,
+                "Client certificate and key do not match",
+                err.to_string(),
+            ))
+        })
+}
+
 #[derive(Debug)]
 pub struct ClientTlsParams {
     pub(crate) client_cert_chain: Vec<CertificateDer<'static>>,
@@ -14,6 +14,7 @@ use num_bigint::BigInt;
 pub(crate) use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
 use strum_macros::Display;
+use telemetrylib::RequestOutcome;
 
 macro_rules! invalid_type_error {
     ($v:expr, $det:expr) => {{
@@ -156,6 +157,9 @@ pub enum ErrorKind {
     /// Used when an error occurs on when user perform wrong usage of management operation.
     /// E.g. not allowed configuration change.
     UserOperationError,
+
+    /// The circuit breaker of the target node is open, so the request was not sent.
+    CircuitOpen,
 }
 
 #[derive(PartialEq, Debug, Clone, Display, Copy)]
@@ -870,6 +874,71 @@ pub(crate) enum RetryMethod {
     WaitAndRetryOnPrimaryRedirectOnReplica,
 }
 
+/// Defines which errors a single command is retried on, overriding the client's default retry behavior.
+///
+/// # Notes
+/// - Retrying on **connection errors** is only safe for idempotent commands, since it is unclear whether the
+///   command was executed before the connection failed. Commands that are known not to have been sent are retried
+///   regardless, as long as retries remain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommandRetryPolicy {
+    /// Overrides the client's maximum number of retries. `Some(0)` disables all retries.
+    pub max_retries: Option<u32>,
+    /// If `true`, the command follows `MOVED` and `ASK` redirections.
+    pub retry_redirects: bool,
+    /// If `true`, the command is retried on transient server errors, such as `TRYAGAIN` and `LOADING`.
+    pub retry_server_errors: bool,
+    /// If `true`, the command is retried after reconnecting, when the connection failed while it was in flight.
+    pub retry_connection_errors: bool,
+}
+
+impl Default for CommandRetryPolicy {
+    /// Follows redirections and retries transient server errors, but doesn't retry commands whose connection
+    /// failed while they were in flight, since they might have already been executed.
+    fn default() -> Self {
+        Self {
+            max_retries: None,
+            retry_redirects: true,
+            retry_server_errors: true,
+            retry_connection_errors: false,
+        }
+    }
+}
+
+impl CommandRetryPolicy {
+    /// A policy that never retries the command.
+    pub fn never() -> Self {
+        Self {
+            max_retries: Some(0),
+            retry_redirects: false,
+            retry_server_errors: false,
+            retry_connection_errors: false,
+        }
+    }
+
+    /// Returns the maximum number of retries, falling back to the client's `default_max_retries`.
+    pub fn max_retries(&self, default_max_retries: u32) -> u32 {
+        self.max_retries.unwrap_or(default_max_retries)
+    }
+
+    /// Returns whether the policy allows retrying a command that failed with `err`.
+    pub fn allows_retry(&self, err: &RedisError) -> bool {
+        self.allows(err.retry_method())
+    }
+
+    pub(crate) fn allows(&self, retry_method: RetryMethod) -> bool {
+        match retry_method {
+            RetryMethod::AskRedirect | RetryMethod::MovedRedirect => self.retry_redirects,
+            RetryMethod::RetryImmediately
+            | RetryMethod::WaitAndRetry
+            | RetryMethod::WaitAndRetryOnPrimaryRedirectOnReplica => self.retry_server_errors,
+            RetryMethod::Reconnect => self.retry_connection_errors,
+            RetryMethod::ReconnectAndRetry => true,
+            RetryMethod::NoRetry => false,
+        }
+    }
+}
+
 /// Indicates a general failure in the library.
 impl RedisError {
     /// Returns the kind of the error.
@@ -947,6 +1016,7 @@ impl RedisError {
             ErrorKind::ParseError => "parse error",
             ErrorKind::NotAllSlotsCovered => "not all slots are covered",
             ErrorKind::UserOperationError => "Wrong usage of management operation",
+            ErrorKind::CircuitOpen => "circuit breaker is open",
         }
     }
 
@@ -1151,6 +1221,7 @@ impl RedisError {
             ErrorKind::FatalReceiveError => RetryMethod::Reconnect,
             ErrorKind::FatalSendError => RetryMethod::ReconnectAndRetry,
             ErrorKind::UserOperationError => RetryMethod::NoRetry,
+            ErrorKind::CircuitOpen => RetryMethod::NoRetry,
         }
     }
 }
@@ -2367,6 +2438,16 @@ pub fn from_owned_redis_value<T: FromRedisValue>(v: Value) -> RedisResult<T> {
     FromRedisValue::from_owned_redis_value(v)
 }
 
+/// Returns the outcome of a request with `result`, as recorded by the request metrics.
+pub(crate) fn request_outcome<T>(result: &RedisResult<T>) -> RequestOutcome {
+    match result {
+        Ok(_) => RequestOutcome::Success,
+        Err(err) if err.is_timeout() => RequestOutcome::Timeout,
+        Err(err) if err.code().is_some() => RequestOutcome::ServerError,
+        Err(_) => RequestOutcome::Error,
+    }
+}
+
 /// Enum representing the communication protocol with the server. This enum represents the types
 /// of data that the server can send to the client, and the capabilities that the client can use.
 #[derive(Clone, Eq, PartialEq, Default, Debug, Copy)]
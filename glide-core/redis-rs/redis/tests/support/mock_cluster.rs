@@ -35,6 +35,9 @@ pub struct MockConnectionBehavior {
     pub connection_id_provider: AtomicUsize,
     pub returned_ip_type: ConnectionIPReturnType,
     pub return_connection_err: ShouldReturnConnectionError,
+    /// Whether `CLUSTER SHARDS` is passed to the handler. Otherwise it's rejected like an older server would,
+    /// so that handlers that only answer `CLUSTER SLOTS` discover the topology with it.
+    pub answers_cluster_shards: bool,
 }
 
 impl MockConnectionBehavior {
@@ -45,6 +48,7 @@ impl MockConnectionBehavior {
             connection_id_provider: AtomicUsize::new(0),
             returned_ip_type: ConnectionIPReturnType::default(),
             return_connection_err: ShouldReturnConnectionError::default(),
+            answers_cluster_shards: false,
         }
     }
 
@@ -55,7 +59,18 @@ impl MockConnectionBehavior {
     }
 
     fn get_handler(&self) -> Handler {
-        self.handler.clone()
+        if self.answers_cluster_shards {
+            return self.handler.clone();
+        }
+        let handler = self.handler.clone();
+        Arc::new(move |cmd, port| {
+            if contains_slice(cmd, b"CLUSTER") && contains_slice(cmd, b"SHARDS") {
+                return Err(redis::parse_redis_value(
+                    b"-ERR unknown subcommand 'SHARDS'\r\n",
+                ));
+            }
+            handler(cmd, port)
+        })
     }
 }
 
@@ -459,6 +474,24 @@ impl MockEnv {
         client_builder: ClusterClientBuilder,
         id: &str,
         handler: impl Fn(&[u8], u16) -> Result<(), RedisResult<Value>> + Send + Sync + 'static,
+    ) -> Self {
+        Self::create(client_builder, id, handler, false)
+    }
+
+    /// Like [`MockEnv::with_client_builder`], but `CLUSTER SHARDS` is passed to `handler` instead of being rejected.
+    pub fn with_cluster_shards_handler(
+        client_builder: ClusterClientBuilder,
+        id: &str,
+        handler: impl Fn(&[u8], u16) -> Result<(), RedisResult<Value>> + Send + Sync + 'static,
+    ) -> Self {
+        Self::create(client_builder, id, handler, true)
+    }
+
+    fn create(
+        client_builder: ClusterClientBuilder,
+        id: &str,
+        handler: impl Fn(&[u8], u16) -> Result<(), RedisResult<Value>> + Send + Sync + 'static,
+        answers_cluster_shards: bool,
     ) -> Self {
         #[cfg(feature = "cluster-async")]
         let runtime = tokio::runtime::Builder::new_current_thread()
@@ -472,6 +505,9 @@ impl MockEnv {
             &id,
             Arc::new(move |cmd, port| handler(cmd, port)),
         );
+        modify_mock_connection_behavior(&id, |behavior| {
+            behavior.answers_cluster_shards = answers_cluster_shards
+        });
         let client = client_builder.build().unwrap();
         let connection = client.get_generic_connection(None).unwrap();
         #[cfg(feature = "cluster-async")]
@@ -32,10 +32,11 @@ mod cluster_async {
             MultipleNodeRoutingInfo, Route, RoutingInfo, SingleNodeRoutingInfo, SlotAddr,
         },
         cluster_topology::{get_slot, DEFAULT_NUMBER_OF_REFRESH_SLOTS_RETRIES},
-        cmd, from_owned_redis_value, parse_redis_value, AsyncCommands, Cmd, ConnectionAddr,
-        ErrorKind, FromRedisValue, GlideConnectionOptions, InfoDict, IntoConnectionInfo,
-        PipelineRetryStrategy, ProtocolVersion, PubSubChannelOrPattern, PubSubSubscriptionInfo,
-        PubSubSubscriptionKind, PushInfo, PushKind, RedisError, RedisFuture, RedisResult, Value,
+        cmd, from_owned_redis_value, parse_redis_value, AsyncCommands, Cmd, CommandRetryPolicy,
+        ConnectionAddr, ErrorKind, FromRedisValue, GlideConnectionOptions, InfoDict,
+        IntoConnectionInfo, PipelineRetryStrategy, ProtocolVersion, PubSubChannelOrPattern,
+        PubSubSubscriptionInfo, PubSubSubscriptionKind, PushInfo, PushKind, RedisError,
+        RedisFuture, RedisResult, Value,
     };
 
     use crate::support::*;
@@ -314,6 +315,30 @@ mod cluster_async {
         metric["data_points"][0]["value"].as_u64().unwrap_or(0)
     }
 
+    fn read_spans_json() -> Vec<serde_json::Value> {
+        let file_content =
+            std::fs::read_to_string(SPANS_JSON).expect("Failed to read spans JSON file");
+        file_content
+            .lines()
+            .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+            .filter(|span| span["span_id"].is_string())
+            .collect()
+    }
+
+    fn find_span<'a>(spans: &'a [serde_json::Value], name: &str) -> &'a serde_json::Value {
+        spans
+            .iter()
+            .find(|span| span["name"] == name)
+            .unwrap_or_else(|| panic!("Span '{name}' not found in {spans:?}"))
+    }
+
+    fn span_attribute<'a>(span: &'a serde_json::Value, key: &str) -> Option<&'a str> {
+        span["span_attributes"]
+            .as_array()?
+            .iter()
+            .find_map(|attribute| attribute[key].as_str())
+    }
+
     #[test]
     #[serial_test::serial]
     fn test_async_cluster_basic_cmd() {
@@ -780,6 +805,85 @@ mod cluster_async {
         });
     }
 
+    #[test]
+    #[serial_test::serial]
+    fn test_async_open_telemetry_request_spans() {
+        let name = "open_telemetry_request_spans";
+        shared_runtime().block_on(init_otel()).unwrap();
+        let _ = std::fs::remove_file(SPANS_JSON);
+
+        let requests = atomic::AtomicUsize::new(0);
+        let MockEnv {
+            runtime,
+            async_connection: mut connection,
+            handler: _handler,
+            ..
+        } = MockEnv::with_client_builder(
+            ClusterClient::builder(vec![&*format!("redis://{name}")]).retries(5),
+            name,
+            move |cmd: &[u8], _| {
+                respond_startup(name, cmd)?;
+
+                match requests.fetch_add(1, atomic::Ordering::SeqCst) {
+                    0 => Err(parse_redis_value(b"-TRYAGAIN mock\r\n")),
+                    1 => Err(parse_redis_value(
+                        format!("-MOVED 123 {name}:6379\r\n").as_bytes(),
+                    )),
+                    _ => Err(Ok(Value::BulkString(b"123".to_vec()))),
+                }
+            },
+        );
+
+        let span = GlideOpenTelemetry::new_span("GET");
+        let mut cmd = cmd("GET");
+        cmd.arg("test").set_span(Some(span.clone()));
+        let value = runtime
+            .block_on(connection.route_command(&cmd, RoutingInfo::for_routable(&cmd).unwrap()));
+        assert_eq!(value, Ok(Value::BulkString(b"123".to_vec())));
+        span.end();
+
+        std::thread::sleep(Duration::from_millis(PUBLISH_TIME + 100));
+
+        let spans = read_spans_json();
+        let request_span = find_span(&spans, "GET");
+        let address = format!("{name}:6379");
+        assert_eq!(
+            span_attribute(request_span, "server.address"),
+            Some(address.as_str())
+        );
+        assert_eq!(span_attribute(request_span, "db.namespace"), Some("0"));
+
+        let child_spans: Vec<_> = spans
+            .iter()
+            .filter(|span| span["parent_span_id"] == request_span["span_id"])
+            .collect();
+        assert!(child_spans
+            .iter()
+            .all(|span| span["trace_id"] == request_span["trace_id"]));
+        let retries: Vec<_> = child_spans
+            .iter()
+            .filter(|span| span["name"] == "retry")
+            .map(|span| {
+                (
+                    span_attribute(span, "retry.attempt"),
+                    span_attribute(span, "error.type"),
+                )
+            })
+            .collect();
+        assert_eq!(
+            retries,
+            vec![(Some("1"), Some("TRYAGAIN")), (Some("2"), Some("MOVED"))]
+        );
+        let moved_redirect = child_spans
+            .iter()
+            .find(|span| span["name"] == "moved_redirect")
+            .expect("No moved_redirect span");
+        assert_eq!(
+            span_attribute(moved_redirect, "redirect.address"),
+            Some(address.as_str())
+        );
+    }
+
     #[tokio::test]
     async fn test_routing_by_slot_to_replica_with_az_affinity_strategy_to_half_replicas() {
         test_az_affinity_helper(StrategyVariant::AZAffinity).await;
@@ -1796,6 +1900,112 @@ mod cluster_async {
         assert_eq!(requests.load(atomic::Ordering::SeqCst), 3);
     }
 
+    #[test]
+    #[serial_test::serial]
+    fn test_async_cluster_retry_policy_reports_retries() {
+        let name = "retry_policy_reports_retries";
+
+        let requests = atomic::AtomicUsize::new(0);
+        let MockEnv {
+            runtime,
+            async_connection: mut connection,
+            handler: _handler,
+            ..
+        } = MockEnv::with_client_builder(
+            ClusterClient::builder(vec![&*format!("redis://{name}")]).retries(5),
+            name,
+            move |cmd: &[u8], _| {
+                respond_startup(name, cmd)?;
+
+                match requests.fetch_add(1, atomic::Ordering::SeqCst) {
+                    0..=1 => Err(parse_redis_value(b"-TRYAGAIN mock\r\n")),
+                    _ => Err(Ok(Value::BulkString(b"123".to_vec()))),
+                }
+            },
+        );
+
+        let mut cmd = cmd("GET");
+        cmd.arg("test");
+        let result = runtime.block_on(connection.route_command_with_retry_policy(
+            &cmd,
+            RoutingInfo::for_routable(&cmd).unwrap(),
+            Some(CommandRetryPolicy::default()),
+        ));
+
+        assert_eq!(result, (Ok(Value::BulkString(b"123".to_vec())), 2));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_async_cluster_retry_policy_reports_retries_of_failed_request() {
+        let name = "retry_policy_reports_retries_of_failed_request";
+
+        let MockEnv {
+            runtime,
+            async_connection: mut connection,
+            handler: _handler,
+            ..
+        } = MockEnv::with_client_builder(
+            ClusterClient::builder(vec![&*format!("redis://{name}")]).retries(5),
+            name,
+            move |cmd: &[u8], _| {
+                respond_startup(name, cmd)?;
+                Err(parse_redis_value(b"-TRYAGAIN mock\r\n"))
+            },
+        );
+
+        let mut cmd = cmd("GET");
+        cmd.arg("test");
+        let (result, retries) = runtime.block_on(connection.route_command_with_retry_policy(
+            &cmd,
+            RoutingInfo::for_routable(&cmd).unwrap(),
+            Some(CommandRetryPolicy {
+                max_retries: Some(2),
+                ..Default::default()
+            }),
+        ));
+
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::TryAgain);
+        assert_eq!(retries, 2);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_async_cluster_retry_policy_disables_retries() {
+        let name = "retry_policy_disables_retries";
+
+        let requests = Arc::new(atomic::AtomicUsize::new(0));
+        let MockEnv {
+            runtime,
+            async_connection: mut connection,
+            handler: _handler,
+            ..
+        } = MockEnv::with_client_builder(
+            ClusterClient::builder(vec![&*format!("redis://{name}")]).retries(5),
+            name,
+            {
+                let requests = requests.clone();
+                move |cmd: &[u8], _| {
+                    respond_startup(name, cmd)?;
+                    requests.fetch_add(1, atomic::Ordering::SeqCst);
+                    Err(parse_redis_value(b"-TRYAGAIN mock\r\n"))
+                }
+            },
+        );
+
+        let mut cmd = cmd("GET");
+        cmd.arg("test");
+        let result = runtime.block_on(connection.route_command_with_retry_policy(
+            &cmd,
+            RoutingInfo::for_routable(&cmd).unwrap(),
+            Some(CommandRetryPolicy::never()),
+        ));
+
+        assert_eq!(result.0.unwrap_err().kind(), ErrorKind::TryAgain);
+        assert_eq!(result.1, 0);
+        assert_eq!(requests.load(atomic::Ordering::SeqCst), 1);
+    }
+
     // Obtain the view index associated with the node with [called_port] port
     fn get_node_view_index(num_of_views: usize, ports: &Vec<u16>, called_port: u16) -> usize {
         let port_index = ports
@@ -3364,6 +3574,274 @@ mod cluster_async {
         assert_eq!(value, Ok(Some(123)));
     }
 
+    #[test]
+    #[serial_test::serial]
+    fn test_async_cluster_maps_announced_addresses() {
+        let name = "test_async_cluster_maps_announced_addresses";
+        // The nodes announce internal addresses, which are only reachable after mapping them
+        let address_mapping = HashMap::from([
+            (("internal1".to_string(), 7000), (name.to_string(), 6379)),
+            (("internal2".to_string(), 7000), (name.to_string(), 6380)),
+        ]);
+        let requests = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let MockEnv {
+            async_connection: mut connection,
+            handler: _handler,
+            runtime,
+            ..
+        } = MockEnv::with_client_builder(
+            ClusterClient::builder(vec![&*format!("redis://{name}")])
+                .address_mapper(address_mapping),
+            name,
+            {
+                let requests = requests.clone();
+                move |cmd: &[u8], port| {
+                    if contains_slice(cmd, b"CLUSTER") && contains_slice(cmd, b"SLOTS") {
+                        let node = |host: &str| {
+                            Value::Array(vec![
+                                Value::BulkString(host.as_bytes().to_vec()),
+                                Value::Int(7000),
+                            ])
+                        };
+                        return Err(Ok(Value::Array(vec![
+                            Value::Array(vec![Value::Int(0), Value::Int(8191), node("internal1")]),
+                            Value::Array(vec![
+                                Value::Int(8192),
+                                Value::Int(16383),
+                                node("internal2"),
+                            ]),
+                        ])));
+                    }
+                    respond_startup(name, cmd)?;
+                    if contains_slice(cmd, b"ASKING") {
+                        return Err(Ok(Value::Okay));
+                    }
+                    requests.lock().unwrap().push(port);
+                    match port {
+                        6379 => Err(parse_redis_value(b"-ASK 6918 internal2:7000\r\n")),
+                        _ => Err(Ok(Value::BulkString(port.to_string().into_bytes()))),
+                    }
+                }
+            },
+        );
+
+        let value = runtime.block_on(
+            cmd("GET")
+                .arg("test")
+                .query_async::<_, String>(&mut connection),
+        );
+        assert_eq!(value, Ok("6380".to_string()));
+        assert_eq!(*requests.lock().unwrap(), vec![6379, 6380]);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_async_cluster_ask_sends_moved_keys_directly_to_migration_target() {
+        let name = "test_async_cluster_ask_sends_moved_keys_directly_to_migration_target";
+        let migration_aborted = Arc::new(AtomicBool::new(false));
+        let requests = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let MockEnv {
+            async_connection: mut connection,
+            handler: _handler,
+            runtime,
+            ..
+        } = MockEnv::new(name, {
+            let migration_aborted = migration_aborted.clone();
+            let requests = requests.clone();
+            move |cmd: &[u8], port| {
+                respond_startup_two_nodes(name, cmd)?;
+                let request = if contains_slice(cmd, b"ASKING") {
+                    "ASKING"
+                } else if contains_slice(cmd, b"{test}other") {
+                    "other"
+                } else {
+                    "test"
+                };
+                requests.lock().unwrap().push((port, request));
+                let migration_aborted = migration_aborted.load(Ordering::SeqCst);
+                match (port, request) {
+                    (_, "ASKING") => Err(Ok(Value::Okay)),
+                    // The key "test" was moved from slot 6918 of the first node to the second node
+                    (6379, "test") if !migration_aborted => Err(parse_redis_value(
+                        format!("-ASK 6918 {name}:6380\r\n").as_bytes(),
+                    )),
+                    // Once the migration is aborted, the second node no longer accepts the slot
+                    (6380, _) if migration_aborted => Err(parse_redis_value(
+                        format!("-MOVED 6918 {name}:6379\r\n").as_bytes(),
+                    )),
+                    _ => Err(Ok(Value::BulkString(port.to_string().into_bytes()))),
+                }
+            }
+        });
+
+        let mut get = |key: &str| {
+            runtime
+                .block_on(
+                    cmd("GET")
+                        .arg(key)
+                        .query_async::<_, String>(&mut connection),
+                )
+                .unwrap()
+        };
+
+        // The first request is redirected by the source node
+        assert_eq!(get("test"), "6380");
+        // Following requests for the moved key are sent directly to the target node
+        assert_eq!(get("test"), "6380");
+        // Keys of the slot that weren't moved are still sent to the source node
+        assert_eq!(get("{test}other"), "6379");
+        assert_eq!(
+            requests.lock().unwrap().drain(..).collect::<Vec<_>>(),
+            vec![
+                (6379, "test"),
+                (6380, "ASKING"),
+                (6380, "test"),
+                (6380, "ASKING"),
+                (6380, "test"),
+                (6379, "other"),
+            ]
+        );
+
+        // A MOVED error clears the slot's migration, so the key is sent to the slot's owner again
+        migration_aborted.store(true, Ordering::SeqCst);
+        assert_eq!(get("test"), "6379");
+        assert_eq!(get("test"), "6379");
+        assert_eq!(
+            requests.lock().unwrap().drain(..).collect::<Vec<_>>(),
+            vec![
+                (6380, "ASKING"),
+                (6380, "test"),
+                (6379, "test"),
+                (6379, "test"),
+            ]
+        );
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_async_cluster_shards_discovery_leaves_out_loading_replicas() {
+        let name = "test_async_cluster_shards_discovery_leaves_out_loading_replicas";
+        let shards_node = |port: i64, role: &str, health: &str| {
+            Value::Array(vec![
+                Value::BulkString(b"port".to_vec()),
+                Value::Int(port),
+                Value::BulkString(b"endpoint".to_vec()),
+                Value::BulkString(name.as_bytes().to_vec()),
+                Value::BulkString(b"role".to_vec()),
+                Value::BulkString(role.as_bytes().to_vec()),
+                Value::BulkString(b"health".to_vec()),
+                Value::BulkString(health.as_bytes().to_vec()),
+            ])
+        };
+        let shards = Value::Array(vec![Value::Array(vec![
+            Value::BulkString(b"slots".to_vec()),
+            Value::Array(vec![Value::Int(0), Value::Int(16383)]),
+            Value::BulkString(b"nodes".to_vec()),
+            Value::Array(vec![
+                shards_node(6379, "master", "online"),
+                shards_node(6380, "replica", "loading"),
+            ]),
+        ])]);
+        let MockEnv {
+            async_connection: mut connection,
+            handler: _handler,
+            runtime,
+            ..
+        } = MockEnv::with_cluster_shards_handler(
+            ClusterClient::builder(vec![&*format!("redis://{name}")]).read_from_replicas(),
+            name,
+            move |cmd: &[u8], port| {
+                if contains_slice(cmd, b"CLUSTER") && contains_slice(cmd, b"SHARDS") {
+                    return Err(Ok(shards.clone()));
+                }
+                respond_startup_with_replica(name, cmd)?;
+                Err(Ok(Value::BulkString(port.to_string().into_bytes())))
+            },
+        );
+
+        // The loading replica isn't used for reads
+        for _ in 0..3 {
+            let value = runtime.block_on(
+                cmd("GET")
+                    .arg("test")
+                    .query_async::<_, String>(&mut connection),
+            );
+            assert_eq!(value, Ok("6379".to_string()));
+        }
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_async_cluster_shards_discovery_falls_back_to_cluster_slots() {
+        let name = "test_async_cluster_shards_discovery_falls_back_to_cluster_slots";
+        let shards_requests = Arc::new(AtomicI32::new(0));
+        let MockEnv {
+            async_connection: mut connection,
+            handler: _handler,
+            runtime,
+            ..
+        } = MockEnv::with_cluster_shards_handler(
+            ClusterClient::builder(vec![&*format!("redis://{name}")]),
+            name,
+            {
+                let shards_requests = shards_requests.clone();
+                move |cmd: &[u8], _| {
+                    if contains_slice(cmd, b"CLUSTER") && contains_slice(cmd, b"SHARDS") {
+                        shards_requests.fetch_add(1, Ordering::SeqCst);
+                        return Err(parse_redis_value(b"-ERR unknown subcommand 'SHARDS'\r\n"));
+                    }
+                    respond_startup(name, cmd)?;
+                    Err(Ok(Value::Int(123)))
+                }
+            },
+        );
+
+        let value = runtime.block_on(
+            cmd("GET")
+                .arg("test")
+                .query_async::<_, Option<i32>>(&mut connection),
+        );
+        assert_eq!(value, Ok(Some(123)));
+        assert_eq!(shards_requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_async_cluster_shards_discovery_falls_back_to_cluster_slots_on_noperm() {
+        let name = "test_async_cluster_shards_discovery_falls_back_to_cluster_slots_on_noperm";
+        let shards_requests = Arc::new(AtomicI32::new(0));
+        let MockEnv {
+            async_connection: mut connection,
+            handler: _handler,
+            runtime,
+            ..
+        } = MockEnv::with_cluster_shards_handler(
+            ClusterClient::builder(vec![&*format!("redis://{name}")]),
+            name,
+            {
+                let shards_requests = shards_requests.clone();
+                move |cmd: &[u8], _| {
+                    if contains_slice(cmd, b"CLUSTER") && contains_slice(cmd, b"SHARDS") {
+                        shards_requests.fetch_add(1, Ordering::SeqCst);
+                        return Err(parse_redis_value(
+                            b"-NOPERM User default has no permissions to run the 'cluster|shards' command\r\n",
+                        ));
+                    }
+                    respond_startup(name, cmd)?;
+                    Err(Ok(Value::Int(123)))
+                }
+            },
+        );
+
+        let value = runtime.block_on(
+            cmd("GET")
+                .arg("test")
+                .query_async::<_, Option<i32>>(&mut connection),
+        );
+        assert_eq!(value, Ok(Some(123)));
+        assert_eq!(shards_requests.load(Ordering::SeqCst), 1);
+    }
+
     #[test]
     #[serial_test::serial]
     fn test_async_cluster_ask_save_new_connection() {
@@ -4360,6 +4838,49 @@ mod cluster_async {
         .unwrap();
     }
 
+    #[test]
+    #[serial_test::serial]
+    fn test_async_cluster_shards_discovery_with_acl_user_denied_cluster_shards() {
+        let cluster = TestClusterContext::new(3, 0);
+
+        block_on_all(async move {
+            let mut connection = cluster.async_connection(None).await;
+            let mut acl_setuser = cmd("ACL");
+            acl_setuser
+                .arg("SETUSER")
+                .arg("no_shards")
+                .arg("on")
+                .arg(">no_shards_password")
+                .arg("~*")
+                .arg("&*")
+                .arg("+@all")
+                .arg("-cluster|shards");
+            connection
+                .route_command(
+                    &acl_setuser,
+                    RoutingInfo::MultiNode((MultipleNodeRoutingInfo::AllNodes, None)),
+                )
+                .await?;
+
+            let client = ClusterClient::builder(cluster.nodes.clone())
+                .use_protocol(use_protocol())
+                .username("no_shards".to_string())
+                .password("no_shards_password".to_string())
+                .cluster_shards_discovery(true)
+                .build()?;
+            let mut connection = client.get_async_connection(None).await?;
+            cmd("SET")
+                .arg("test")
+                .arg("test_data")
+                .query_async::<_, ()>(&mut connection)
+                .await?;
+            let res: String = cmd("GET").arg("test").query_async(&mut connection).await?;
+            assert_eq!(res, "test_data");
+            Ok::<_, RedisError>(())
+        })
+        .unwrap();
+    }
+
     #[test]
     #[serial_test::serial]
     fn test_async_cluster_io_error() {
@@ -493,4 +493,34 @@ pub mod async_tests {
         })
         .unwrap();
     }
+
+    #[test]
+    fn test_sentinel_master_and_replica_addresses_async() {
+        let number_of_replicas = 3;
+        let master_name = "master1";
+        let mut context = TestSentinelContext::new(2, number_of_replicas, 3);
+        let node_conn_info = context.sentinel_node_connection_info();
+        let sentinel = context.sentinel_mut();
+
+        block_on_all(async move {
+            let master_client = sentinel
+                .async_master_for(master_name, Some(&node_conn_info))
+                .await?;
+            let (master_address, replica_addresses) = sentinel
+                .async_master_and_replica_addresses_for(master_name)
+                .await?;
+
+            let (master_host, master_port) = match &master_client.get_connection_info().addr {
+                ConnectionAddr::Tcp(host, port) => (host, port),
+                ConnectionAddr::TcpTls { host, port, .. } => (host, port),
+                ConnectionAddr::Unix(..) => panic!("Unexpected master connection type"),
+            };
+            assert_eq!(master_address, (master_host.clone(), *master_port));
+            assert_eq!(replica_addresses.len(), number_of_replicas as usize);
+            assert!(!replica_addresses.contains(&master_address));
+
+            Ok::<(), RedisError>(())
+        })
+        .unwrap();
+    }
 }
@@ -0,0 +1,128 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! A synchronous client for applications that don't run an async runtime.
+//!
+//! Requests are executed on the shared Glide runtime returned by [`get_or_init_runtime`], and the calling
+//! thread blocks until they complete. The client is cheap to clone and can be used from many threads at once.
+//! Its methods must not be called from within an async context, since they would block the executor.
+
+use crate::client::{self, ConnectionError, ConnectionRequest, get_or_init_runtime};
+use redis::cluster_routing::RoutingInfo;
+use redis::{
+    ClusterScanArgs, Cmd, ErrorKind, Pipeline, PipelineRetryStrategy, PushInfo, RedisError,
+    RedisResult, ScanStateRC, Value,
+};
+use std::future::Future;
+use std::io;
+use tokio::runtime::Handle;
+use tokio::sync::mpsc;
+
+#[derive(Clone)]
+pub struct Client {
+    inner: client::Client,
+    runtime: Handle,
+}
+
+impl Client {
+    /// Creates a new client, blocking until it's connected.
+    pub fn new(
+        request: ConnectionRequest,
+        push_sender: Option<mpsc::UnboundedSender<PushInfo>>,
+    ) -> Result<Self, ConnectionError> {
+        let runtime = get_or_init_runtime()
+            .map_err(|err| ConnectionError::IoError(io::Error::other(err)))?
+            .runtime
+            .clone();
+        let inner = block_on_runtime(&runtime, client::Client::new(request, push_sender))
+            .map_err(|err| ConnectionError::IoError(io::Error::other(err.to_string())))??;
+        Ok(Self { inner, runtime })
+    }
+
+    /// Send a command to the server, and block until its response is received.
+    /// See [`client::Client::send_command`].
+    pub fn send_command(&self, cmd: &Cmd, routing: Option<RoutingInfo>) -> RedisResult<Value> {
+        let mut inner = self.inner.clone();
+        let cmd = cmd.clone();
+        self.block_on(async move { inner.send_command(&cmd, routing).await })
+    }
+
+    /// Send a transaction to the server, and block until its response is received.
+    /// See [`client::Client::send_transaction`].
+    pub fn send_transaction(
+        &self,
+        pipeline: &Pipeline,
+        routing: Option<RoutingInfo>,
+        transaction_timeout: Option<u32>,
+        raise_on_error: bool,
+    ) -> RedisResult<Value> {
+        let mut inner = self.inner.clone();
+        let pipeline = pipeline.clone();
+        self.block_on(async move {
+            inner
+                .send_transaction(&pipeline, routing, transaction_timeout, raise_on_error)
+                .await
+        })
+    }
+
+    /// Send a pipeline to the server, and block until its responses are received.
+    /// See [`client::Client::send_pipeline`].
+    pub fn send_pipeline(
+        &self,
+        pipeline: &Pipeline,
+        routing: Option<RoutingInfo>,
+        raise_on_error: bool,
+        pipeline_timeout: Option<u32>,
+        pipeline_retry_strategy: PipelineRetryStrategy,
+    ) -> RedisResult<Value> {
+        let mut inner = self.inner.clone();
+        let pipeline = pipeline.clone();
+        self.block_on(async move {
+            inner
+                .send_pipeline(
+                    &pipeline,
+                    routing,
+                    raise_on_error,
+                    pipeline_timeout,
+                    pipeline_retry_strategy,
+                )
+                .await
+        })
+    }
+
+    /// Run a single iteration of a cluster scan, and block until its results are received.
+    /// See [`client::Client::cluster_scan`].
+    pub fn cluster_scan(
+        &self,
+        scan_state_cursor: &ScanStateRC,
+        cluster_scan_args: ClusterScanArgs,
+    ) -> RedisResult<Value> {
+        let mut inner = self.inner.clone();
+        let scan_state_cursor = scan_state_cursor.clone();
+        self.block_on(async move {
+            inner
+                .cluster_scan(&scan_state_cursor, cluster_scan_args)
+                .await
+        })
+    }
+
+    fn block_on<T: Send + 'static>(
+        &self,
+        future: impl Future<Output = RedisResult<T>> + Send + 'static,
+    ) -> RedisResult<T> {
+        block_on_runtime(&self.runtime, future).map_err(|err| {
+            RedisError::from((
+                ErrorKind::ClientError,
+                "Request task failed",
+                err.to_string(),
+            ))
+        })?
+    }
+}
+
+/// Runs `future` to completion on the runtime's thread, blocking the calling thread until it's done.
+fn block_on_runtime<T: Send + 'static>(
+    runtime: &Handle,
+    future: impl Future<Output = T> + Send + 'static,
+) -> Result<T, tokio::task::JoinError> {
+    runtime.block_on(runtime.spawn(future))
+}
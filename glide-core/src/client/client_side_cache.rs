@@ -0,0 +1,414 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use logger_core::log_debug;
+use redis::cluster_routing::Routable;
+use redis::{Cmd, PushInfo, PushKind, Value};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex, Weak};
+use tokio::sync::mpsc;
+use tokio::task;
+
+const LOCK_ERR: &str = "Failed to acquire the client side cache lock";
+
+/// Read commands whose responses are cached, and whether all of their arguments are keys, or only the first one.
+const CACHEABLE_COMMANDS: &[(&[u8], bool)] = &[
+    (b"GET", false),
+    (b"MGET", true),
+    (b"STRLEN", false),
+    (b"HGET", false),
+    (b"HMGET", false),
+    (b"HGETALL", false),
+    (b"LRANGE", false),
+    (b"SMEMBERS", false),
+];
+
+/// An in-process cache of read commands' responses.
+///
+/// The connections enable `CLIENT TRACKING`, so the server sends an invalidation push message whenever a key
+/// that was read is modified, and the cached responses that read the key are evicted.
+/// A new connection also sends a flush-all invalidation, since invalidations might have been missed while disconnected.
+pub(crate) struct ClientSideCache {
+    max_entries: usize,
+    state: Mutex<CacheState>,
+}
+
+#[derive(Default)]
+struct CacheState {
+    /// Cached responses by their packed command.
+    entries: HashMap<Vec<u8>, CacheEntry>,
+    /// The packed commands that read each key.
+    commands_by_key: HashMap<Vec<u8>, HashSet<Vec<u8>>>,
+    /// The least recently used command is first. Each use pushes a new generation of the command,
+    /// so items whose generation doesn't match their entry are stale, and skipped.
+    usage_order: VecDeque<(Vec<u8>, u64)>,
+    generation: u64,
+    /// Keys with in-flight reads.
+    pending_keys: HashMap<Vec<u8>, PendingKey>,
+    /// Incremented whenever the cache is flushed, so responses to reads sent before the flush aren't cached.
+    flush_epoch: u64,
+}
+
+struct CacheEntry {
+    value: Value,
+    keys: Vec<Vec<u8>>,
+    generation: u64,
+}
+
+#[derive(Default)]
+struct PendingKey {
+    readers: usize,
+    /// Whether the key was invalidated while being read, in which case the responses might be stale.
+    invalidated: bool,
+}
+
+/// A read that missed the cache. Its response is cached by calling [`PendingRead::complete`],
+/// unless the keys it read were invalidated in the meantime.
+pub(crate) struct PendingRead {
+    cache: Arc<ClientSideCache>,
+    command: Vec<u8>,
+    keys: Vec<Vec<u8>>,
+    flush_epoch: u64,
+}
+
+impl ClientSideCache {
+    /// Creates the cache, and returns the sender the connections should push messages to.
+    /// Invalidations are applied to the cache, and other push messages are forwarded to `push_sender`.
+    pub(crate) fn new(
+        max_entries: usize,
+        push_sender: Option<mpsc::UnboundedSender<PushInfo>>,
+    ) -> (Arc<Self>, mpsc::UnboundedSender<PushInfo>) {
+        let cache = Arc::new(Self {
+            max_entries,
+            state: Mutex::new(CacheState::default()),
+        });
+        let (cache_push_sender, push_receiver) = mpsc::unbounded_channel();
+        task::spawn(Self::handle_push_messages(
+            Arc::downgrade(&cache),
+            push_receiver,
+            push_sender,
+        ));
+        (cache, cache_push_sender)
+    }
+
+    async fn handle_push_messages(
+        cache: Weak<Self>,
+        mut push_receiver: mpsc::UnboundedReceiver<PushInfo>,
+        push_sender: Option<mpsc::UnboundedSender<PushInfo>>,
+    ) {
+        while let Some(push_info) = push_receiver.recv().await {
+            let Some(cache) = cache.upgrade() else {
+                return;
+            };
+            match push_info.kind {
+                PushKind::Invalidate => {
+                    cache.invalidate(push_info.data);
+                    continue;
+                }
+                PushKind::Disconnection => cache.flush(),
+                _ => {}
+            }
+            if let Some(push_sender) = &push_sender {
+                let _ = push_sender.send(push_info);
+            }
+        }
+    }
+
+    /// Returns the cached response to `cmd`, or a [`PendingRead`] for caching the response once it's received.
+    /// Returns `None` if `cmd`'s responses aren't cached.
+    pub(crate) fn get(self: &Arc<Self>, cmd: &Cmd) -> Option<Result<Value, PendingRead>> {
+        let keys = cached_keys(cmd)?;
+        let command = cmd.get_packed_command();
+        let mut state = self.state.lock().expect(LOCK_ERR);
+        if let Some(value) = state.touch(&command) {
+            return Some(Ok(value));
+        }
+        for key in keys.iter() {
+            state.pending_keys.entry(key.clone()).or_default().readers += 1;
+        }
+        Some(Err(PendingRead {
+            cache: self.clone(),
+            command,
+            keys,
+            flush_epoch: state.flush_epoch,
+        }))
+    }
+
+    /// Removes all cached responses.
+    pub(crate) fn flush(&self) {
+        let mut state = self.state.lock().expect(LOCK_ERR);
+        state.entries.clear();
+        state.commands_by_key.clear();
+        state.usage_order.clear();
+        state.flush_epoch += 1;
+    }
+
+    /// Applies an invalidation message, which contains either the modified keys, or nil if all keys should be invalidated.
+    fn invalidate(&self, data: Vec<Value>) {
+        let keys = match data.into_iter().next() {
+            Some(Value::Array(keys)) => keys,
+            _ => {
+                log_debug("client side cache", "Flushing the cache");
+                self.flush();
+                return;
+            }
+        };
+        let mut state = self.state.lock().expect(LOCK_ERR);
+        for key in keys {
+            let Value::BulkString(key) = key else {
+                continue;
+            };
+            if let Some(pending_key) = state.pending_keys.get_mut(&key) {
+                pending_key.invalidated = true;
+            }
+            for command in state.commands_by_key.remove(&key).unwrap_or_default() {
+                state.remove(&command);
+            }
+        }
+    }
+}
+
+impl CacheState {
+    /// Returns the cached response, and marks it as the most recently used.
+    fn touch(&mut self, command: &[u8]) -> Option<Value> {
+        self.generation += 1;
+        let generation = self.generation;
+        let entry = self.entries.get_mut(command)?;
+        entry.generation = generation;
+        let value = entry.value.clone();
+        self.usage_order.push_back((command.to_vec(), generation));
+        Some(value)
+    }
+
+    fn insert(&mut self, command: Vec<u8>, keys: Vec<Vec<u8>>, value: Value, max_entries: usize) {
+        self.remove(&command);
+        self.generation += 1;
+        for key in keys.iter() {
+            self.commands_by_key
+                .entry(key.clone())
+                .or_default()
+                .insert(command.clone());
+        }
+        self.usage_order
+            .push_back((command.clone(), self.generation));
+        self.entries.insert(
+            command,
+            CacheEntry {
+                value,
+                keys,
+                generation: self.generation,
+            },
+        );
+
+        while self.entries.len() > max_entries {
+            let Some((command, generation)) = self.usage_order.pop_front() else {
+                break;
+            };
+            if self.is_current(&command, generation) {
+                self.remove(&command);
+            }
+        }
+        // Prevents stale items from accumulating when the same commands are repeatedly used.
+        if self.usage_order.len() > max_entries.saturating_mul(2) {
+            let mut usage_order = std::mem::take(&mut self.usage_order);
+            usage_order.retain(|(command, generation)| self.is_current(command, *generation));
+            self.usage_order = usage_order;
+        }
+    }
+
+    fn is_current(&self, command: &[u8], generation: u64) -> bool {
+        self.entries
+            .get(command)
+            .is_some_and(|entry| entry.generation == generation)
+    }
+
+    fn remove(&mut self, command: &[u8]) {
+        let Some(entry) = self.entries.remove(command) else {
+            return;
+        };
+        for key in entry.keys {
+            if let Some(commands) = self.commands_by_key.get_mut(&key) {
+                commands.remove(command);
+                if commands.is_empty() {
+                    self.commands_by_key.remove(&key);
+                }
+            }
+        }
+    }
+}
+
+impl PendingRead {
+    /// Caches the response, unless the read keys were invalidated or the cache was flushed since the read was sent.
+    pub(crate) fn complete(self, value: &Value) {
+        let mut state = self.cache.state.lock().expect(LOCK_ERR);
+        let invalidated = state.flush_epoch != self.flush_epoch
+            || self.keys.iter().any(|key| {
+                state
+                    .pending_keys
+                    .get(key)
+                    .is_some_and(|pending_key| pending_key.invalidated)
+            });
+        if !invalidated {
+            state.insert(
+                self.command.clone(),
+                self.keys.clone(),
+                value.clone(),
+                self.cache.max_entries,
+            );
+        }
+    }
+}
+
+impl Drop for PendingRead {
+    fn drop(&mut self) {
+        let mut state = self.cache.state.lock().expect(LOCK_ERR);
+        for key in self.keys.iter() {
+            if let Some(pending_key) = state.pending_keys.get_mut(key) {
+                pending_key.readers -= 1;
+                if pending_key.readers == 0 {
+                    state.pending_keys.remove(key);
+                }
+            }
+        }
+    }
+}
+
+/// Returns the keys read by `cmd`, or `None` if its responses aren't cached.
+fn cached_keys(cmd: &Cmd) -> Option<Vec<Vec<u8>>> {
+    let command = cmd.command()?;
+    let (_, all_args_are_keys) = CACHEABLE_COMMANDS
+        .iter()
+        .find(|(name, _)| *name == command.as_slice())?;
+    let keys: Vec<_> = if *all_args_are_keys {
+        (1..)
+            .map_while(|idx| cmd.arg_idx(idx))
+            .map(<[u8]>::to_vec)
+            .collect()
+    } else {
+        cmd.arg_idx(1).map(<[u8]>::to_vec).into_iter().collect()
+    };
+    (!keys.is_empty()).then_some(keys)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_cmd(keys: &[&str]) -> Cmd {
+        let mut cmd = redis::cmd(if keys.len() > 1 { "MGET" } else { "GET" });
+        for key in keys {
+            cmd.arg(*key);
+        }
+        cmd
+    }
+
+    fn read(cache: &Arc<ClientSideCache>, cmd: &Cmd, value: &str) -> Option<Value> {
+        match cache.get(cmd).expect("command should be cacheable") {
+            Ok(value) => Some(value),
+            Err(pending_read) => {
+                pending_read.complete(&Value::BulkString(value.as_bytes().to_vec()));
+                None
+            }
+        }
+    }
+
+    fn invalidation(keys: &[&str]) -> Vec<Value> {
+        vec![Value::Array(
+            keys.iter()
+                .map(|key| Value::BulkString(key.as_bytes().to_vec()))
+                .collect(),
+        )]
+    }
+
+    #[tokio::test]
+    async fn test_cached_responses_are_invalidated_by_key() {
+        let (cache, _) = ClientSideCache::new(10, None);
+        let get_foo = get_cmd(&["foo"]);
+        let mget = get_cmd(&["foo", "bar"]);
+        let get_bar = get_cmd(&["bar"]);
+
+        assert_eq!(read(&cache, &get_foo, "1"), None);
+        assert_eq!(read(&cache, &mget, "2"), None);
+        assert_eq!(read(&cache, &get_bar, "3"), None);
+        assert_eq!(
+            read(&cache, &get_foo, "unused"),
+            Some(Value::BulkString(b"1".to_vec()))
+        );
+
+        cache.invalidate(invalidation(&["foo"]));
+        assert_eq!(read(&cache, &get_foo, "4"), None);
+        assert_eq!(read(&cache, &mget, "5"), None);
+        assert_eq!(
+            read(&cache, &get_bar, "unused"),
+            Some(Value::BulkString(b"3".to_vec()))
+        );
+
+        cache.invalidate(vec![Value::Nil]);
+        assert_eq!(read(&cache, &get_bar, "6"), None);
+    }
+
+    #[tokio::test]
+    async fn test_response_is_not_cached_if_invalidated_while_in_flight() {
+        let (cache, _) = ClientSideCache::new(10, None);
+        let get_foo = get_cmd(&["foo"]);
+
+        let Some(Err(pending_read)) = cache.get(&get_foo) else {
+            panic!("Expected a cache miss");
+        };
+        cache.invalidate(invalidation(&["foo"]));
+        pending_read.complete(&Value::BulkString(b"stale".to_vec()));
+        assert_eq!(read(&cache, &get_foo, "fresh"), None);
+        assert_eq!(
+            read(&cache, &get_foo, "unused"),
+            Some(Value::BulkString(b"fresh".to_vec()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_least_recently_used_response_is_evicted() {
+        let (cache, _) = ClientSideCache::new(2, None);
+        let (get_a, get_b, get_c) = (get_cmd(&["a"]), get_cmd(&["b"]), get_cmd(&["c"]));
+
+        read(&cache, &get_a, "a");
+        read(&cache, &get_b, "b");
+        assert!(read(&cache, &get_a, "unused").is_some());
+        read(&cache, &get_c, "c");
+
+        assert!(read(&cache, &get_a, "unused").is_some());
+        assert!(read(&cache, &get_c, "unused").is_some());
+        assert_eq!(read(&cache, &get_b, "b"), None);
+    }
+
+    #[tokio::test]
+    async fn test_push_messages_are_forwarded_without_invalidations() {
+        let (push_sender, mut push_receiver) = mpsc::unbounded_channel();
+        let (cache, cache_push_sender) = ClientSideCache::new(10, Some(push_sender));
+        let get_foo = get_cmd(&["foo"]);
+        read(&cache, &get_foo, "1");
+
+        cache_push_sender
+            .send(PushInfo {
+                kind: PushKind::Invalidate,
+                data: invalidation(&["foo"]),
+            })
+            .unwrap();
+        cache_push_sender
+            .send(PushInfo {
+                kind: PushKind::Message,
+                data: vec![],
+            })
+            .unwrap();
+
+        let push_info = push_receiver.recv().await.unwrap();
+        assert_eq!(push_info.kind, PushKind::Message);
+        assert_eq!(read(&cache, &get_foo, "2"), None);
+    }
+
+    #[test]
+    fn test_only_read_commands_are_cached() {
+        assert!(cached_keys(&redis::cmd("SET").arg("foo").arg("bar").clone()).is_none());
+        assert_eq!(
+            cached_keys(&redis::cmd("hgetall").arg("foo").clone()),
+            Some(vec![b"foo".to_vec()])
+        );
+    }
+}
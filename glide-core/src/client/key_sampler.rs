@@ -0,0 +1,323 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Sampling of requests' keys and responses, to detect the hot keys and big values that cause hot shards.
+
+use super::KeySamplerConfig;
+use logger_core::log_error;
+use redis::cluster_routing::first_key;
+use redis::cluster_topology::get_slot;
+use redis::{Cmd, Value};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use telemetrylib::{GlideOpenTelemetry, is_sampled};
+
+const LOCK_ERR: &str = "Failed to acquire the key sampler lock";
+
+/// A frequently requested key, as estimated from the sampled requests.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HotKey {
+    pub key: Vec<u8>,
+    pub slot: u16,
+    /// The number of sampled requests to the key. This is an upper bound, which overestimates the key's
+    /// frequency by at most `error`.
+    pub count: u64,
+    pub error: u64,
+}
+
+/// A big response to a sampled request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BigValue {
+    pub command: String,
+    pub key: Vec<u8>,
+    /// The approximate size of the response, in bytes.
+    pub size: usize,
+}
+
+/// The hot keys and big values detected by the key sampler.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeySamplerSnapshot {
+    /// The number of sampled requests to keys.
+    pub sampled_requests: u64,
+    /// The most frequently requested keys across all slots, from the most frequent.
+    pub hot_keys: Vec<HotKey>,
+    /// The biggest responses of each command, from the biggest.
+    pub big_values: Vec<BigValue>,
+}
+
+/// Samples requests, keeping the approximate top-K key frequencies of each slot, and the biggest responses of each command.
+///
+/// Key frequencies are tracked with the Space-Saving algorithm, which keeps `top_k` counters per slot. When a key
+/// without a counter is sampled, it replaces the key with the least count, and inherits its count as its error.
+pub(crate) struct KeySampler {
+    config: KeySamplerConfig,
+    requests: AtomicU64,
+    state: Mutex<SamplerState>,
+}
+
+#[derive(Default)]
+struct SamplerState {
+    sampled_requests: u64,
+    hot_keys: HashMap<u16, Vec<KeyCounter>>,
+    big_values: HashMap<String, Vec<(Vec<u8>, usize)>>,
+}
+
+struct KeyCounter {
+    key: Vec<u8>,
+    count: u64,
+    error: u64,
+}
+
+impl KeySampler {
+    pub(crate) fn new(config: KeySamplerConfig) -> Self {
+        Self {
+            config,
+            requests: AtomicU64::new(0),
+            state: Mutex::new(SamplerState::default()),
+        }
+    }
+
+    /// Samples `cmd` and its `response`, if the request is chosen by the sample percentage.
+    pub(crate) fn sample(&self, cmd: &Cmd, command: &str, response: &Value) {
+        let request = self.requests.fetch_add(1, Ordering::Relaxed);
+        if !is_sampled(request, self.config.sample_percentage) {
+            return;
+        }
+        let Some(key) = first_key(cmd) else {
+            return;
+        };
+        let slot = get_slot(key);
+        let size = value_size(response);
+        let is_big_value = size >= self.config.big_value_threshold;
+
+        self.state.lock().expect(LOCK_ERR).add_sample(
+            key,
+            slot,
+            is_big_value.then_some((command, size)),
+            self.config.top_k,
+        );
+
+        if let Err(e) = GlideOpenTelemetry::record_key_sample(slot) {
+            log_error(
+                "OpenTelemetry:key_sample_error",
+                format!("Failed to record key sample: {e}"),
+            );
+        }
+        if is_big_value && let Err(e) = GlideOpenTelemetry::record_big_value(command, size) {
+            log_error(
+                "OpenTelemetry:big_value_error",
+                format!("Failed to record big value: {e}"),
+            );
+        }
+    }
+
+    pub(crate) fn snapshot(&self) -> KeySamplerSnapshot {
+        let state = self.state.lock().expect(LOCK_ERR);
+        let mut hot_keys: Vec<_> = state
+            .hot_keys
+            .iter()
+            .flat_map(|(slot, counters)| {
+                counters.iter().map(|counter| HotKey {
+                    key: counter.key.clone(),
+                    slot: *slot,
+                    count: counter.count,
+                    error: counter.error,
+                })
+            })
+            .collect();
+        hot_keys.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.key.cmp(&b.key)));
+        hot_keys.truncate(self.config.top_k);
+
+        let mut big_values: Vec<_> = state
+            .big_values
+            .iter()
+            .flat_map(|(command, values)| {
+                values.iter().map(|(key, size)| BigValue {
+                    command: command.clone(),
+                    key: key.clone(),
+                    size: *size,
+                })
+            })
+            .collect();
+        big_values.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.key.cmp(&b.key)));
+
+        KeySamplerSnapshot {
+            sampled_requests: state.sampled_requests,
+            hot_keys,
+            big_values,
+        }
+    }
+}
+
+impl SamplerState {
+    fn add_sample(
+        &mut self,
+        key: &[u8],
+        slot: u16,
+        big_value: Option<(&str, usize)>,
+        top_k: usize,
+    ) {
+        self.sampled_requests += 1;
+
+        let counters = self.hot_keys.entry(slot).or_default();
+        if let Some(counter) = counters.iter_mut().find(|counter| counter.key == key) {
+            counter.count += 1;
+        } else if counters.len() < top_k {
+            counters.push(KeyCounter {
+                key: key.to_vec(),
+                count: 1,
+                error: 0,
+            });
+        } else if let Some(least_frequent) = counters.iter_mut().min_by_key(|counter| counter.count)
+        {
+            least_frequent.error = least_frequent.count;
+            least_frequent.count += 1;
+            least_frequent.key = key.to_vec();
+        }
+
+        let Some((command, size)) = big_value else {
+            return;
+        };
+        let values = self.big_values.entry(command.to_string()).or_default();
+        if let Some((_, biggest_size)) = values.iter_mut().find(|(value_key, _)| value_key == key) {
+            *biggest_size = size.max(*biggest_size);
+        } else if values.len() < top_k {
+            values.push((key.to_vec(), size));
+        } else if let Some(smallest) = values
+            .iter_mut()
+            .min_by_key(|(_, size)| *size)
+            .filter(|(_, smallest_size)| *smallest_size < size)
+        {
+            *smallest = (key.to_vec(), size);
+        }
+    }
+}
+
+/// Returns the approximate size of `value` in bytes, counting the length of its strings, and 8 bytes for other scalars.
+fn value_size(value: &Value) -> usize {
+    match value {
+        Value::Nil | Value::Okay => 0,
+        Value::Int(_) | Value::Double(_) | Value::Boolean(_) => 8,
+        Value::BulkString(val) => val.len(),
+        Value::SimpleString(val) => val.len(),
+        Value::BigNumber(val) => val.to_string().len(),
+        Value::VerbatimString { text, .. } => text.len(),
+        Value::Array(values) | Value::Set(values) => values.iter().map(value_size).sum(),
+        Value::Map(values) => values
+            .iter()
+            .map(|(key, value)| value_size(key) + value_size(value))
+            .sum(),
+        Value::Attribute { data, .. } => value_size(data),
+        Value::Push { data, .. } => data.iter().map(value_size).sum(),
+        Value::ServerError(_) => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sampler(top_k: usize) -> KeySampler {
+        KeySampler::new(KeySamplerConfig {
+            sample_percentage: 100,
+            top_k,
+            big_value_threshold: 10,
+        })
+    }
+
+    fn get_cmd(key: &str) -> Cmd {
+        let mut cmd = redis::cmd("GET");
+        cmd.arg(key);
+        cmd
+    }
+
+    fn sample_keys(sampler: &KeySampler, keys: &[&str]) {
+        for key in keys {
+            sampler.sample(&get_cmd(key), "GET", &Value::Okay);
+        }
+    }
+
+    #[test]
+    fn test_hot_keys_are_counted_per_slot() {
+        let sampler = sampler(2);
+        // "{a}1" and "{a}2" share a slot, "b" is in another one.
+        sample_keys(&sampler, &["{a}1", "{a}1", "{a}1", "{a}2", "b", "b"]);
+        // Commands without keys aren't sampled.
+        sampler.sample(&redis::cmd("PING"), "PING", &Value::Okay);
+        let snapshot = sampler.snapshot();
+        assert_eq!(snapshot.sampled_requests, 6);
+        assert_eq!(
+            snapshot.hot_keys,
+            vec![
+                HotKey {
+                    key: b"{a}1".to_vec(),
+                    slot: get_slot(b"a"),
+                    count: 3,
+                    error: 0,
+                },
+                HotKey {
+                    key: b"b".to_vec(),
+                    slot: get_slot(b"b"),
+                    count: 2,
+                    error: 0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_least_frequent_key_is_replaced_when_slot_is_full() {
+        let sampler = sampler(2);
+        sample_keys(&sampler, &["{a}1", "{a}1", "{a}2", "{a}3", "{a}3"]);
+        let snapshot = sampler.snapshot();
+        let counts: Vec<_> = snapshot
+            .hot_keys
+            .iter()
+            .map(|hot_key| (hot_key.key.as_slice(), hot_key.count, hot_key.error))
+            .collect();
+        assert_eq!(counts, vec![(&b"{a}3"[..], 3, 1), (&b"{a}1"[..], 2, 0)]);
+    }
+
+    #[test]
+    fn test_big_values_are_kept_per_command() {
+        let sampler = sampler(1);
+        let small = Value::BulkString(vec![0; 9]);
+        let big = Value::Array(vec![Value::BulkString(vec![0; 8]), Value::Int(1)]);
+        let bigger = Value::BulkString(vec![0; 20]);
+        sampler.sample(&get_cmd("small"), "GET", &small);
+        sampler.sample(&get_cmd("big"), "GET", &big);
+        sampler.sample(&get_cmd("bigger"), "GET", &bigger);
+        sampler.sample(&get_cmd("big"), "GET", &big);
+
+        let mut lrange = redis::cmd("LRANGE");
+        lrange.arg("list").arg(0).arg(-1);
+        sampler.sample(&lrange, "LRANGE", &big);
+
+        assert_eq!(
+            sampler.snapshot().big_values,
+            vec![
+                BigValue {
+                    command: "GET".to_string(),
+                    key: b"bigger".to_vec(),
+                    size: 20,
+                },
+                BigValue {
+                    command: "LRANGE".to_string(),
+                    key: b"list".to_vec(),
+                    size: 16,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_requests_are_sampled_by_percentage() {
+        let sampler = KeySampler::new(KeySamplerConfig {
+            sample_percentage: 10,
+            top_k: 10,
+            big_value_threshold: usize::MAX,
+        });
+        sample_keys(&sampler, &["key"; 100]);
+        assert_eq!(sampler.snapshot().sampled_requests, 10);
+    }
+}
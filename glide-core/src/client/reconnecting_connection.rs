@@ -3,15 +3,19 @@
 use super::{NodeAddress, TlsMode};
 use async_trait::async_trait;
 use futures_intrusive::sync::ManualResetEvent;
-use logger_core::{log_debug, log_error, log_trace, log_warn};
+use logger_core::{
+    LogContext, log_debug_with_context, log_error_with_context, log_trace_with_context,
+    log_warn_with_context,
+};
 use redis::aio::{DisconnectNotifier, MultiplexedConnection};
 use redis::{
-    GlideConnectionOptions, PushInfo, RedisConnectionInfo, RedisError, RedisResult, RetryStrategy,
+    GlideConnectionOptions, ProtocolVersion, PubSubChannelOrPattern, PubSubSubscriptionKind,
+    PushInfo, RedisConnectionInfo, RedisError, RedisResult, RetryStrategy,
 };
 use std::fmt;
 use std::sync::Arc;
 use std::sync::Mutex;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{RwLock, RwLockReadGuard};
 use std::time::Duration;
 use telemetrylib::Telemetry;
@@ -24,6 +28,11 @@ use super::{DEFAULT_CONNECTION_TIMEOUT, run_with_timeout};
 
 const WRITE_LOCK_ERR: &str = "Failed to acquire the write lock";
 const READ_LOCK_ERR: &str = "Failed to acquire the read lock";
+/// The weight given to a new latency sample when updating the node's smoothed latency.
+const LATENCY_SMOOTHING_FACTOR: f64 = 0.2;
+
+/// The ID of the next created connection, which is added to its logs.
+static NEXT_CONNECTION_ID: AtomicU64 = AtomicU64::new(1);
 
 /// The reason behind the call to `reconnect()`
 #[derive(PartialEq, Eq, Debug, Clone)]
@@ -57,6 +66,10 @@ enum ConnectionState {
 struct InnerReconnectingConnection {
     state: Mutex<ConnectionState>,
     backend: ConnectionBackend,
+    /// Smoothed round-trip latency to the node, `None` until the first sample is recorded.
+    latency: Mutex<Option<Duration>>,
+    /// The IDs of the connection and of its client, which are added to the connection's logs.
+    log_context: LogContext,
 }
 
 #[derive(Clone)]
@@ -123,6 +136,7 @@ async fn create_connection(
     push_sender: Option<mpsc::UnboundedSender<PushInfo>>,
     discover_az: bool,
     connection_timeout: Duration,
+    log_context: LogContext,
 ) -> Result<ReconnectingConnection, (ReconnectingConnection, RedisError)> {
     let client = {
         let guard = connection_backend
@@ -148,42 +162,37 @@ async fn create_connection(
             .map_err(RetryError::transient)
     };
 
+    let address = client.get_connection_info().addr.to_string();
     match Retry::spawn(retry_strategy.get_bounded_backoff_dur_iterator(), action).await {
         Ok(connection) => {
-            log_debug(
+            log_debug_with_context(
+                log_context,
                 "connection creation",
-                format!(
-                    "Connection to {} created",
-                    connection_backend
-                        .get_backend_client()
-                        .get_connection_info()
-                        .addr
-                ),
+                format!("Connection to {address} created"),
             );
             Telemetry::incr_total_connections(1);
             Ok(ReconnectingConnection {
                 inner: Arc::new(InnerReconnectingConnection {
                     state: Mutex::new(ConnectionState::Connected(connection)),
                     backend: connection_backend,
+                    latency: Mutex::new(None),
+                    log_context,
                 }),
                 connection_options,
             })
         }
         Err(err) => {
-            log_warn(
+            log_warn_with_context(
+                log_context,
                 "connection creation",
-                format!(
-                    "Failed connecting to {}, due to {err}",
-                    connection_backend
-                        .get_backend_client()
-                        .get_connection_info()
-                        .addr
-                ),
+                format!("Failed connecting to {address}, due to {err}"),
             );
             let connection = ReconnectingConnection {
                 inner: Arc::new(InnerReconnectingConnection {
                     state: Mutex::new(ConnectionState::InitializedDisconnected),
                     backend: connection_backend,
+                    latency: Mutex::new(None),
+                    log_context,
                 }),
                 connection_options,
             };
@@ -224,8 +233,14 @@ impl ReconnectingConnection {
         discover_az: bool,
         connection_timeout: Duration,
         tls_params: Option<redis::TlsConnParams>,
+        client_log_context: LogContext,
     ) -> Result<ReconnectingConnection, (ReconnectingConnection, RedisError)> {
-        log_debug(
+        let log_context = LogContext {
+            connection_id: Some(NEXT_CONNECTION_ID.fetch_add(1, Ordering::Relaxed)),
+            ..client_log_context
+        };
+        log_debug_with_context(
+            log_context,
             "connection creation",
             format!("Attempting connection to {address}"),
         );
@@ -242,10 +257,16 @@ impl ReconnectingConnection {
             push_sender,
             discover_az,
             connection_timeout,
+            log_context,
         )
         .await
     }
 
+    /// Returns the IDs of the connection and of its client, to be added to the logs about the connection.
+    pub(super) fn log_context(&self) -> LogContext {
+        self.inner.log_context
+    }
+
     pub(crate) fn node_address(&self) -> String {
         self.inner
             .backend
@@ -255,6 +276,15 @@ impl ReconnectingConnection {
             .to_string()
     }
 
+    pub(crate) fn database_id(&self) -> i64 {
+        self.inner
+            .backend
+            .get_backend_client()
+            .get_connection_info()
+            .redis
+            .db
+    }
+
     pub(super) fn is_dropped(&self) -> bool {
         self.inner
             .backend
@@ -297,14 +327,14 @@ impl ReconnectingConnection {
         {
             let mut guard = self.inner.state.lock().unwrap();
             if matches!(*guard, ConnectionState::Reconnecting) {
-                log_trace("reconnect", "already started");
+                log_trace_with_context(self.log_context(), "reconnect", "already started");
                 // exit early - if reconnection already started or failed, there's nothing else to do.
                 return;
             }
             self.inner.backend.connection_available_signal.reset();
             *guard = ConnectionState::Reconnecting;
         };
-        log_debug("reconnect", "starting");
+        log_debug_with_context(self.log_context(), "reconnect", "starting");
 
         let connection_clone = self.clone();
 
@@ -332,7 +362,8 @@ impl ReconnectingConnection {
                 .get_infinite_backoff_dur_iterator();
             for sleep_duration in infinite_backoff_dur_iterator {
                 if connection_clone.is_dropped() {
-                    log_debug(
+                    log_debug_with_context(
+                        connection_clone.log_context(),
                         "ReconnectingConnection",
                         "reconnect stopped after client was dropped",
                     );
@@ -353,7 +384,11 @@ impl ReconnectingConnection {
                         }
                         {
                             let mut guard = connection_clone.inner.state.lock().unwrap();
-                            log_debug("reconnect", "completed successfully");
+                            log_debug_with_context(
+                                connection_clone.log_context(),
+                                "reconnect",
+                                "completed successfully",
+                            );
                             connection_clone
                                 .inner
                                 .backend
@@ -377,6 +412,24 @@ impl ReconnectingConnection {
         )
     }
 
+    /// Records a new round-trip latency sample for the node,
+    /// using an exponentially weighted moving average to smooth out spikes.
+    pub(super) fn update_latency(&self, sample: Duration) {
+        let mut latency = self.inner.latency.lock().unwrap();
+        *latency = Some(match *latency {
+            Some(current) => {
+                current.mul_f64(1.0 - LATENCY_SMOOTHING_FACTOR)
+                    + sample.mul_f64(LATENCY_SMOOTHING_FACTOR)
+            }
+            None => sample,
+        });
+    }
+
+    /// Returns the smoothed round-trip latency to the node, if it was measured.
+    pub(super) fn latency(&self) -> Option<Duration> {
+        *self.inner.latency.lock().unwrap()
+    }
+
     pub async fn wait_for_disconnect_with_timeout(&self, max_wait: &Duration) {
         // disconnect_notifier should always exists
         if let Some(disconnect_notifier) = &self.connection_options.disconnect_notifier {
@@ -384,7 +437,11 @@ impl ReconnectingConnection {
                 .wait_for_disconnect_with_timeout(max_wait)
                 .await;
         } else {
-            log_error("disconnect notifier", "BUG! Disconnect notifier is not set");
+            log_error_with_context(
+                self.log_context(),
+                "disconnect notifier",
+                "BUG! Disconnect notifier is not set",
+            );
         }
     }
 
@@ -434,4 +491,40 @@ impl ReconnectingConnection {
         let client = self.inner.backend.get_backend_client();
         client.get_connection_info().redis.username.clone()
     }
+
+    pub(crate) fn get_protocol(&self) -> ProtocolVersion {
+        let client = self.inner.backend.get_backend_client();
+        client.get_connection_info().redis.protocol
+    }
+
+    /// Adds pubsub subscriptions to connection_info, that will be restored in case of disconnection from the server.
+    pub(crate) fn add_pubsub_subscriptions(
+        &self,
+        kind: PubSubSubscriptionKind,
+        channels_or_patterns: &[PubSubChannelOrPattern],
+    ) {
+        let mut client = self
+            .inner
+            .backend
+            .connection_info
+            .write()
+            .expect(WRITE_LOCK_ERR);
+        client.add_pubsub_subscriptions(kind, channels_or_patterns);
+    }
+
+    /// Removes pubsub subscriptions from connection_info, and returns the removed channels or patterns.
+    /// If `channels_or_patterns` is empty, all subscriptions of `kind` are removed.
+    pub(crate) fn remove_pubsub_subscriptions(
+        &self,
+        kind: PubSubSubscriptionKind,
+        channels_or_patterns: &[PubSubChannelOrPattern],
+    ) -> Vec<PubSubChannelOrPattern> {
+        let mut client = self
+            .inner
+            .backend
+            .connection_info
+            .write()
+            .expect(WRITE_LOCK_ERR);
+        client.remove_pubsub_subscriptions(kind, channels_or_patterns)
+    }
 }
@@ -1,25 +1,37 @@
 // Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
 
-use super::get_valkey_connection_info;
 use super::reconnecting_connection::{ReconnectReason, ReconnectingConnection};
-use super::{ConnectionRequest, NodeAddress, TlsMode};
-use super::{DEFAULT_CONNECTION_TIMEOUT, to_duration};
+use super::{ConnectionRequest, NodeAddress, SentinelConfiguration, TlsMode};
+use super::{DEFAULT_CONNECTION_TIMEOUT, DEFAULT_RETRIES, to_duration};
+use super::{
+    get_connection_info, get_tls_certificates, get_valkey_connection_info, request_outcome,
+    validate_client_side_cache, validate_key_sampler,
+};
 use crate::client::types::ReadFrom as ClientReadFrom;
 use futures::{StreamExt, future, stream};
-use logger_core::log_debug;
-use logger_core::log_warn;
+use logger_core::LogContext;
+use logger_core::log_debug_with_context;
+use logger_core::log_error_with_context;
+use logger_core::log_info_with_context;
+use logger_core::log_warn_with_context;
 use rand::Rng;
 use redis::aio::ConnectionLike;
 use redis::cluster_routing::{self, ResponsePolicy, Routable, RoutingInfo, is_readonly_cmd};
-use redis::{PushInfo, RedisError, RedisResult, RetryStrategy, Value};
-use std::sync::Arc;
+use redis::sentinel::Sentinel;
+use redis::{
+    CommandRetryPolicy, ErrorKind, ProtocolVersion, PubSubChannelOrPattern, PubSubSubscriptionKind,
+    PushInfo, RedisError, RedisResult, RetryStrategy, Value,
+};
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
-use std::time::Duration;
-use telemetrylib::Telemetry;
+use std::sync::{Arc, Weak};
+use std::time::{Duration, Instant};
+use telemetrylib::{GlideOpenTelemetry, Telemetry};
 use tokio::sync::mpsc;
 use tokio::task;
 
+const SWITCH_MASTER_CHANNEL: &str = "+switch-master";
+
 #[derive(Debug)]
 enum ReadFrom {
     Primary,
@@ -34,14 +46,100 @@ enum ReadFrom {
         client_az: String,
         last_read_replica_index: Arc<AtomicUsize>,
     },
+    LowestLatency {
+        latest_read_replica_index: Arc<AtomicUsize>,
+    },
 }
 
 #[derive(Debug)]
 struct DropWrapper {
     /// Connection to the primary node in the client.
-    primary_index: usize,
+    primary_index: AtomicUsize,
     nodes: Vec<ReconnectingConnection>,
     read_from: ReadFrom,
+    /// Serializes primary rediscovery, so that concurrent failures trigger a single role scan.
+    primary_rediscovery: tokio::sync::Mutex<()>,
+    /// Connection to the node that holds the pubsub subscriptions.
+    pubsub_index: usize,
+    /// The backoff between retries of commands with a retry policy.
+    retry_strategy: RetryStrategy,
+    /// The ID of the client, which is added to its logs.
+    log_context: LogContext,
+}
+
+impl DropWrapper {
+    fn primary_index(&self) -> usize {
+        self.primary_index.load(Ordering::Relaxed)
+    }
+
+    /// Marks the node with the given address as the primary.
+    fn update_primary(&self, address: &str) {
+        let Some(index) = self
+            .nodes
+            .iter()
+            .position(|node| node.node_address() == address)
+        else {
+            log_warn_with_context(
+                self.log_context,
+                "StandaloneClient",
+                format!("New primary `{address}` is not one of the client's nodes"),
+            );
+            return;
+        };
+        if self.primary_index.swap(index, Ordering::Relaxed) != index {
+            log_info_with_context(
+                self.log_context,
+                "StandaloneClient",
+                format!("Primary switched to `{address}`"),
+            );
+        }
+    }
+
+    /// Scans the roles of all nodes, and marks the single node that reports itself as primary as the client's primary.
+    /// Returns whether the primary was changed from `observed_primary_index`, either by this scan or by a concurrent one.
+    async fn rediscover_primary(&self, observed_primary_index: usize) -> bool {
+        let _guard = self.primary_rediscovery.lock().await;
+        if self.primary_index() != observed_primary_index {
+            return true;
+        }
+
+        let roles = future::join_all(self.nodes.iter().map(is_primary)).await;
+        let mut primaries = roles
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, is_primary)| is_primary.then_some(index));
+        match (primaries.next(), primaries.next()) {
+            (Some(index), None) => {
+                if index == observed_primary_index {
+                    return false;
+                }
+                self.primary_index.store(index, Ordering::Relaxed);
+                log_info_with_context(
+                    self.log_context,
+                    "StandaloneClient",
+                    format!("Primary switched to `{}`", self.nodes[index].node_address()),
+                );
+                true
+            }
+            (None, _) => {
+                log_warn_with_context(
+                    self.log_context,
+                    "StandaloneClient",
+                    "Primary rediscovery found no primary",
+                );
+                false
+            }
+            // Might happen mid-failover, before the old primary was demoted.
+            (Some(_), Some(_)) => {
+                log_warn_with_context(
+                    self.log_context,
+                    "StandaloneClient",
+                    "Primary rediscovery found more than one primary",
+                );
+                false
+            }
+        }
+    }
 }
 
 impl Drop for DropWrapper {
@@ -117,10 +215,38 @@ impl StandaloneClient {
         connection_request: ConnectionRequest,
         push_sender: Option<mpsc::UnboundedSender<PushInfo>>,
         iam_token_manager: Option<&Arc<crate::iam::IAMTokenManager>>,
+    ) -> Result<Self, StandaloneClientConnectionError> {
+        Self::create_client_with_log_context(
+            connection_request,
+            push_sender,
+            iam_token_manager,
+            LogContext::default(),
+        )
+        .await
+    }
+
+    /// Like [`Self::create_client`], adding the IDs of `log_context` to the logs of the client and its connections.
+    pub(crate) async fn create_client_with_log_context(
+        mut connection_request: ConnectionRequest,
+        push_sender: Option<mpsc::UnboundedSender<PushInfo>>,
+        iam_token_manager: Option<&Arc<crate::iam::IAMTokenManager>>,
+        log_context: LogContext,
     ) -> Result<Self, StandaloneClientConnectionError> {
         if connection_request.addresses.is_empty() {
             return Err(StandaloneClientConnectionError::NoAddressesProvided);
         }
+        validate_client_side_cache(&connection_request)
+            .and_then(|_| validate_key_sampler(&connection_request))
+            .map_err(|err| StandaloneClientConnectionError::FailedConnection(vec![(None, err)]))?;
+        if connection_request.circuit_breaker.is_some() {
+            return Err(StandaloneClientConnectionError::FailedConnection(vec![(
+                None,
+                RedisError::from((
+                    ErrorKind::InvalidClientConfig,
+                    "Circuit breaker is only supported in cluster mode",
+                )),
+            )]));
+        }
 
         let mut valkey_connection_info =
             get_valkey_connection_info(&connection_request, iam_token_manager).await;
@@ -137,6 +263,33 @@ impl StandaloneClient {
         };
 
         let tls_mode = connection_request.tls_mode;
+        let tls_params = get_tls_certificates(&connection_request)
+            .and_then(|tls_certificates| {
+                tls_certificates
+                    .map(redis::retrieve_tls_certificates)
+                    .transpose()
+            })
+            .map_err(|err| StandaloneClientConnectionError::FailedConnection(vec![(None, err)]))?;
+
+        let sentinel = match connection_request.sentinel_configuration.clone() {
+            Some(sentinel_configuration) => {
+                let sentinels = get_sentinels_connection_info(
+                    &connection_request.addresses,
+                    &sentinel_configuration,
+                    tls_mode.unwrap_or(TlsMode::NoTls),
+                    tls_params.clone(),
+                );
+                connection_request.addresses =
+                    discover_nodes_from_sentinels(&sentinels, &sentinel_configuration.service_name)
+                        .await
+                        .map_err(|err| {
+                            StandaloneClientConnectionError::FailedConnection(vec![(None, err)])
+                        })?;
+                Some((sentinels, sentinel_configuration.service_name))
+            }
+            None => None,
+        };
+
         let node_count = connection_request.addresses.len();
         // randomize pubsub nodes, maybe a batter option is to always use the primary
         let pubsub_node_index = rand::thread_rng().gen_range(0..node_count);
@@ -152,39 +305,14 @@ impl StandaloneClient {
             DEFAULT_CONNECTION_TIMEOUT,
         );
 
-        let tls_params = if !connection_request.root_certs.is_empty() {
-            if tls_mode.unwrap_or(TlsMode::NoTls) == TlsMode::NoTls {
-                return Err(StandaloneClientConnectionError::FailedConnection(vec![(
-                    None,
-                    RedisError::from((
-                        redis::ErrorKind::InvalidClientConfig,
-                        "Custom root certificates provided but TLS is disabled",
-                    )),
-                )]));
-            }
-            let mut combined_certs = Vec::new();
-            for cert in &connection_request.root_certs {
-                combined_certs.extend_from_slice(cert);
-            }
-            let tls_certificates = redis::TlsCertificates {
-                client_tls: None,
-                root_cert: Some(combined_certs),
-            };
-            Some(
-                redis::retrieve_tls_certificates(tls_certificates).map_err(|err| {
-                    StandaloneClientConnectionError::FailedConnection(vec![(None, err)])
-                })?,
-            )
-        } else {
-            None
-        };
-
-        let mut stream = stream::iter(connection_request.addresses.into_iter())
+        let mut stream = stream::iter(connection_request.addresses)
             .map(move |address| {
-                let info = if address.host != pubsub_addr.host || address.port != pubsub_addr.port {
-                    valkey_connection_info.clone()
-                } else {
+                let is_pubsub_node =
+                    address.host == pubsub_addr.host && address.port == pubsub_addr.port;
+                let info = if is_pubsub_node {
                     pubsub_connection_info.clone()
+                } else {
+                    valkey_connection_info.clone()
                 };
                 let retry = retry_strategy;
                 let sender = push_sender.clone();
@@ -193,11 +321,20 @@ impl StandaloneClient {
                 let timeout = connection_timeout;
                 let params = tls_params.clone();
                 async move {
-                    get_connection_and_replication_info(
-                        &address, &retry, &info, tls, &sender, discover, timeout, params,
+                    let result = get_connection_and_replication_info(
+                        &address,
+                        &retry,
+                        &info,
+                        tls,
+                        &sender,
+                        discover,
+                        timeout,
+                        params,
+                        log_context,
                     )
                     .await
-                    .map_err(|err| (format!("{}:{}", address.host, address.port), err))
+                    .map_err(|err| (format!("{}:{}", address.host, address.port), err));
+                    (is_pubsub_node, result)
                 }
             })
             .buffer_unordered(node_count);
@@ -205,13 +342,15 @@ impl StandaloneClient {
         let mut nodes = Vec::with_capacity(node_count);
         let mut addresses_and_errors = Vec::with_capacity(node_count);
         let mut primary_index = None;
-        while let Some(result) = stream.next().await {
+        let mut pubsub_index = 0;
+        while let Some((is_pubsub_node, result)) = stream.next().await {
+            if is_pubsub_node {
+                pubsub_index = nodes.len();
+            }
             match result {
                 Ok((connection, replication_status)) => {
                     nodes.push(connection);
-                    if redis::from_owned_redis_value::<String>(replication_status)
-                        .is_ok_and(|val| val.contains("role:master"))
-                    {
+                    if is_primary_replication_info(replication_status) {
                         if let Some(primary_index) = primary_index {
                             // More than one primary found
                             return Err(StandaloneClientConnectionError::PrimaryConflictFound(
@@ -247,7 +386,8 @@ impl StandaloneClient {
             ));
         };
         if !addresses_and_errors.is_empty() {
-            log_warn(
+            log_warn_with_context(
+                log_context,
                 "client creation",
                 format!(
                     "Failed to connect to {addresses_and_errors:?}, will attempt to reconnect."
@@ -255,30 +395,50 @@ impl StandaloneClient {
             );
         }
         let read_from = get_read_from(connection_request.read_from);
+        let measure_latency = matches!(read_from, ReadFrom::LowestLatency { .. });
 
         #[cfg(feature = "standalone_heartbeat")]
         for node in nodes.iter() {
             Self::start_heartbeat(node.clone());
         }
 
-        for node in nodes.iter() {
-            Self::start_periodic_connection_check(node.clone());
+        let inner = Arc::new(DropWrapper {
+            primary_index: AtomicUsize::new(primary_index),
+            nodes,
+            read_from,
+            primary_rediscovery: Default::default(),
+            pubsub_index,
+            retry_strategy,
+            log_context,
+        });
+
+        for node_index in 0..inner.nodes.len() {
+            Self::start_periodic_connection_check(&inner, node_index, measure_latency);
+        }
+
+        if let Some((sentinels, service_name)) = sentinel {
+            Self::start_switch_master_listener(
+                Arc::downgrade(&inner),
+                sentinels,
+                service_name,
+                log_context,
+            );
         }
 
         // Successfully created new client. Update the telemetry
         Telemetry::incr_total_clients(1);
 
-        Ok(Self {
-            inner: Arc::new(DropWrapper {
-                primary_index,
-                nodes,
-                read_from,
-            }),
-        })
+        Ok(Self { inner })
     }
 
     fn get_primary_connection(&self) -> &ReconnectingConnection {
-        self.inner.nodes.get(self.inner.primary_index).unwrap()
+        self.inner.nodes.get(self.inner.primary_index()).unwrap()
+    }
+
+    /// Returns the connection to the node chosen on creation to hold the pubsub subscriptions,
+    /// which isn't necessarily the primary.
+    fn get_pubsub_connection(&self) -> &ReconnectingConnection {
+        self.inner.nodes.get(self.inner.pubsub_index).unwrap()
     }
 
     fn round_robin_read_from_replica(
@@ -295,7 +455,7 @@ impl StandaloneClient {
                 return self.get_primary_connection();
             }
             let index = (initial_index + check_count) % self.inner.nodes.len();
-            if index == self.inner.primary_index {
+            if index == self.inner.primary_index() {
                 continue;
             }
             let Some(connection) = self.inner.nodes.get(index) else {
@@ -399,6 +559,22 @@ impl StandaloneClient {
         self.round_robin_read_from_replica(latest_read_replica_index)
     }
 
+    fn lowest_latency_read_from_replica(
+        &self,
+        latest_read_replica_index: &Arc<AtomicUsize>,
+    ) -> &ReconnectingConnection {
+        self.inner
+            .nodes
+            .iter()
+            .enumerate()
+            .filter(|(index, node)| *index != self.inner.primary_index() && node.is_connected())
+            .filter_map(|(_, node)| node.latency().map(|latency| (latency, node)))
+            .min_by_key(|(latency, _)| *latency)
+            .map(|(_, node)| node)
+            // No latency was measured for any of the connected replicas yet.
+            .unwrap_or_else(|| self.round_robin_read_from_replica(latest_read_replica_index))
+    }
+
     async fn get_connection(&self, readonly: bool) -> &ReconnectingConnection {
         if self.inner.nodes.len() == 1 || !readonly {
             return self.get_primary_connection();
@@ -429,6 +605,9 @@ impl StandaloneClient {
                 )
                 .await
             }
+            ReadFrom::LowestLatency {
+                latest_read_replica_index,
+            } => self.lowest_latency_read_from_replica(latest_read_replica_index),
         }
     }
 
@@ -437,10 +616,35 @@ impl StandaloneClient {
         reconnecting_connection: &ReconnectingConnection,
     ) -> RedisResult<Value> {
         let mut connection = reconnecting_connection.get_connection().await?;
-        let result = connection.send_packed_command(cmd).await;
+        if let Some(span) = cmd.span() {
+            span.set_attribute("server.address", &reconnecting_connection.node_address());
+            span.set_attribute(
+                "db.namespace",
+                &reconnecting_connection.database_id().to_string(),
+            );
+        }
+        let started = Instant::now();
+        let result = redis::aio::req_command_with_surroundings(&mut connection, cmd).await;
+        let command = cmd.command().unwrap_or_default();
+        if let Err(e) = GlideOpenTelemetry::record_node_request_latency(
+            &String::from_utf8_lossy(&command),
+            &reconnecting_connection.node_address(),
+            request_outcome(&result),
+            started.elapsed(),
+        ) {
+            log_error_with_context(
+                reconnecting_connection.log_context(),
+                "OpenTelemetry:node_request_latency_error",
+                format!("Failed to record node request latency: {e}"),
+            );
+        }
         match result {
             Err(err) if err.is_unrecoverable_error() => {
-                log_warn("send request", format!("received disconnect error `{err}`"));
+                log_warn_with_context(
+                    reconnecting_connection.log_context(),
+                    "send request",
+                    format!("received disconnect error `{err}`"),
+                );
                 reconnecting_connection.reconnect(ReconnectReason::ConnectionDropped);
                 Err(err)
             }
@@ -524,8 +728,18 @@ impl StandaloneClient {
         cmd: &redis::Cmd,
         readonly: bool,
     ) -> RedisResult<Value> {
+        let primary_index = self.inner.primary_index();
         let reconnecting_connection = self.get_connection(readonly).await;
-        Self::send_request(cmd, reconnecting_connection).await
+        let result = Self::send_request(cmd, reconnecting_connection).await;
+        // The primary was demoted, e.g. after a failover - find the new primary and retry the request on it.
+        if result
+            .as_ref()
+            .is_err_and(|err| err.kind() == redis::ErrorKind::ReadOnly)
+            && self.inner.rediscover_primary(primary_index).await
+        {
+            return Self::send_request(cmd, self.get_primary_connection()).await;
+        }
+        result
     }
 
     pub async fn send_command(&mut self, cmd: &redis::Cmd) -> RedisResult<Value> {
@@ -541,6 +755,37 @@ impl StandaloneClient {
             .await
     }
 
+    /// Sends the command, retrying it according to `retry_policy`, with the backoff of the connection retry strategy.
+    /// Returns the result, along with the number of times the command was retried, whether it succeeded or not.
+    pub async fn send_command_with_retry_policy(
+        &mut self,
+        cmd: &redis::Cmd,
+        retry_policy: CommandRetryPolicy,
+    ) -> (RedisResult<Value>, u32) {
+        let max_retries = retry_policy.max_retries(DEFAULT_RETRIES);
+        let mut backoff = self
+            .inner
+            .retry_strategy
+            .get_infinite_backoff_dur_iterator();
+        let mut retries = 0;
+        loop {
+            match self.send_command(cmd).await {
+                Err(err) if retries < max_retries && retry_policy.allows_retry(&err) => {
+                    log_debug_with_context(
+                        self.inner.log_context,
+                        "send request",
+                        format!("retrying request after error `{err}`"),
+                    );
+                    retries += 1;
+                    if let Some(duration) = backoff.next() {
+                        tokio::time::sleep(duration).await;
+                    }
+                }
+                result => return (result, retries),
+            }
+        }
+    }
+
     pub async fn send_pipeline(
         &mut self,
         pipeline: &redis::Pipeline,
@@ -554,7 +799,8 @@ impl StandaloneClient {
             .await;
         match result {
             Err(err) if err.is_unrecoverable_error() => {
-                log_warn(
+                log_warn_with_context(
+                    reconnecting_connection.log_context(),
                     "pipeline request",
                     format!("received disconnect error `{err}`"),
                 );
@@ -571,7 +817,8 @@ impl StandaloneClient {
             loop {
                 tokio::time::sleep(super::HEARTBEAT_SLEEP_DURATION).await;
                 if reconnecting_connection.is_dropped() {
-                    log_debug(
+                    log_debug_with_context(
+                        reconnecting_connection.log_context(),
                         "StandaloneClient",
                         "heartbeat stopped after connection was dropped",
                     );
@@ -581,20 +828,29 @@ impl StandaloneClient {
 
                 let Some(mut connection) = reconnecting_connection.try_get_connection().await
                 else {
-                    log_debug(
+                    log_debug_with_context(
+                        reconnecting_connection.log_context(),
                         "StandaloneClient",
                         "heartbeat stopped while connection is reconnecting",
                     );
                     // Client is reconnecting..
                     continue;
                 };
-                log_debug("StandaloneClient", "performing heartbeat");
+                log_debug_with_context(
+                    reconnecting_connection.log_context(),
+                    "StandaloneClient",
+                    "performing heartbeat",
+                );
                 if connection
                     .send_packed_command(&redis::cmd("PING"))
                     .await
                     .is_err_and(|err| err.is_connection_dropped() || err.is_connection_refusal())
                 {
-                    log_debug("StandaloneClient", "heartbeat triggered reconnect");
+                    log_debug_with_context(
+                        reconnecting_connection.log_context(),
+                        "StandaloneClient",
+                        "heartbeat triggered reconnect",
+                    );
                     reconnecting_connection.reconnect(ReconnectReason::ConnectionDropped);
                 }
             }
@@ -604,7 +860,15 @@ impl StandaloneClient {
     // Monitors passive connection status and reconnects if necessary.
     // This function is cheaper alternative to start_heartbeat(),
     // as it avoids sending PING commands to the server, checking only the connection state.
-    fn start_periodic_connection_check(reconnecting_connection: ReconnectingConnection) {
+    // When `measure_latency` is set, a PING is sent on each check to sample the node's round-trip latency.
+    // When the primary's connection is lost, the nodes' roles are rescanned, so that a failover is followed.
+    fn start_periodic_connection_check(
+        inner: &Arc<DropWrapper>,
+        node_index: usize,
+        measure_latency: bool,
+    ) {
+        let reconnecting_connection = inner.nodes[node_index].clone();
+        let inner = Arc::downgrade(inner);
         task::spawn(async move {
             loop {
                 reconnecting_connection
@@ -612,7 +876,8 @@ impl StandaloneClient {
                     .await;
                 // check connection is valid
                 if reconnecting_connection.is_dropped() {
-                    log_debug(
+                    log_debug_with_context(
+                        reconnecting_connection.log_context(),
                         "StandaloneClient",
                         "connection checker stopped after connection was dropped",
                     );
@@ -621,26 +886,121 @@ impl StandaloneClient {
                     return;
                 }
 
-                let Some(connection) = reconnecting_connection.try_get_connection().await else {
-                    log_debug(
+                let Some(mut connection) = reconnecting_connection.try_get_connection().await
+                else {
+                    log_debug_with_context(
+                        reconnecting_connection.log_context(),
                         "StandaloneClient",
                         "connection checker is skipping a connections since its reconnecting",
                     );
                     // Client is reconnecting..
+                    rediscover_lost_primary(&inner, node_index).await;
                     continue;
                 };
 
                 if connection.is_closed() {
-                    log_debug(
+                    log_debug_with_context(
+                        reconnecting_connection.log_context(),
                         "StandaloneClient",
                         "connection checker has triggered reconnect",
                     );
                     reconnecting_connection.reconnect(ReconnectReason::ConnectionDropped);
+                    rediscover_lost_primary(&inner, node_index).await;
+                } else if measure_latency {
+                    let start = Instant::now();
+                    if connection
+                        .send_packed_command(&redis::cmd("PING"))
+                        .await
+                        .is_ok()
+                    {
+                        reconnecting_connection.update_latency(start.elapsed());
+                    }
                 }
             }
         });
     }
 
+    // Follows the sentinels' `+switch-master` events, so that the primary is updated after a failover.
+    // Cycles through the sentinels whenever the subscription is lost, and stops once the client is dropped.
+    fn start_switch_master_listener(
+        inner: Weak<DropWrapper>,
+        sentinels: Vec<redis::ConnectionInfo>,
+        service_name: String,
+        log_context: LogContext,
+    ) {
+        task::spawn(async move {
+            for sentinel in sentinels.iter().cycle() {
+                match Self::listen_to_switch_master(&inner, sentinel, &service_name).await {
+                    Ok(()) => {
+                        log_debug_with_context(
+                            log_context,
+                            "StandaloneClient",
+                            "switch-master listener stopped after client was dropped",
+                        );
+                        return;
+                    }
+                    Err(err) => log_warn_with_context(
+                        log_context,
+                        "StandaloneClient",
+                        format!(
+                            "Lost `{SWITCH_MASTER_CHANNEL}` subscription to sentinel `{}`: {err}",
+                            sentinel.addr
+                        ),
+                    ),
+                }
+                tokio::time::sleep(super::CONNECTION_CHECKS_INTERVAL).await;
+            }
+        });
+    }
+
+    async fn listen_to_switch_master(
+        inner: &Weak<DropWrapper>,
+        sentinel: &redis::ConnectionInfo,
+        service_name: &str,
+    ) -> RedisResult<()> {
+        if inner.strong_count() == 0 {
+            return Ok(());
+        }
+        let mut pubsub = redis::Client::open(sentinel.clone())?
+            .get_async_pubsub()
+            .await?;
+        pubsub.subscribe(SWITCH_MASTER_CHANNEL).await?;
+        let mut messages = pubsub.into_on_message();
+        loop {
+            let message = match tokio::time::timeout(
+                super::CONNECTION_CHECKS_INTERVAL,
+                messages.next(),
+            )
+            .await
+            {
+                Ok(Some(message)) => message,
+                Ok(None) => {
+                    return Err(RedisError::from((
+                        redis::ErrorKind::IoError,
+                        "Sentinel connection closed",
+                    )));
+                }
+                // Wake up periodically to check whether the client was dropped.
+                Err(_) => {
+                    if inner.strong_count() == 0 {
+                        return Ok(());
+                    }
+                    continue;
+                }
+            };
+            let Some(inner) = inner.upgrade() else {
+                return Ok(());
+            };
+            if let Some(new_primary) = message
+                .get_payload::<String>()
+                .ok()
+                .and_then(|payload| parse_switch_master_message(&payload, service_name))
+            {
+                inner.update_primary(&new_primary);
+            }
+        }
+    }
+
     /// Update the password used to authenticate with the servers.
     /// If the password is `None`, the password will be removed.
     pub async fn update_connection_password(
@@ -675,6 +1035,48 @@ impl StandaloneClient {
         Ok(Value::Okay)
     }
 
+    /// Subscribe the node holding the subscriptions from the connection request to the given channels or patterns.
+    /// The subscriptions are also saved inside connection_info, so they're restored after reconnecting.
+    pub async fn subscribe(
+        &self,
+        kind: PubSubSubscriptionKind,
+        channels_or_patterns: Vec<PubSubChannelOrPattern>,
+    ) -> RedisResult<Value> {
+        let pubsub_node = self.get_pubsub_connection();
+        check_subscriptions_protocol(pubsub_node)?;
+        pubsub_node.add_pubsub_subscriptions(kind, &channels_or_patterns);
+        let mut connection = pubsub_node.get_connection().await?;
+        // Each channel gets its own command, since every channel is confirmed by a separate push message.
+        for channel_or_pattern in channels_or_patterns {
+            let mut cmd = redis::cmd(subscription_command(kind, true));
+            cmd.arg(channel_or_pattern);
+            connection.send_packed_command(&cmd).await?;
+        }
+        Ok(Value::Okay)
+    }
+
+    /// Unsubscribe the node holding the subscriptions from the given channels or patterns,
+    /// or from all subscriptions of `kind` if `channels_or_patterns` is empty.
+    pub async fn unsubscribe(
+        &self,
+        kind: PubSubSubscriptionKind,
+        channels_or_patterns: Vec<PubSubChannelOrPattern>,
+    ) -> RedisResult<Value> {
+        let pubsub_node = self.get_pubsub_connection();
+        check_subscriptions_protocol(pubsub_node)?;
+        let removed = pubsub_node.remove_pubsub_subscriptions(kind, &channels_or_patterns);
+        if removed.is_empty() {
+            return Ok(Value::Okay);
+        }
+        let mut connection = pubsub_node.get_connection().await?;
+        for channel_or_pattern in removed {
+            let mut cmd = redis::cmd(subscription_command(kind, false));
+            cmd.arg(channel_or_pattern);
+            connection.send_packed_command(&cmd).await?;
+        }
+        Ok(Value::Okay)
+    }
+
     /// Retrieve the username used to authenticate with the server.
     pub fn get_username(&self) -> Option<String> {
         // All nodes in the client should have the same username configured, thus any connection would work here.
@@ -692,6 +1094,7 @@ async fn get_connection_and_replication_info(
     discover_az: bool,
     connection_timeout: Duration,
     tls_params: Option<redis::TlsConnParams>,
+    log_context: LogContext,
 ) -> Result<(ReconnectingConnection, Value), (ReconnectingConnection, RedisError)> {
     let reconnecting_connection = ReconnectingConnection::new(
         address,
@@ -702,6 +1105,7 @@ async fn get_connection_and_replication_info(
         discover_az,
         connection_timeout,
         tls_params,
+        log_context,
     )
     .await?;
 
@@ -713,15 +1117,72 @@ async fn get_connection_and_replication_info(
         }
     };
 
+    // The replication info round-trip also serves as the node's initial latency sample.
+    let start = Instant::now();
     match multiplexed_connection
         .send_packed_command(redis::cmd("INFO").arg("REPLICATION"))
         .await
     {
-        Ok(replication_status) => Ok((reconnecting_connection, replication_status)),
+        Ok(replication_status) => {
+            reconnecting_connection.update_latency(start.elapsed());
+            Ok((reconnecting_connection, replication_status))
+        }
         Err(err) => Err((reconnecting_connection, err)),
     }
 }
 
+fn is_primary_replication_info(replication_info: Value) -> bool {
+    redis::from_owned_redis_value::<String>(replication_info)
+        .is_ok_and(|val| val.contains("role:master"))
+}
+
+/// Checks the node's role. Nodes that are currently reconnecting aren't considered primaries.
+async fn is_primary(node: &ReconnectingConnection) -> bool {
+    let Some(mut connection) = node.try_get_connection().await else {
+        return false;
+    };
+    connection
+        .send_packed_command(redis::cmd("INFO").arg("REPLICATION"))
+        .await
+        .is_ok_and(is_primary_replication_info)
+}
+
+/// Rescans the nodes' roles if the lost connection is to the client's primary.
+async fn rediscover_lost_primary(inner: &Weak<DropWrapper>, node_index: usize) {
+    let Some(inner) = inner.upgrade() else {
+        return;
+    };
+    if inner.nodes.len() > 1 && inner.primary_index() == node_index {
+        log_debug_with_context(
+            inner.log_context,
+            "StandaloneClient",
+            "connection checker has triggered primary rediscovery",
+        );
+        inner.rediscover_primary(node_index).await;
+    }
+}
+
+fn check_subscriptions_protocol(connection: &ReconnectingConnection) -> RedisResult<()> {
+    if connection.get_protocol() != ProtocolVersion::RESP3 {
+        return Err(RedisError::from((
+            redis::ErrorKind::InvalidClientConfig,
+            "Subscriptions can only be tracked with RESP3",
+        )));
+    }
+    Ok(())
+}
+
+fn subscription_command(kind: PubSubSubscriptionKind, subscribe: bool) -> &'static str {
+    match (kind, subscribe) {
+        (PubSubSubscriptionKind::Exact, true) => "SUBSCRIBE",
+        (PubSubSubscriptionKind::Exact, false) => "UNSUBSCRIBE",
+        (PubSubSubscriptionKind::Pattern, true) => "PSUBSCRIBE",
+        (PubSubSubscriptionKind::Pattern, false) => "PUNSUBSCRIBE",
+        (PubSubSubscriptionKind::Sharded, true) => "SSUBSCRIBE",
+        (PubSubSubscriptionKind::Sharded, false) => "SUNSUBSCRIBE",
+    }
+}
+
 fn get_read_from(read_from: Option<super::ReadFrom>) -> ReadFrom {
     match read_from {
         Some(super::ReadFrom::Primary) => ReadFrom::Primary,
@@ -738,6 +1199,66 @@ fn get_read_from(read_from: Option<super::ReadFrom>) -> ReadFrom {
                 last_read_replica_index: Default::default(),
             }
         }
+        Some(super::ReadFrom::LowestLatency) => ReadFrom::LowestLatency {
+            latest_read_replica_index: Default::default(),
+        },
         None => ReadFrom::Primary,
     }
 }
+
+fn get_sentinels_connection_info(
+    addresses: &[NodeAddress],
+    sentinel_configuration: &SentinelConfiguration,
+    tls_mode: TlsMode,
+    tls_params: Option<redis::TlsConnParams>,
+) -> Vec<redis::ConnectionInfo> {
+    let redis_connection_info = redis::RedisConnectionInfo {
+        username: sentinel_configuration
+            .authentication_info
+            .as_ref()
+            .and_then(|info| info.username.clone()),
+        password: sentinel_configuration
+            .authentication_info
+            .as_ref()
+            .and_then(|info| info.password.clone()),
+        ..Default::default()
+    };
+    addresses
+        .iter()
+        .map(|address| {
+            get_connection_info(
+                address,
+                tls_mode,
+                redis_connection_info.clone(),
+                tls_params.clone(),
+            )
+        })
+        .collect()
+}
+
+/// Queries the sentinels for the nodes of the given service. The primary is returned first.
+async fn discover_nodes_from_sentinels(
+    sentinels: &[redis::ConnectionInfo],
+    service_name: &str,
+) -> RedisResult<Vec<NodeAddress>> {
+    let mut sentinel = Sentinel::build(sentinels.to_vec())?;
+    let (primary, replicas) = sentinel
+        .async_master_and_replica_addresses_for(service_name)
+        .await?;
+    Ok(std::iter::once(primary)
+        .chain(replicas)
+        .map(|(host, port)| NodeAddress { host, port })
+        .collect())
+}
+
+/// Parses a `+switch-master` message - `<service name> <old ip> <old port> <new ip> <new port>`,
+/// returning the address of the new primary if the message refers to `service_name`.
+fn parse_switch_master_message(payload: &str, service_name: &str) -> Option<String> {
+    let mut parts = payload.split_whitespace();
+    if parts.next()? != service_name {
+        return None;
+    }
+    let host = parts.nth(2)?;
+    let port = parts.next()?;
+    Some(format!("{host}:{port}"))
+}
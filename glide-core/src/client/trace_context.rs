@@ -0,0 +1,102 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Propagation of the trace context of traced commands to the server.
+//!
+//! A traced command is surrounded by commands that tag the connection with the command's W3C `traceparent`
+//! through `CLIENT SETNAME` before the command, and restore the client's name after it. The surrounding
+//! commands are pipelined with the command on the connection it's routed to, so the command's slowlog and
+//! `CLIENT LIST` entries carry the `traceparent`, and can be tied back to the distributed trace.
+
+use redis::Cmd;
+use redis::cluster_routing::Routable;
+use std::sync::RwLock;
+
+pub(crate) struct TraceContextPropagation {
+    /// The client's name, restored after each traced command.
+    client_name: RwLock<Option<String>>,
+}
+
+impl TraceContextPropagation {
+    pub(crate) fn new(client_name: Option<String>) -> Self {
+        Self {
+            client_name: RwLock::new(client_name),
+        }
+    }
+
+    /// Updates the client name restored after traced commands, after the user set it.
+    pub(crate) fn set_client_name(&self, client_name: Option<String>) {
+        *self.client_name.write().expect("client name lock poisoned") = client_name;
+    }
+
+    /// Returns a copy of `cmd` that's surrounded by the commands that propagate its trace context. Returns `None`
+    /// if the command isn't traced, or if it reads or changes the client's name, which the surrounding commands
+    /// would interfere with.
+    pub(crate) fn traced_command(&self, cmd: &Cmd) -> Option<Cmd> {
+        let span = cmd.span()?;
+        if cmd
+            .command()
+            .is_some_and(|command| command == b"CLIENT SETNAME" || command == b"CLIENT GETNAME")
+        {
+            return None;
+        }
+        let client_name = self
+            .client_name
+            .read()
+            .expect("client name lock poisoned")
+            .clone()
+            .unwrap_or_default();
+
+        let mut tag = redis::cmd("CLIENT");
+        tag.arg("SETNAME").arg(span.traceparent());
+        let mut restore = redis::cmd("CLIENT");
+        restore.arg("SETNAME").arg(client_name);
+
+        let mut traced_cmd = cmd.clone();
+        traced_cmd.set_surrounding_commands(Some((tag, restore)));
+        Some(traced_cmd)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use telemetrylib::GlideSpan;
+
+    #[test]
+    fn test_traced_command_tags_and_restores_client_name() {
+        let propagation = TraceContextPropagation::new(Some("app".to_string()));
+        let mut cmd = redis::cmd("GET");
+        cmd.arg("key");
+        assert!(propagation.traced_command(&cmd).is_none());
+
+        let span = GlideSpan::new("GET");
+        cmd.set_span(Some(span.clone()));
+        let traced_cmd = propagation.traced_command(&cmd).unwrap();
+        assert_eq!(traced_cmd.get_packed_command(), cmd.get_packed_command());
+        let (tag, restore) = traced_cmd.surrounding_commands().unwrap();
+        assert_eq!(
+            tag.get_packed_command(),
+            redis::cmd("CLIENT")
+                .arg("SETNAME")
+                .arg(span.traceparent())
+                .get_packed_command()
+        );
+        assert_eq!(
+            restore.get_packed_command(),
+            redis::cmd("CLIENT")
+                .arg("SETNAME")
+                .arg("app")
+                .get_packed_command()
+        );
+        span.end();
+    }
+
+    #[test]
+    fn test_commands_that_use_the_client_name_are_not_tagged() {
+        let propagation = TraceContextPropagation::new(None);
+        let mut cmd = redis::cmd("CLIENT");
+        cmd.arg("SETNAME").arg("name");
+        cmd.set_span(Some(GlideSpan::new("CLIENT SETNAME")));
+        assert!(propagation.traced_command(&cmd).is_none());
+    }
+}
@@ -0,0 +1,635 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Typed methods for every command in [`RequestType`].
+//!
+//! Each method appends its parameters to the request's command in order, sends it with the client's default routing,
+//! and converts the response - after the same conversion that the language wrappers receive - into a Rust type.
+//! Options that change the response's shape, such as `WITHSCORES` or `SET ... GET`, aren't supported and fail the
+//! conversion. Commands whose response shape is defined by a script or module (e.g. `EVAL`, `JSON.*`), or depends on
+//! the nodes they're routed to (e.g. `INFO` in cluster mode), don't have typed methods and are sent with
+//! [`Client::send_command`].
+//!
+//! Optional arguments that are keywords or numbers, such as `NX` or `EX 10`, are passed in `options`.
+//! Parameters that the command prefixes with their count, such as the keys of `ZDIFF`, are slices.
+//!
+//! Subscriptions, transactions and replication streams (e.g. `SUBSCRIBE`, `MULTI`, `MONITOR`) aren't
+//! covered, since they are handled by dedicated client APIs.
+
+use super::Client;
+use crate::request_type::RequestType;
+use redis::{Cmd, ErrorKind, FromRedisValue, RedisError, RedisResult, ToRedisArgs, Value};
+use std::collections::{HashMap, HashSet};
+
+/// The field-value pairs of a stream entry, in order.
+pub type StreamFields = Vec<(Vec<u8>, Vec<u8>)>;
+
+/// Stream entries, as pairs of entry ID and fields, in order.
+pub type StreamEntries = Vec<(String, StreamFields)>;
+
+/// A cursor, followed by the elements returned in this iteration of a `SCAN`-like command.
+pub type ScanResult = (String, Vec<Vec<u8>>);
+
+macro_rules! typed_commands {
+    (@type $param:ident Counted) => { &[impl ToRedisArgs + Sync] };
+    (@type options) => { &[&str] };
+    (@type $param:ident) => { impl ToRedisArgs + Send };
+    (@arg $cmd:ident $param:ident Counted) => { $cmd.arg($param.len()).arg($param) };
+    (@arg $cmd:ident $param:ident) => { $cmd.arg($param) };
+    ($($name:ident ($($param:ident $(: $kind:ident)?),*) -> $ret:ty = $request_type:ident;)*) => {
+        impl Client {
+            $(
+                #[doc = concat!("Sends a [`RequestType::", stringify!($request_type), "`] request.")]
+                pub async fn $name(
+                    &mut self,
+                    $($param: typed_commands!(@type $param $($kind)?)),*
+                ) -> RedisResult<$ret> {
+                    #[allow(unused_mut)]
+                    let mut cmd = typed_command(RequestType::$request_type)?;
+                    $(typed_commands!(@arg cmd $param $($kind)?);)*
+                    self.send_typed_command(cmd).await
+                }
+            )*
+        }
+    };
+}
+
+/// Returns the command of `request_type`, for the request types that have typed methods.
+fn typed_command(request_type: RequestType) -> RedisResult<Cmd> {
+    match request_type {
+        RequestType::InvalidRequest | RequestType::CustomCommand => None,
+        _ => request_type.get_command(),
+    }
+    .ok_or_else(|| {
+        RedisError::from((
+            ErrorKind::ClientError,
+            "Request type has no typed method",
+            format!("{request_type:?}"),
+        ))
+    })
+}
+
+impl Client {
+    /// Sends `cmd`, and converts the response into `T`.
+    async fn send_typed_command<T: FromRedisValue>(&mut self, cmd: Cmd) -> RedisResult<T> {
+        let value = self.send_command(&cmd, None).await?;
+        T::from_owned_redis_value(value)
+    }
+
+    /// Sends a [`RequestType::HSetEx`] request. `field_values` are sent after `FIELDS` and their count.
+    pub async fn hsetex(
+        &mut self,
+        key: impl ToRedisArgs + Send,
+        options: &[&str],
+        field_values: &[(impl ToRedisArgs + Sync, impl ToRedisArgs + Sync)],
+    ) -> RedisResult<bool> {
+        let mut cmd = typed_command(RequestType::HSetEx)?;
+        cmd.arg(key)
+            .arg(options)
+            .arg("FIELDS")
+            .arg(field_values.len())
+            .arg(field_values);
+        self.send_typed_command(cmd).await
+    }
+
+    /// Sends a [`RequestType::HGetEx`] request. `fields` are sent after `FIELDS` and their count.
+    pub async fn hgetex(
+        &mut self,
+        key: impl ToRedisArgs + Send,
+        options: &[&str],
+        fields: &[impl ToRedisArgs + Sync],
+    ) -> RedisResult<Vec<Option<Vec<u8>>>> {
+        self.send_hash_field_command(RequestType::HGetEx, key, options, fields)
+            .await
+    }
+
+    /// Sends a [`RequestType::HExpire`] request. `fields` are sent after `FIELDS` and their count.
+    pub async fn hexpire(
+        &mut self,
+        key: impl ToRedisArgs + Send,
+        seconds: i64,
+        options: &[&str],
+        fields: &[impl ToRedisArgs + Sync],
+    ) -> RedisResult<Vec<i64>> {
+        self.send_hash_field_command(RequestType::HExpire, (key, seconds), options, fields)
+            .await
+    }
+
+    /// Sends a [`RequestType::HExpireAt`] request. `fields` are sent after `FIELDS` and their count.
+    pub async fn hexpireat(
+        &mut self,
+        key: impl ToRedisArgs + Send,
+        unix_time_seconds: i64,
+        options: &[&str],
+        fields: &[impl ToRedisArgs + Sync],
+    ) -> RedisResult<Vec<i64>> {
+        self.send_hash_field_command(
+            RequestType::HExpireAt,
+            (key, unix_time_seconds),
+            options,
+            fields,
+        )
+        .await
+    }
+
+    /// Sends a [`RequestType::HPExpire`] request. `fields` are sent after `FIELDS` and their count.
+    pub async fn hpexpire(
+        &mut self,
+        key: impl ToRedisArgs + Send,
+        milliseconds: i64,
+        options: &[&str],
+        fields: &[impl ToRedisArgs + Sync],
+    ) -> RedisResult<Vec<i64>> {
+        self.send_hash_field_command(RequestType::HPExpire, (key, milliseconds), options, fields)
+            .await
+    }
+
+    /// Sends a [`RequestType::HPExpireAt`] request. `fields` are sent after `FIELDS` and their count.
+    pub async fn hpexpireat(
+        &mut self,
+        key: impl ToRedisArgs + Send,
+        unix_time_milliseconds: i64,
+        options: &[&str],
+        fields: &[impl ToRedisArgs + Sync],
+    ) -> RedisResult<Vec<i64>> {
+        self.send_hash_field_command(
+            RequestType::HPExpireAt,
+            (key, unix_time_milliseconds),
+            options,
+            fields,
+        )
+        .await
+    }
+
+    /// Sends a [`RequestType::HPersist`] request. `fields` are sent after `FIELDS` and their count.
+    pub async fn hpersist(
+        &mut self,
+        key: impl ToRedisArgs + Send,
+        fields: &[impl ToRedisArgs + Sync],
+    ) -> RedisResult<Vec<i64>> {
+        self.send_hash_field_command(RequestType::HPersist, key, &[], fields)
+            .await
+    }
+
+    /// Sends a [`RequestType::HTtl`] request. `fields` are sent after `FIELDS` and their count.
+    pub async fn httl(
+        &mut self,
+        key: impl ToRedisArgs + Send,
+        fields: &[impl ToRedisArgs + Sync],
+    ) -> RedisResult<Vec<i64>> {
+        self.send_hash_field_command(RequestType::HTtl, key, &[], fields)
+            .await
+    }
+
+    /// Sends a [`RequestType::HPTtl`] request. `fields` are sent after `FIELDS` and their count.
+    pub async fn hpttl(
+        &mut self,
+        key: impl ToRedisArgs + Send,
+        fields: &[impl ToRedisArgs + Sync],
+    ) -> RedisResult<Vec<i64>> {
+        self.send_hash_field_command(RequestType::HPTtl, key, &[], fields)
+            .await
+    }
+
+    /// Sends a [`RequestType::HExpireTime`] request. `fields` are sent after `FIELDS` and their count.
+    pub async fn hexpiretime(
+        &mut self,
+        key: impl ToRedisArgs + Send,
+        fields: &[impl ToRedisArgs + Sync],
+    ) -> RedisResult<Vec<i64>> {
+        self.send_hash_field_command(RequestType::HExpireTime, key, &[], fields)
+            .await
+    }
+
+    /// Sends a [`RequestType::HPExpireTime`] request. `fields` are sent after `FIELDS` and their count.
+    pub async fn hpexpiretime(
+        &mut self,
+        key: impl ToRedisArgs + Send,
+        fields: &[impl ToRedisArgs + Sync],
+    ) -> RedisResult<Vec<i64>> {
+        self.send_hash_field_command(RequestType::HPExpireTime, key, &[], fields)
+            .await
+    }
+
+    async fn send_hash_field_command<T: FromRedisValue>(
+        &mut self,
+        request_type: RequestType,
+        key_and_args: impl ToRedisArgs + Send,
+        options: &[&str],
+        fields: &[impl ToRedisArgs + Sync],
+    ) -> RedisResult<T> {
+        let mut cmd = typed_command(request_type)?;
+        cmd.arg(key_and_args)
+            .arg(options)
+            .arg("FIELDS")
+            .arg(fields.len())
+            .arg(fields);
+        self.send_typed_command(cmd).await
+    }
+
+    /// Sends a [`RequestType::XRead`] request. `keys` and `ids` are sent after `STREAMS`, and must have the same length.
+    pub async fn xread(
+        &mut self,
+        options: &[&str],
+        keys: &[impl ToRedisArgs + Sync],
+        ids: &[impl ToRedisArgs + Sync],
+    ) -> RedisResult<Option<Vec<(Vec<u8>, StreamEntries)>>> {
+        let mut cmd = typed_command(RequestType::XRead)?;
+        cmd.arg(options).arg("STREAMS").arg(keys).arg(ids);
+        self.send_typed_command(cmd).await
+    }
+
+    /// Sends a [`RequestType::XReadGroup`] request. `keys` and `ids` are sent after `STREAMS`, and must have the same length.
+    pub async fn xreadgroup(
+        &mut self,
+        group: impl ToRedisArgs + Send,
+        consumer: impl ToRedisArgs + Send,
+        options: &[&str],
+        keys: &[impl ToRedisArgs + Sync],
+        ids: &[impl ToRedisArgs + Sync],
+    ) -> RedisResult<Option<Vec<(Vec<u8>, StreamEntries)>>> {
+        let mut cmd = typed_command(RequestType::XReadGroup)?;
+        cmd.arg("GROUP")
+            .arg(group)
+            .arg(consumer)
+            .arg(options)
+            .arg("STREAMS")
+            .arg(keys)
+            .arg(ids);
+        self.send_typed_command(cmd).await
+    }
+}
+
+typed_commands! {
+    // Bitmap commands
+    bitcount(key, options) -> i64 = BitCount;
+    bitfield(key, operations) -> Vec<Option<i64>> = BitField;
+    bitfield_ro(key, operations) -> Vec<Option<i64>> = BitFieldReadOnly;
+    bitop(operation, destination, keys) -> i64 = BitOp;
+    bitpos(key, bit, options) -> i64 = BitPos;
+    getbit(key, offset) -> i64 = GetBit;
+    setbit(key, offset, value) -> i64 = SetBit;
+
+    // Cluster commands
+    asking() -> () = Asking;
+    cluster_addslots(slots) -> () = ClusterAddSlots;
+    cluster_addslotsrange(slot_ranges) -> () = ClusterAddSlotsRange;
+    cluster_bumpepoch() -> String = ClusterBumpEpoch;
+    cluster_count_failure_reports(node_id) -> i64 = ClusterCountFailureReports;
+    cluster_countkeysinslot(slot) -> i64 = ClusterCountKeysInSlot;
+    cluster_delslots(slots) -> () = ClusterDelSlots;
+    cluster_delslotsrange(slot_ranges) -> () = ClusterDelSlotsRange;
+    cluster_failover(options) -> () = ClusterFailover;
+    cluster_flushslots() -> () = ClusterFlushSlots;
+    cluster_forget(node_id) -> () = ClusterForget;
+    cluster_getkeysinslot(slot, count) -> Vec<Vec<u8>> = ClusterGetKeysInSlot;
+    cluster_info() -> String = ClusterInfo;
+    cluster_keyslot(key) -> i64 = ClusterKeySlot;
+    cluster_meet(ip, port, options) -> () = ClusterMeet;
+    cluster_myid() -> String = ClusterMyId;
+    cluster_myshardid() -> String = ClusterMyShardId;
+    cluster_nodes() -> String = ClusterNodes;
+    cluster_replicas(node_id) -> Vec<String> = ClusterReplicas;
+    cluster_replicate(node_id) -> () = ClusterReplicate;
+    cluster_reset(options) -> () = ClusterReset;
+    cluster_saveconfig() -> () = ClusterSaveConfig;
+    cluster_set_config_epoch(config_epoch) -> () = ClusterSetConfigEpoch;
+    cluster_setslot(slot, subcommand) -> () = ClusterSetslot;
+    cluster_slaves(node_id) -> Vec<String> = ClusterSlaves;
+    readonly() -> () = ReadOnly;
+    readwrite() -> () = ReadWrite;
+
+    // Connection management commands
+    auth(username, password) -> () = Auth;
+    client_caching(mode) -> () = ClientCaching;
+    client_getname() -> Option<String> = ClientGetName;
+    client_getredir() -> i64 = ClientGetRedir;
+    client_id() -> i64 = ClientId;
+    client_info() -> String = ClientInfo;
+    client_kill_simple(address) -> () = ClientKillSimple;
+    client_kill(filters) -> i64 = ClientKill;
+    client_list(options) -> String = ClientList;
+    client_no_evict(mode) -> () = ClientNoEvict;
+    client_no_touch(mode) -> () = ClientNoTouch;
+    client_pause(timeout, options) -> () = ClientPause;
+    client_reply(mode) -> () = ClientReply;
+    client_setinfo(attribute, value) -> () = ClientSetInfo;
+    client_setname(name) -> () = ClientSetName;
+    client_tracking(status, options) -> () = ClientTracking;
+    client_trackinginfo() -> HashMap<String, Value> = ClientTrackingInfo;
+    client_unblock(client_id, options) -> bool = ClientUnblock;
+    client_unpause() -> () = ClientUnpause;
+    echo(message) -> Vec<u8> = Echo;
+    hello(options) -> HashMap<String, Value> = Hello;
+    ping(options) -> String = Ping;
+    quit() -> () = Quit;
+    reset() -> String = Reset;
+    select(index) -> () = Select;
+
+    // Generic commands
+    copy(source, destination, options) -> bool = Copy;
+    del(keys) -> i64 = Del;
+    dump(key) -> Option<Vec<u8>> = Dump;
+    exists(keys) -> i64 = Exists;
+    expire(key, seconds, options) -> bool = Expire;
+    expireat(key, unix_time_seconds, options) -> bool = ExpireAt;
+    expiretime(key) -> i64 = ExpireTime;
+    keys(pattern) -> Vec<Vec<u8>> = Keys;
+    migrate(host, port, key, destination_db, timeout, options) -> String = Migrate;
+    move_key(key, db) -> bool = Move;
+    object_encoding(key) -> Option<String> = ObjectEncoding;
+    object_freq(key) -> Option<i64> = ObjectFreq;
+    object_idletime(key) -> Option<i64> = ObjectIdleTime;
+    object_refcount(key) -> Option<i64> = ObjectRefCount;
+    persist(key) -> bool = Persist;
+    pexpire(key, milliseconds, options) -> bool = PExpire;
+    pexpireat(key, unix_time_milliseconds, options) -> bool = PExpireAt;
+    pexpiretime(key) -> i64 = PExpireTime;
+    pttl(key) -> i64 = PTTL;
+    randomkey() -> Option<Vec<u8>> = RandomKey;
+    rename(key, new_key) -> () = Rename;
+    renamenx(key, new_key) -> bool = RenameNX;
+    restore(key, ttl, serialized_value, options) -> () = Restore;
+    scan(cursor, options) -> ScanResult = Scan;
+    sort(key, options) -> Vec<Option<Vec<u8>>> = Sort;
+    sort_ro(key, options) -> Vec<Option<Vec<u8>>> = SortReadOnly;
+    touch(keys) -> i64 = Touch;
+    ttl(key) -> i64 = TTL;
+    key_type(key) -> String = Type;
+    unlink(keys) -> i64 = Unlink;
+    wait(num_replicas, timeout) -> i64 = Wait;
+    waitaof(num_local, num_replicas, timeout) -> (i64, i64) = WaitAof;
+
+    // Geospatial indices commands
+    geoadd(key, options, longitude_latitude_members) -> i64 = GeoAdd;
+    geodist(key, member1, member2, options) -> Option<f64> = GeoDist;
+    geohash(key, members) -> Vec<Option<String>> = GeoHash;
+    geopos(key, members) -> Vec<Option<Vec<f64>>> = GeoPos;
+    georadius(key, longitude, latitude, radius, unit, options) -> Vec<Vec<u8>> = GeoRadius;
+    georadius_ro(key, longitude, latitude, radius, unit, options) -> Vec<Vec<u8>> = GeoRadiusReadOnly;
+    georadiusbymember(key, member, radius, unit, options) -> Vec<Vec<u8>> = GeoRadiusByMember;
+    georadiusbymember_ro(key, member, radius, unit, options) -> Vec<Vec<u8>> = GeoRadiusByMemberReadOnly;
+    geosearch(key, options) -> Vec<Vec<u8>> = GeoSearch;
+    geosearchstore(destination, source, options) -> i64 = GeoSearchStore;
+
+    // Hash commands
+    hdel(key, fields) -> i64 = HDel;
+    hexists(key, field) -> bool = HExists;
+    hget(key, field) -> Option<Vec<u8>> = HGet;
+    hgetall(key) -> HashMap<Vec<u8>, Vec<u8>> = HGetAll;
+    hincrby(key, field, increment) -> i64 = HIncrBy;
+    hincrbyfloat(key, field, increment) -> f64 = HIncrByFloat;
+    hkeys(key) -> Vec<Vec<u8>> = HKeys;
+    hlen(key) -> i64 = HLen;
+    hmget(key, fields) -> Vec<Option<Vec<u8>>> = HMGet;
+    hmset(key, field_values) -> () = HMSet;
+    hrandfield(key) -> Option<Vec<u8>> = HRandField;
+    hrandfield_count(key, count) -> Vec<Vec<u8>> = HRandField;
+    hscan(key, cursor, options) -> ScanResult = HScan;
+    hset(key, field_values) -> i64 = HSet;
+    hsetnx(key, field, value) -> bool = HSetNX;
+    hstrlen(key, field) -> i64 = HStrlen;
+    hvals(key) -> Vec<Vec<u8>> = HVals;
+
+    // HyperLogLog commands
+    pfadd(key, elements) -> bool = PfAdd;
+    pfcount(keys) -> i64 = PfCount;
+    pfmerge(destination, sources) -> () = PfMerge;
+
+    // List commands
+    blmove(source, destination, where_from, where_to, timeout) -> Option<Vec<u8>> = BLMove;
+    blmpop(timeout, keys: Counted, direction, options) -> Option<HashMap<Vec<u8>, Vec<Vec<u8>>>> = BLMPop;
+    blpop(keys, timeout) -> Option<(Vec<u8>, Vec<u8>)> = BLPop;
+    brpop(keys, timeout) -> Option<(Vec<u8>, Vec<u8>)> = BRPop;
+    brpoplpush(source, destination, timeout) -> Option<Vec<u8>> = BRPopLPush;
+    lindex(key, index) -> Option<Vec<u8>> = LIndex;
+    linsert(key, position, pivot, element) -> i64 = LInsert;
+    llen(key) -> i64 = LLen;
+    lmove(source, destination, where_from, where_to) -> Option<Vec<u8>> = LMove;
+    lmpop(keys: Counted, direction, options) -> Option<HashMap<Vec<u8>, Vec<Vec<u8>>>> = LMPop;
+    lpop(key) -> Option<Vec<u8>> = LPop;
+    lpop_count(key, count) -> Option<Vec<Vec<u8>>> = LPop;
+    lpos(key, element, options) -> Option<i64> = LPos;
+    lpush(key, elements) -> i64 = LPush;
+    lpushx(key, elements) -> i64 = LPushX;
+    lrange(key, start, stop) -> Vec<Vec<u8>> = LRange;
+    lrem(key, count, element) -> i64 = LRem;
+    lset(key, index, element) -> () = LSet;
+    ltrim(key, start, stop) -> () = LTrim;
+    rpop(key) -> Option<Vec<u8>> = RPop;
+    rpop_count(key, count) -> Option<Vec<Vec<u8>>> = RPop;
+    rpoplpush(source, destination) -> Option<Vec<u8>> = RPopLPush;
+    rpush(key, elements) -> i64 = RPush;
+    rpushx(key, elements) -> i64 = RPushX;
+
+    // Pub/Sub commands
+    publish(channel, message) -> i64 = Publish;
+    pubsub_channels(options) -> Vec<Vec<u8>> = PubSubChannels;
+    pubsub_numpat() -> i64 = PubSubNumPat;
+    pubsub_numsub(channels) -> HashMap<Vec<u8>, i64> = PubSubNumSub;
+    pubsub_shardchannels(options) -> Vec<Vec<u8>> = PubSubShardChannels;
+    pubsub_shardnumsub(shard_channels) -> HashMap<Vec<u8>, i64> = PubSubShardNumSub;
+    spublish(shard_channel, message) -> i64 = SPublish;
+
+    // Scripting and Functions commands
+    function_delete(library_name) -> () = FunctionDelete;
+    function_dump() -> Vec<u8> = FunctionDump;
+    function_flush(options) -> () = FunctionFlush;
+    function_kill() -> () = FunctionKill;
+    function_list(options) -> Vec<HashMap<String, Value>> = FunctionList;
+    function_load(options, function_code) -> String = FunctionLoad;
+    function_restore(serialized_value, options) -> () = FunctionRestore;
+    script_debug(mode) -> () = ScriptDebug;
+    script_exists(sha1s) -> Vec<bool> = ScriptExists;
+    script_flush(options) -> () = ScriptFlush;
+    script_kill() -> () = ScriptKill;
+    script_load(script) -> String = ScriptLoad;
+    script_show(sha1) -> Vec<u8> = ScriptShow;
+
+    // Server management commands
+    acl_cat(options) -> Vec<String> = AclCat;
+    acl_deluser(usernames) -> i64 = AclDelUser;
+    acl_dryrun(username, command) -> String = AclDryRun;
+    acl_genpass(options) -> String = AclGenPass;
+    acl_getuser(username) -> Option<HashMap<String, Value>> = AclGetUser;
+    acl_list() -> Vec<String> = AclList;
+    acl_load() -> () = AclLoad;
+    acl_save() -> () = AclSave;
+    acl_setuser(username, rules) -> () = AclSetSser;
+    acl_users() -> Vec<String> = AclUsers;
+    acl_whoami() -> String = AclWhoami;
+    bgrewriteaof() -> String = BgRewriteAof;
+    bgsave(options) -> String = BgSave;
+    command_count() -> i64 = CommandCount;
+    command_getkeys(command) -> Vec<Vec<u8>> = CommandGetKeys;
+    command_list(options) -> Vec<String> = CommandList;
+    config_resetstat() -> () = ConfigResetStat;
+    config_rewrite() -> () = ConfigRewrite;
+    config_set(parameter_values) -> () = ConfigSet;
+    dbsize() -> i64 = DBSize;
+    failover(options) -> () = FailOver;
+    flushall(options) -> () = FlushAll;
+    flushdb(options) -> () = FlushDB;
+    lastsave() -> i64 = LastSave;
+    latency_reset(options) -> i64 = LatencyReset;
+    lolwut(options) -> String = Lolwut;
+    memory_purge() -> () = MemoryPurge;
+    memory_usage(key, options) -> Option<i64> = MemoryUsage;
+    module_load(path, options) -> () = ModuleLoad;
+    module_loadex(path, options) -> () = ModuleLoadEx;
+    module_unload(name) -> () = ModuleUnload;
+    replicaof(host, port) -> () = ReplicaOf;
+    restore_asking(key, ttl, serialized_value, options) -> () = RestoreAsking;
+    save() -> () = Save;
+    shutdown(options) -> () = ShutDown;
+    slaveof(host, port) -> () = SlaveOf;
+    slowlog_len() -> i64 = SlowLogLen;
+    slowlog_reset() -> () = SlowLogReset;
+    swapdb(index1, index2) -> () = SwapDb;
+    time() -> (i64, i64) = Time;
+
+    // Set commands
+    sadd(key, members) -> i64 = SAdd;
+    scard(key) -> i64 = SCard;
+    sdiff(keys) -> HashSet<Vec<u8>> = SDiff;
+    sdiffstore(destination, keys) -> i64 = SDiffStore;
+    sinter(keys) -> HashSet<Vec<u8>> = SInter;
+    sintercard(keys: Counted, options) -> i64 = SInterCard;
+    sinterstore(destination, keys) -> i64 = SInterStore;
+    sismember(key, member) -> bool = SIsMember;
+    smembers(key) -> HashSet<Vec<u8>> = SMembers;
+    smismember(key, members) -> Vec<bool> = SMIsMember;
+    smove(source, destination, member) -> bool = SMove;
+    spop(key) -> Option<Vec<u8>> = SPop;
+    spop_count(key, count) -> HashSet<Vec<u8>> = SPop;
+    srandmember(key) -> Option<Vec<u8>> = SRandMember;
+    srandmember_count(key, count) -> Vec<Vec<u8>> = SRandMember;
+    srem(key, members) -> i64 = SRem;
+    sscan(key, cursor, options) -> ScanResult = SScan;
+    sunion(keys) -> HashSet<Vec<u8>> = SUnion;
+    sunionstore(destination, keys) -> i64 = SUnionStore;
+
+    // Sorted set commands
+    bzmpop(timeout, keys: Counted, direction, options) -> Option<(Vec<u8>, HashMap<Vec<u8>, f64>)> = BZMPop;
+    bzpopmax(keys, timeout) -> Option<(Vec<u8>, Vec<u8>, f64)> = BZPopMax;
+    bzpopmin(keys, timeout) -> Option<(Vec<u8>, Vec<u8>, f64)> = BZPopMin;
+    zadd(key, options, score_members) -> i64 = ZAdd;
+    zcard(key) -> i64 = ZCard;
+    zcount(key, min, max) -> i64 = ZCount;
+    zdiff(keys: Counted) -> Vec<Vec<u8>> = ZDiff;
+    zdiffstore(destination, keys: Counted) -> i64 = ZDiffStore;
+    zincrby(key, increment, member) -> f64 = ZIncrBy;
+    zinter(keys: Counted, options) -> Vec<Vec<u8>> = ZInter;
+    zintercard(keys: Counted, options) -> i64 = ZInterCard;
+    zinterstore(destination, keys: Counted, options) -> i64 = ZInterStore;
+    zlexcount(key, min, max) -> i64 = ZLexCount;
+    zmpop(keys: Counted, direction, options) -> Option<(Vec<u8>, HashMap<Vec<u8>, f64>)> = ZMPop;
+    zmscore(key, members) -> Vec<Option<f64>> = ZMScore;
+    zpopmax(key, options) -> HashMap<Vec<u8>, f64> = ZPopMax;
+    zpopmin(key, options) -> HashMap<Vec<u8>, f64> = ZPopMin;
+    zrandmember(key) -> Option<Vec<u8>> = ZRandMember;
+    zrandmember_count(key, count) -> Vec<Vec<u8>> = ZRandMember;
+    zrange(key, start, stop, options) -> Vec<Vec<u8>> = ZRange;
+    zrangebylex(key, min, max, options) -> Vec<Vec<u8>> = ZRangeByLex;
+    zrangebyscore(key, min, max, options) -> Vec<Vec<u8>> = ZRangeByScore;
+    zrangestore(destination, source, start, stop, options) -> i64 = ZRangeStore;
+    zrank(key, member) -> Option<i64> = ZRank;
+    zrem(key, members) -> i64 = ZRem;
+    zremrangebylex(key, min, max) -> i64 = ZRemRangeByLex;
+    zremrangebyrank(key, start, stop) -> i64 = ZRemRangeByRank;
+    zremrangebyscore(key, min, max) -> i64 = ZRemRangeByScore;
+    zrevrange(key, start, stop, options) -> Vec<Vec<u8>> = ZRevRange;
+    zrevrangebylex(key, max, min, options) -> Vec<Vec<u8>> = ZRevRangeByLex;
+    zrevrangebyscore(key, max, min, options) -> Vec<Vec<u8>> = ZRevRangeByScore;
+    zrevrank(key, member) -> Option<i64> = ZRevRank;
+    zscan(key, cursor, options) -> ScanResult = ZScan;
+    zscore(key, member) -> Option<f64> = ZScore;
+    zunion(keys: Counted, options) -> Vec<Vec<u8>> = ZUnion;
+    zunionstore(destination, keys: Counted, options) -> i64 = ZUnionStore;
+
+    // Stream commands
+    xack(key, group, ids) -> i64 = XAck;
+    xadd(key, options, id, field_values) -> Option<String> = XAdd;
+    xclaim(key, group, consumer, min_idle_time, ids, options) -> StreamEntries = XClaim;
+    xdel(key, ids) -> i64 = XDel;
+    xgroup_create(key, group, id, options) -> () = XGroupCreate;
+    xgroup_createconsumer(key, group, consumer) -> bool = XGroupCreateConsumer;
+    xgroup_delconsumer(key, group, consumer) -> i64 = XGroupDelConsumer;
+    xgroup_destroy(key, group) -> bool = XGroupDestroy;
+    xgroup_setid(key, group, id, options) -> () = XGroupSetId;
+    xinfo_consumers(key, group) -> Vec<HashMap<String, Value>> = XInfoConsumers;
+    xinfo_groups(key) -> Vec<HashMap<String, Value>> = XInfoGroups;
+    xinfo_stream(key, options) -> HashMap<String, Value> = XInfoStream;
+    xlen(key) -> i64 = XLen;
+    xrange(key, start, end, options) -> StreamEntries = XRange;
+    xrevrange(key, end, start, options) -> StreamEntries = XRevRange;
+    xsetid(key, last_id, options) -> () = XSetId;
+    xtrim(key, options) -> i64 = XTrim;
+
+    // String commands
+    append(key, value) -> i64 = Append;
+    decr(key) -> i64 = Decr;
+    decrby(key, decrement) -> i64 = DecrBy;
+    get(key) -> Option<Vec<u8>> = Get;
+    getdel(key) -> Option<Vec<u8>> = GetDel;
+    getex(key, options) -> Option<Vec<u8>> = GetEx;
+    getrange(key, start, end) -> Vec<u8> = GetRange;
+    getset(key, value) -> Option<Vec<u8>> = GetSet;
+    incr(key) -> i64 = Incr;
+    incrby(key, increment) -> i64 = IncrBy;
+    incrbyfloat(key, increment) -> f64 = IncrByFloat;
+    lcs(key1, key2) -> Vec<u8> = LCS;
+    mget(keys) -> Vec<Option<Vec<u8>>> = MGet;
+    mset(key_values) -> () = MSet;
+    msetnx(key_values) -> bool = MSetNX;
+    psetex(key, milliseconds, value) -> () = PSetEx;
+    set(key, value, options) -> bool = Set;
+    setex(key, seconds, value) -> () = SetEx;
+    setnx(key, value) -> bool = SetNX;
+    setrange(key, offset, value) -> i64 = SetRange;
+    strlen(key) -> i64 = Strlen;
+    substr(key, start, end) -> Vec<u8> = Substr;
+
+    // Transaction commands
+    unwatch() -> () = UnWatch;
+    watch(keys) -> () = Watch;
+
+    // JSON commands
+    json_clear(key, options) -> i64 = JsonClear;
+    json_del(key, options) -> i64 = JsonDel;
+    json_forget(key, options) -> i64 = JsonForget;
+    json_get(key, options) -> Option<String> = JsonGet;
+    json_mget(keys, path) -> Vec<Option<String>> = JsonMGet;
+    json_numincrby(key, path, increment) -> String = JsonNumIncrBy;
+    json_nummultby(key, path, multiplier) -> String = JsonNumMultBy;
+    json_set(key, path, value, options) -> bool = JsonSet;
+
+    // Vector Search commands
+    ft_list() -> Vec<Vec<u8>> = FtList;
+    ft_aliasadd(alias, index) -> () = FtAliasAdd;
+    ft_aliasdel(alias) -> () = FtAliasDel;
+    ft_aliaslist() -> HashMap<Vec<u8>, Vec<u8>> = FtAliasList;
+    ft_aliasupdate(alias, index) -> () = FtAliasUpdate;
+    ft_create(index, schema) -> () = FtCreate;
+    ft_dropindex(index) -> () = FtDropIndex;
+    ft_explain(index, query) -> String = FtExplain;
+    ft_explaincli(index, query) -> Vec<String> = FtExplainCli;
+    ft_info(index) -> HashMap<String, Value> = FtInfo;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typed_command_rejects_request_types_without_typed_methods() {
+        for request_type in [
+            RequestType::Subscribe,
+            RequestType::Multi,
+            RequestType::Monitor,
+        ] {
+            let err = typed_command(request_type).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ClientError);
+        }
+        assert!(typed_command(RequestType::Get).is_ok());
+    }
+}
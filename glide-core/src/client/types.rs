@@ -2,8 +2,11 @@
 
 #[allow(unused_imports)]
 use logger_core::log_warn;
+use redis::cluster::AddressMapper;
+use std::collections::HashMap;
 #[allow(unused_imports)]
 use std::collections::HashSet;
+use std::sync::Arc;
 use std::time::Duration;
 
 #[cfg(feature = "proto")]
@@ -30,6 +33,91 @@ pub struct ConnectionRequest {
     pub lazy_connect: bool,
     pub refresh_topology_from_initial_nodes: bool,
     pub root_certs: Vec<Vec<u8>>,
+    pub sentinel_configuration: Option<SentinelConfiguration>,
+    pub client_cert: Vec<u8>,
+    pub client_key: Vec<u8>,
+    pub client_side_cache: Option<ClientSideCacheConfig>,
+    pub circuit_breaker: Option<redis::cluster_async::CircuitBreakerConfig>,
+    pub propagate_trace_context: bool,
+    pub key_sampler: Option<KeySamplerConfig>,
+    pub address_mapping: Option<AddressMapping>,
+    /// Discovers the cluster topology with `CLUSTER SHARDS`. Enabled if `None`
+    pub cluster_shards_discovery: Option<bool>,
+}
+
+/// Configuration of the in-process cache of read commands' responses.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ClientSideCacheConfig {
+    /// The maximum number of cached responses
+    pub max_entries: usize,
+
+    /// The `CLIENT TRACKING` mode used to receive invalidations
+    pub tracking_mode: redis::ClientTrackingMode,
+}
+
+/// Configuration of the sampler of requests' keys and responses, used to detect hot keys and big values.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct KeySamplerConfig {
+    /// The percentage of requests that are sampled
+    pub sample_percentage: u32,
+
+    /// The number of most frequent keys tracked per slot, and of biggest values tracked per command
+    pub top_k: usize,
+
+    /// The approximate response size, in bytes, from which a response is considered a big value
+    pub big_value_threshold: usize,
+}
+
+impl Default for KeySamplerConfig {
+    fn default() -> Self {
+        Self {
+            sample_percentage: 1,
+            top_k: 10,
+            big_value_threshold: 1024 * 1024,
+        }
+    }
+}
+
+/// Mapping of the addresses that cluster nodes announce to the addresses that the client connects to.
+///
+/// Addresses are first looked up in the static mapping, and the others are passed to the mapper, if any.
+#[derive(Clone, Default)]
+pub struct AddressMapping {
+    /// Maps each announced `(host, port)` to the `(host, port)` to connect to
+    pub static_mapping: HashMap<(String, u16), (String, u16)>,
+
+    /// Maps the announced addresses that aren't in the static mapping
+    pub mapper: Option<Arc<dyn AddressMapper>>,
+}
+
+impl AddressMapper for AddressMapping {
+    fn map_address(&self, host: &str, port: u16) -> Option<(String, u16)> {
+        self.static_mapping
+            .map_address(host, port)
+            .or_else(|| self.mapper.as_ref()?.map_address(host, port))
+    }
+}
+
+impl ::std::fmt::Debug for AddressMapping {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        f.debug_struct("AddressMapping")
+            .field("static_mapping", &self.static_mapping)
+            .field("mapper", &self.mapper.is_some())
+            .finish()
+    }
+}
+
+/// Sentinel configuration for discovering the nodes of a standalone deployment.
+///
+/// When set, the connection request's addresses are the sentinels' addresses, and the primary
+/// and replicas are discovered through them.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct SentinelConfiguration {
+    /// The name of the primary, as monitored by the sentinels
+    pub service_name: String,
+
+    /// Authentication information for the sentinels, which may differ from the data nodes'
+    pub authentication_info: Option<AuthenticationInfo>,
 }
 
 /// Authentication information for connecting to Redis/Valkey servers
@@ -94,6 +182,7 @@ pub enum ReadFrom {
     PreferReplica,
     AZAffinity(String),
     AZAffinityReplicasAndPrimary(String),
+    LowestLatency,
 }
 
 #[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
@@ -129,12 +218,53 @@ pub(crate) fn none_if_zero(value: u32) -> Option<u32> {
 }
 
 #[cfg(feature = "proto")]
-impl From<protobuf::ConnectionRequest> for ConnectionRequest {
-    fn from(value: protobuf::ConnectionRequest) -> Self {
+fn to_authentication_info(authentication_info: protobuf::AuthenticationInfo) -> AuthenticationInfo {
+    let password = chars_to_string_option(&authentication_info.password);
+    let username = chars_to_string_option(&authentication_info.username);
+    let iam_config = authentication_info.iam_credentials.0.map(|iam_creds| {
+        let cluster_name = chars_to_string_option(&iam_creds.cluster_name).unwrap_or_default();
+        let region = chars_to_string_option(&iam_creds.region).unwrap_or_default();
+        let service_type = match iam_creds.service_type.enum_value() {
+            Ok(protobuf::ServiceType::MEMORYDB) => ServiceType::MemoryDB,
+            _ => ServiceType::ElastiCache,
+        };
+        let refresh_interval_seconds = iam_creds.refresh_interval_seconds;
+
+        IamAuthenticationConfig {
+            cluster_name,
+            region,
+            service_type,
+            refresh_interval_seconds,
+        }
+    });
+
+    AuthenticationInfo {
+        password,
+        username,
+        iam_config,
+    }
+}
+
+#[cfg(feature = "proto")]
+fn to_address_mapping_port(port: u32) -> redis::RedisResult<u16> {
+    u16::try_from(port).map_err(|_| {
+        redis::RedisError::from((
+            redis::ErrorKind::InvalidClientConfig,
+            "Address mapping port is out of range",
+            format!("port {port} is larger than {}", u16::MAX),
+        ))
+    })
+}
+
+#[cfg(feature = "proto")]
+impl TryFrom<protobuf::ConnectionRequest> for ConnectionRequest {
+    type Error = redis::RedisError;
+
+    fn try_from(value: protobuf::ConnectionRequest) -> Result<Self, Self::Error> {
         let read_from = value.read_from.enum_value().ok().map(|val| match val {
             protobuf::ReadFrom::Primary => ReadFrom::Primary,
             protobuf::ReadFrom::PreferReplica => ReadFrom::PreferReplica,
-            protobuf::ReadFrom::LowestLatency => todo!(),
+            protobuf::ReadFrom::LowestLatency => ReadFrom::LowestLatency,
             protobuf::ReadFrom::AZAffinity => {
                 if let Some(client_az) = chars_to_string_option(&value.client_az) {
                     ReadFrom::AZAffinity(client_az)
@@ -167,34 +297,10 @@ impl From<protobuf::ConnectionRequest> for ConnectionRequest {
 
         let client_name = chars_to_string_option(&value.client_name);
         let lib_name = chars_to_string_option(&value.lib_name);
-        let authentication_info = value.authentication_info.0.map(|authentication_info| {
-            let password = chars_to_string_option(&authentication_info.password);
-            let username = chars_to_string_option(&authentication_info.username);
-            let iam_config = authentication_info.iam_credentials.0.map(|iam_creds| {
-                let cluster_name =
-                    chars_to_string_option(&iam_creds.cluster_name).unwrap_or_default();
-                let region = chars_to_string_option(&iam_creds.region).unwrap_or_default();
-                let service_type = match iam_creds.service_type.enum_value() {
-                    Ok(protobuf::ServiceType::MEMORYDB) => ServiceType::MemoryDB,
-                    _ => ServiceType::ElastiCache,
-                };
-                let refresh_interval_seconds = iam_creds.refresh_interval_seconds;
-
-                IamAuthenticationConfig {
-                    cluster_name,
-                    region,
-                    service_type,
-                    refresh_interval_seconds,
-                }
-            });
-
-            AuthenticationInfo {
-                password,
-                username,
-                iam_config,
-            }
-        });
-
+        let authentication_info = value
+            .authentication_info
+            .0
+            .map(|authentication_info| to_authentication_info(*authentication_info));
         let database_id = value.database_id as i64;
         let protocol = value.protocol.enum_value().ok().map(|val| match val {
             protobuf::ProtocolVersion::RESP3 => redis::ProtocolVersion::RESP3,
@@ -282,8 +388,83 @@ impl From<protobuf::ConnectionRequest> for ConnectionRequest {
             .into_iter()
             .map(|cert| cert.to_vec())
             .collect();
+        let sentinel_configuration = value
+            .sentinel_configuration
+            .0
+            .map(|sentinel_configuration| SentinelConfiguration {
+                service_name: sentinel_configuration.service_name.to_string(),
+                authentication_info: sentinel_configuration
+                    .authentication_info
+                    .0
+                    .map(|authentication_info| to_authentication_info(*authentication_info)),
+            });
+
+        let client_cert = value.client_cert.to_vec();
+        let client_key = value.client_key.to_vec();
+        let client_side_cache =
+            value
+                .client_side_cache
+                .0
+                .map(|client_side_cache| ClientSideCacheConfig {
+                    max_entries: client_side_cache.max_entries as usize,
+                    tracking_mode: if client_side_cache.broadcast {
+                        redis::ClientTrackingMode::Broadcast
+                    } else {
+                        redis::ClientTrackingMode::Default
+                    },
+                });
+        let circuit_breaker = value.circuit_breaker.0.map(|circuit_breaker| {
+            let default = redis::cluster_async::CircuitBreakerConfig::default();
+            redis::cluster_async::CircuitBreakerConfig {
+                failure_threshold: none_if_zero(circuit_breaker.failure_threshold)
+                    .unwrap_or(default.failure_threshold),
+                failure_window: none_if_zero(circuit_breaker.failure_window_ms)
+                    .map(|ms| Duration::from_millis(ms as u64))
+                    .unwrap_or(default.failure_window),
+                open_duration: none_if_zero(circuit_breaker.open_duration_ms)
+                    .map(|ms| Duration::from_millis(ms as u64))
+                    .unwrap_or(default.open_duration),
+            }
+        });
 
-        ConnectionRequest {
+        let propagate_trace_context = value.propagate_trace_context;
+        let cluster_shards_discovery = value.cluster_shards_discovery;
+        let key_sampler = value.key_sampler.0.map(|key_sampler| {
+            let default = KeySamplerConfig::default();
+            KeySamplerConfig {
+                sample_percentage: none_if_zero(key_sampler.sample_percentage)
+                    .unwrap_or(default.sample_percentage),
+                top_k: none_if_zero(key_sampler.top_k)
+                    .map(|top_k| top_k as usize)
+                    .unwrap_or(default.top_k),
+                big_value_threshold: none_if_zero(key_sampler.big_value_threshold)
+                    .map(|threshold| threshold as usize)
+                    .unwrap_or(default.big_value_threshold),
+            }
+        });
+
+        let mut static_mapping = HashMap::new();
+        for mapping in value.address_mappings {
+            let (Some(announced), Some(mapped)) = (mapping.announced.0, mapping.mapped.0) else {
+                continue;
+            };
+            static_mapping.insert(
+                (
+                    announced.host.to_string(),
+                    to_address_mapping_port(announced.port)?,
+                ),
+                (
+                    mapped.host.to_string(),
+                    to_address_mapping_port(mapped.port)?,
+                ),
+            );
+        }
+        let address_mapping = (!static_mapping.is_empty()).then(|| AddressMapping {
+            static_mapping,
+            mapper: None,
+        });
+
+        Ok(ConnectionRequest {
             read_from,
             client_name,
             lib_name,
@@ -302,6 +483,15 @@ impl From<protobuf::ConnectionRequest> for ConnectionRequest {
             lazy_connect,
             refresh_topology_from_initial_nodes,
             root_certs,
-        }
+            sentinel_configuration,
+            client_cert,
+            client_key,
+            client_side_cache,
+            circuit_breaker,
+            propagate_trace_context,
+            key_sampler,
+            address_mapping,
+            cluster_shards_discovery,
+        })
     }
 }
@@ -2,8 +2,11 @@
 
 #[cfg(feature = "proto")]
 include!("generated/mod.rs");
+pub mod blocking;
 pub mod client;
 #[cfg(feature = "socket-layer")]
+pub mod resp_encoding;
+#[cfg(feature = "socket-layer")]
 pub mod rotating_buffer;
 #[cfg(feature = "socket-layer")]
 mod socket_listener;
@@ -761,7 +761,8 @@ impl From<::protobuf::EnumOrUnknown<ProtobufRequestType>> for RequestType {
 }
 
 impl RequestType {
-    /// Returns a `Cmd` set with the command name matching the request.
+    /// Returns a `Cmd` set with the command name matching the request,
+    /// or `None` if the request type can't be sent as a command.
     pub fn get_command(&self) -> Option<Cmd> {
         match self {
             RequestType::InvalidRequest => None,
@@ -1086,7 +1087,92 @@ impl RequestType {
             RequestType::ClusterSlots => Some(get_two_word_command("CLUSTER", "SLOTS")),
             RequestType::ReadOnly => Some(cmd("READONLY")),
             RequestType::ReadWrite => Some(cmd("READWRITE")),
-            _ => todo!(),
+            RequestType::Keys => Some(cmd("KEYS")),
+            RequestType::Migrate => Some(cmd("MIGRATE")),
+            RequestType::WaitAof => Some(cmd("WAITAOF")),
+            RequestType::GeoRadius => Some(cmd("GEORADIUS")),
+            RequestType::GeoRadiusReadOnly => Some(cmd("GEORADIUS_RO")),
+            RequestType::GeoRadiusByMember => Some(cmd("GEORADIUSBYMEMBER")),
+            RequestType::GeoRadiusByMemberReadOnly => Some(cmd("GEORADIUSBYMEMBER_RO")),
+            RequestType::BRPopLPush => Some(cmd("BRPOPLPUSH")),
+            RequestType::RPopLPush => Some(cmd("RPOPLPUSH")),
+            RequestType::Eval => Some(cmd("EVAL")),
+            RequestType::EvalReadOnly => Some(cmd("EVAL_RO")),
+            RequestType::EvalSha => Some(cmd("EVALSHA")),
+            RequestType::EvalShaReadOnly => Some(cmd("EVALSHA_RO")),
+            RequestType::ScriptDebug => Some(get_two_word_command("SCRIPT", "DEBUG")),
+            RequestType::ScriptLoad => Some(get_two_word_command("SCRIPT", "LOAD")),
+            RequestType::AclCat => Some(get_two_word_command("ACL", "CAT")),
+            RequestType::AclDelUser => Some(get_two_word_command("ACL", "DELUSER")),
+            RequestType::AclDryRun => Some(get_two_word_command("ACL", "DRYRUN")),
+            RequestType::AclGenPass => Some(get_two_word_command("ACL", "GENPASS")),
+            RequestType::AclGetUser => Some(get_two_word_command("ACL", "GETUSER")),
+            RequestType::AclList => Some(get_two_word_command("ACL", "LIST")),
+            RequestType::AclLoad => Some(get_two_word_command("ACL", "LOAD")),
+            RequestType::AclLog => Some(get_two_word_command("ACL", "LOG")),
+            RequestType::AclSave => Some(get_two_word_command("ACL", "SAVE")),
+            RequestType::AclSetSser => Some(get_two_word_command("ACL", "SETUSER")),
+            RequestType::AclUsers => Some(get_two_word_command("ACL", "USERS")),
+            RequestType::AclWhoami => Some(get_two_word_command("ACL", "WHOAMI")),
+            RequestType::BgRewriteAof => Some(cmd("BGREWRITEAOF")),
+            RequestType::BgSave => Some(cmd("BGSAVE")),
+            RequestType::Command_ => Some(cmd("COMMAND")),
+            RequestType::CommandCount => Some(get_two_word_command("COMMAND", "COUNT")),
+            RequestType::CommandDocs => Some(get_two_word_command("COMMAND", "DOCS")),
+            RequestType::CommandGetKeys => Some(get_two_word_command("COMMAND", "GETKEYS")),
+            RequestType::CommandGetKeysAndFlags => {
+                Some(get_two_word_command("COMMAND", "GETKEYSANDFLAGS"))
+            }
+            RequestType::CommandInfo => Some(get_two_word_command("COMMAND", "INFO")),
+            RequestType::CommandList => Some(get_two_word_command("COMMAND", "LIST")),
+            RequestType::FailOver => Some(cmd("FAILOVER")),
+            RequestType::LatencyDoctor => Some(get_two_word_command("LATENCY", "DOCTOR")),
+            RequestType::LatencyGraph => Some(get_two_word_command("LATENCY", "GRAPH")),
+            RequestType::LatencyHistogram => Some(get_two_word_command("LATENCY", "HISTOGRAM")),
+            RequestType::LatencyHistory => Some(get_two_word_command("LATENCY", "HISTORY")),
+            RequestType::LatencyLatest => Some(get_two_word_command("LATENCY", "LATEST")),
+            RequestType::LatencyReset => Some(get_two_word_command("LATENCY", "RESET")),
+            RequestType::MemoryDoctor => Some(get_two_word_command("MEMORY", "DOCTOR")),
+            RequestType::MemoryMallocStats => Some(get_two_word_command("MEMORY", "MALLOC-STATS")),
+            RequestType::MemoryPurge => Some(get_two_word_command("MEMORY", "PURGE")),
+            RequestType::MemoryStats => Some(get_two_word_command("MEMORY", "STATS")),
+            RequestType::MemoryUsage => Some(get_two_word_command("MEMORY", "USAGE")),
+            RequestType::ReplicaOf => Some(cmd("REPLICAOF")),
+            RequestType::RestoreAsking => Some(cmd("RESTORE-ASKING")),
+            RequestType::Role => Some(cmd("ROLE")),
+            RequestType::Save => Some(cmd("SAVE")),
+            RequestType::ShutDown => Some(cmd("SHUTDOWN")),
+            RequestType::SlaveOf => Some(cmd("SLAVEOF")),
+            RequestType::SlowLogGet => Some(get_two_word_command("SLOWLOG", "GET")),
+            RequestType::SlowLogLen => Some(get_two_word_command("SLOWLOG", "LEN")),
+            RequestType::SlowLogReset => Some(get_two_word_command("SLOWLOG", "RESET")),
+            RequestType::SwapDb => Some(cmd("SWAPDB")),
+            RequestType::ZRangeByLex => Some(cmd("ZRANGEBYLEX")),
+            RequestType::ZRangeByScore => Some(cmd("ZRANGEBYSCORE")),
+            RequestType::ZRevRange => Some(cmd("ZREVRANGE")),
+            RequestType::ZRevRangeByLex => Some(cmd("ZREVRANGEBYLEX")),
+            RequestType::ZRevRangeByScore => Some(cmd("ZREVRANGEBYSCORE")),
+            RequestType::XSetId => Some(cmd("XSETID")),
+            RequestType::GetSet => Some(cmd("GETSET")),
+            RequestType::PSetEx => Some(cmd("PSETEX")),
+            RequestType::SetEx => Some(cmd("SETEX")),
+            RequestType::SetNX => Some(cmd("SETNX")),
+            RequestType::Substr => Some(cmd("SUBSTR")),
+            // Subscriptions, transactions and replication streams change the connection's state,
+            // so they can't be sent as standalone commands.
+            RequestType::Subscribe
+            | RequestType::PSubscribe
+            | RequestType::SSubscribe
+            | RequestType::Unsubscribe
+            | RequestType::PUnsubscribe
+            | RequestType::SUnsubscribe
+            | RequestType::Monitor
+            | RequestType::Sync
+            | RequestType::PSync
+            | RequestType::ReplConf
+            | RequestType::Multi
+            | RequestType::Exec
+            | RequestType::Discard => None,
         }
     }
 }
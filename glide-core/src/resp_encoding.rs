@@ -0,0 +1,120 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+//! Serialization of [`Value`]s to RESP3, for socket clients that can't receive values by pointer.
+
+use redis::Value;
+use std::io::Write;
+
+/// Appends the RESP3 serialization of `value` to `buffer`.
+pub fn encode_value(value: &Value, buffer: &mut Vec<u8>) {
+    match value {
+        Value::Nil => buffer.extend_from_slice(b"_\r\n"),
+        Value::Int(val) => write_line(buffer, b':', val),
+        Value::BulkString(val) => encode_blob(buffer, b'$', val),
+        Value::Array(values) => encode_aggregate(buffer, b'*', values),
+        Value::SimpleString(val) => write_line(buffer, b'+', val),
+        Value::Okay => buffer.extend_from_slice(b"+OK\r\n"),
+        Value::Map(values) => encode_map(buffer, b'%', values),
+        Value::Attribute { data, attributes } => {
+            encode_map(buffer, b'|', attributes);
+            encode_value(data, buffer);
+        }
+        Value::Set(values) => encode_aggregate(buffer, b'~', values),
+        Value::Double(val) if val.is_nan() => buffer.extend_from_slice(b",nan\r\n"),
+        Value::Double(val) => write_line(buffer, b',', val),
+        Value::Boolean(val) => write_line(buffer, b'#', if *val { 't' } else { 'f' }),
+        Value::VerbatimString { format, text } => {
+            encode_blob(buffer, b'=', format!("{format}:{text}").as_bytes())
+        }
+        Value::BigNumber(val) => write_line(buffer, b'(', val),
+        Value::Push { kind, data } => {
+            write_line(buffer, b'>', data.len() + 1);
+            encode_blob(buffer, b'$', kind.to_string().as_bytes());
+            for value in data {
+                encode_value(value, buffer);
+            }
+        }
+        Value::ServerError(err) => match err.details() {
+            Some(details) => write_line(buffer, b'-', format!("{} {details}", err.err_code())),
+            None => write_line(buffer, b'-', err.err_code()),
+        },
+    }
+}
+
+fn write_line(buffer: &mut Vec<u8>, prefix: u8, content: impl std::fmt::Display) {
+    buffer.push(prefix);
+    // Writing to a vector can't fail.
+    let _ = write!(buffer, "{content}\r\n");
+}
+
+fn encode_blob(buffer: &mut Vec<u8>, prefix: u8, blob: &[u8]) {
+    write_line(buffer, prefix, blob.len());
+    buffer.extend_from_slice(blob);
+    buffer.extend_from_slice(b"\r\n");
+}
+
+fn encode_aggregate(buffer: &mut Vec<u8>, prefix: u8, values: &[Value]) {
+    write_line(buffer, prefix, values.len());
+    for value in values {
+        encode_value(value, buffer);
+    }
+}
+
+fn encode_map(buffer: &mut Vec<u8>, prefix: u8, values: &[(Value, Value)]) {
+    write_line(buffer, prefix, values.len());
+    for (key, value) in values {
+        encode_value(key, buffer);
+        encode_value(value, buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use redis::VerbatimFormat;
+
+    fn encode(value: Value) -> String {
+        let mut buffer = Vec::new();
+        encode_value(&value, &mut buffer);
+        String::from_utf8(buffer).unwrap()
+    }
+
+    #[test]
+    fn encodes_simple_values() {
+        assert_eq!(encode(Value::Nil), "_\r\n");
+        assert_eq!(encode(Value::Int(-3)), ":-3\r\n");
+        assert_eq!(encode(Value::Okay), "+OK\r\n");
+        assert_eq!(encode(Value::Boolean(true)), "#t\r\n");
+        assert_eq!(encode(Value::Double(1.5)), ",1.5\r\n");
+        assert_eq!(encode(Value::Double(f64::NEG_INFINITY)), ",-inf\r\n");
+        assert_eq!(
+            encode(Value::BulkString(b"a\r\nb".to_vec())),
+            "$4\r\na\r\nb\r\n"
+        );
+        assert_eq!(
+            encode(Value::VerbatimString {
+                format: VerbatimFormat::Text,
+                text: "hi".to_string(),
+            }),
+            "=6\r\ntxt:hi\r\n"
+        );
+        let server_error = redis::parse_redis_value(b"-ERR wrong\r\n").unwrap();
+        assert!(matches!(server_error, Value::ServerError(_)));
+        assert_eq!(encode(server_error), "-ERR wrong\r\n");
+    }
+
+    #[test]
+    fn encoded_aggregates_are_parsed_back() {
+        let value = Value::Array(vec![
+            Value::Map(vec![(
+                Value::SimpleString("key".to_string()),
+                Value::Set(vec![Value::Int(1), Value::Nil]),
+            )]),
+            Value::Double(0.25),
+            Value::BulkString(b"value".to_vec()),
+        ]);
+        let mut buffer = Vec::new();
+        encode_value(&value, &mut buffer);
+        assert_eq!(redis::parse_redis_value(&buffer).unwrap(), value);
+    }
+}
@@ -3,12 +3,15 @@
 use super::rotating_buffer::RotatingBuffer;
 use crate::client::Client;
 use crate::client::get_or_init_runtime;
+use crate::client::is_blocking_command;
 use crate::cluster_scan_container::get_cluster_scan_cursor;
 use crate::command_request::{
-    Batch, ClusterScan, Command, CommandRequest, Routes, SlotTypes, command, command_request,
+    Batch, ClusterScan, Command, CommandRequest, PubSubChannelType, Routes, SlotTypes, command,
+    command_request,
 };
-use crate::connection_request::ConnectionRequest;
+use crate::connection_request::{ConnectionRequest, ResponseEncoding};
 use crate::errors::{RequestErrorType, error_message, error_type};
+use crate::resp_encoding::encode_value;
 use crate::response;
 use crate::response::Response;
 use ClosingReason::*;
@@ -23,10 +26,11 @@ use redis::cluster_routing::{
 };
 use redis::cluster_routing::{ResponsePolicy, Routable};
 use redis::{
-    ClusterScanArgs, Cmd, PipelineRetryStrategy, PushInfo, RedisError, ScanStateRC, Value,
+    ClusterScanArgs, Cmd, CommandRetryPolicy, PipelineRetryStrategy, PubSubChannelOrPattern,
+    PubSubSubscriptionKind, PushInfo, RedisError, ScanStateRC, Value,
 };
-use std::cell::Cell;
-use std::collections::HashSet;
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::io;
 use std::os::unix::fs::PermissionsExt;
@@ -41,6 +45,7 @@ use tokio::sync::Mutex;
 use tokio::sync::mpsc;
 use tokio::sync::mpsc::{Sender, channel};
 use tokio::task;
+use tokio::task::AbortHandle;
 use tokio_util::task::LocalPoolHandle;
 use uuid::Uuid;
 
@@ -71,6 +76,69 @@ struct Writer {
     lock: Mutex<()>,
     accumulated_outputs: Cell<Vec<u8>>,
     closing_sender: Sender<ClosingReason>,
+    /// How values are returned, as chosen by the connection request.
+    response_encoding: Cell<ResponseEncoding>,
+}
+
+/// A request of the socket that is still being handled, and can be cancelled.
+struct InflightTask {
+    abort_handle: AbortHandle,
+    root_span_ptr: Option<u64>,
+    /// Cleared once the request sends a blocking command. Aborting the task wouldn't unblock the connection,
+    /// so the following requests on it would still wait for the blocking command's timeout.
+    cancellable: Rc<Cell<bool>>,
+}
+
+/// The in-flight requests of a single socket, by their callback index.
+type InflightTasks = Rc<RefCell<HashMap<u32, InflightTask>>>;
+
+/// Holds a reservation of one of the client's inflight request slots, and releases it when dropped.
+/// Since a cancelled request's task is dropped mid-flight, the reservation can't be released at the end of the task.
+struct InflightRequestGuard(Client);
+
+impl InflightRequestGuard {
+    fn reserve(client: &Client) -> Option<Self> {
+        client
+            .reserve_inflight_request()
+            .then(|| Self(client.clone()))
+    }
+}
+
+impl Drop for InflightRequestGuard {
+    fn drop(&mut self) {
+        self.0.release_inflight_request();
+    }
+}
+
+/// Clients of socket connections that use the `Resp` response encoding, by their encoded connection request.
+/// Connections from different processes with identical connection requests share a single client.
+static SHARED_CLIENTS: Lazy<std::sync::Mutex<HashMap<Vec<u8>, SharedClient>>> =
+    Lazy::new(Default::default);
+
+struct SharedClient {
+    client: Client,
+    /// The push senders of the connections sharing the client. Every push notification is sent to all of them.
+    push_senders: Arc<std::sync::Mutex<Vec<mpsc::UnboundedSender<PushInfo>>>>,
+    connections: usize,
+}
+
+/// A socket connection's share of a shared client. The client is dropped once all of its connections close.
+struct SharedClientLease {
+    key: Vec<u8>,
+}
+
+impl Drop for SharedClientLease {
+    fn drop(&mut self) {
+        let mut shared_clients = SHARED_CLIENTS
+            .lock()
+            .expect("Failed to acquire shared clients lock");
+        if let Some(shared_client) = shared_clients.get_mut(&self.key) {
+            shared_client.connections -= 1;
+            if shared_client.connections == 0 {
+                shared_clients.remove(&self.key);
+            }
+        }
+    }
 }
 
 enum PipeListeningResult<TRequest: Message> {
@@ -189,11 +257,13 @@ async fn write_result(
     callback_index: u32,
     writer: &Rc<Writer>,
     command_span_ptr: Option<u64>,
+    retries: Option<u32>,
 ) -> Result<(), io::Error> {
     let mut response = Response::new();
     response.callback_idx = callback_index;
     response.is_push = false;
     response.root_span_ptr = command_span_ptr;
+    response.retries = retries;
     let otel_command_span: Option<GlideSpan> = get_unsafe_span_from_ptr(command_span_ptr);
     response.value = match resp_result {
         Ok(Value::Okay) => Some(response::response::Value::ConstantResponse(
@@ -205,10 +275,7 @@ async fn write_result(
             }
             if value != Value::Nil {
                 // Since null values don't require any additional data, they can be sent without any extra effort.
-                // Move the value to the heap and leak it. The wrapper should use `Box::from_raw` to recreate the box, use the value, and drop the allocation.
-                let reference = Box::leak(Box::new(value));
-                let raw_pointer = from_mut(reference);
-                Some(response::response::Value::RespPointer(raw_pointer as u64))
+                Some(encode_response_value(value, writer))
             } else {
                 None
             }
@@ -222,6 +289,22 @@ async fn write_result(
                 error_message.into(),
             ))
         }
+        Err(ClientUsageError::Cancelled) => {
+            log_debug(
+                "request cancelled",
+                format!("for callback {callback_index}"),
+            );
+            let error_message = ClientUsageError::Cancelled.to_string();
+            if let Some(span) = otel_command_span {
+                span.set_status(GlideSpanStatus::Error((&error_message).into()));
+            }
+            let request_error = response::RequestError {
+                type_: response::RequestErrorType::Cancelled.into(),
+                message: error_message.into(),
+                ..Default::default()
+            };
+            Some(response::response::Value::RequestError(request_error))
+        }
         Err(ClientUsageError::User(error_message)) => {
             log_error("user error", &error_message);
             if let Some(span) = otel_command_span {
@@ -247,6 +330,7 @@ async fn write_result(
                     RequestErrorType::ExecAbort => response::RequestErrorType::ExecAbort,
                     RequestErrorType::Timeout => response::RequestErrorType::Timeout,
                     RequestErrorType::Disconnect => response::RequestErrorType::Disconnect,
+                    RequestErrorType::Cancelled => response::RequestErrorType::Cancelled,
                 }
                 .into(),
                 message: error_message.into(),
@@ -258,6 +342,23 @@ async fn write_result(
     write_to_writer(response, writer).await
 }
 
+/// Returns `value` in the response encoding chosen by the connection request.
+fn encode_response_value(value: Value, writer: &Writer) -> response::response::Value {
+    match writer.response_encoding.get() {
+        ResponseEncoding::Pointer => {
+            // Move the value to the heap and leak it. The wrapper should use `Box::from_raw` to recreate the box, use the value, and drop the allocation.
+            let reference = Box::leak(Box::new(value));
+            let raw_pointer = from_mut(reference);
+            response::response::Value::RespPointer(raw_pointer as u64)
+        }
+        ResponseEncoding::Resp => {
+            let mut buffer = Vec::new();
+            encode_value(&value, &mut buffer);
+            response::response::Value::RespValue(buffer.into())
+        }
+    }
+}
+
 async fn write_to_writer(response: Response, writer: &Rc<Writer>) -> Result<(), io::Error> {
     let mut vec = writer.accumulated_outputs.take();
     let encode_result = response.write_length_delimited_to_vec(&mut vec);
@@ -280,16 +381,20 @@ async fn write_to_writer(response: Response, writer: &Rc<Writer>) -> Result<(),
     }
 }
 
-fn get_command(request: &Command) -> Option<Cmd> {
-    let request_type: crate::request_type::RequestType = request.request_type.into();
-    request_type.get_command()
-}
-
 fn get_redis_command(command: &Command) -> Result<Cmd, ClientUsageError> {
-    let Some(mut cmd) = get_command(command) else {
-        return Err(ClientUsageError::Internal(format!(
-            "Received invalid request type: {:?}",
-            command.request_type
+    let request_type: crate::request_type::RequestType = command.request_type.into();
+    let Some(mut cmd) = request_type.get_command() else {
+        if matches!(
+            request_type,
+            crate::request_type::RequestType::InvalidRequest
+        ) {
+            return Err(ClientUsageError::Internal(format!(
+                "Received invalid request type: {:?}",
+                command.request_type
+            )));
+        }
+        return Err(ClientUsageError::User(format!(
+            "Request type {request_type:?} can't be sent as a command"
         )));
     };
 
@@ -321,21 +426,39 @@ fn get_redis_command(command: &Command) -> Result<Cmd, ClientUsageError> {
     Ok(cmd)
 }
 
+fn get_retry_policy(command: &Command) -> Option<CommandRetryPolicy> {
+    command.retry_policy.as_ref().map(|retry_policy| {
+        let default_policy = CommandRetryPolicy::default();
+        CommandRetryPolicy {
+            max_retries: retry_policy.max_retries,
+            retry_redirects: retry_policy
+                .retry_redirects
+                .unwrap_or(default_policy.retry_redirects),
+            retry_server_errors: retry_policy
+                .retry_server_errors
+                .unwrap_or(default_policy.retry_server_errors),
+            retry_connection_errors: retry_policy
+                .retry_connection_errors
+                .unwrap_or(default_policy.retry_connection_errors),
+        }
+    })
+}
+
 async fn send_command(
     cmd: Cmd,
     mut client: Client,
     routing: Option<RoutingInfo>,
-) -> ClientUsageResult<Value> {
+    retry_policy: Option<CommandRetryPolicy>,
+) -> (ClientUsageResult<Value>, u32) {
     let child_span = create_child_span(cmd.span().as_ref(), "send_command");
-    let res = client
-        .send_command(&cmd, routing)
-        .await
-        .map_err(|err| err.into());
+    let (res, retries) = client
+        .send_command_with_retry_policy(&cmd, routing, retry_policy)
+        .await;
 
     if let Some(c) = child_span {
         c.end()
     };
-    res
+    (res.map_err(|err| err.into()), retries)
 }
 
 // Parse the cluster scan command parameters from protobuf and send the command to redis-rs.
@@ -417,6 +540,7 @@ async fn send_batch(
     client: &mut Client,
     routing: Option<RoutingInfo>,
     command_span: Option<GlideSpan>,
+    cancellable: &Cell<bool>,
 ) -> ClientUsageResult<Value> {
     let mut pipeline = redis::Pipeline::with_capacity(request.commands.capacity());
     pipeline.set_pipeline_span(command_span);
@@ -428,6 +552,10 @@ async fn send_batch(
     for command in request.commands {
         pipeline.add_command(get_redis_command(&command)?);
     }
+    // Commands in a transaction are queued by the server and never block.
+    if !request.is_atomic && pipeline.cmd_iter().any(|cmd| is_blocking_command(cmd)) {
+        cancellable.set(false);
+    }
 
     let res = match request.is_atomic {
         true => client
@@ -470,6 +598,26 @@ fn get_slot_addr(slot_type: &protobuf::EnumOrUnknown<SlotTypes>) -> ClientUsageR
         .map_err(|id| ClientUsageError::Internal(format!("Received unexpected slot id type {id}")))
 }
 
+fn get_channels_or_patterns(channels_or_patterns: Vec<Bytes>) -> Vec<PubSubChannelOrPattern> {
+    channels_or_patterns
+        .into_iter()
+        .map(|channel_or_pattern| channel_or_pattern.to_vec())
+        .collect()
+}
+
+fn get_subscription_kind(
+    channel_type: &protobuf::EnumOrUnknown<PubSubChannelType>,
+) -> ClientUsageResult<PubSubSubscriptionKind> {
+    channel_type
+        .enum_value()
+        .map(|channel_type| match channel_type {
+            PubSubChannelType::Exact => PubSubSubscriptionKind::Exact,
+            PubSubChannelType::Pattern => PubSubSubscriptionKind::Pattern,
+            PubSubChannelType::Sharded => PubSubSubscriptionKind::Sharded,
+        })
+        .map_err(|id| ClientUsageError::Internal(format!("Received unexpected channel type {id}")))
+}
+
 fn get_route(
     route: Option<Box<Routes>>,
     cmd: Option<&Cmd>,
@@ -531,19 +679,98 @@ fn get_route(
     }
 }
 
-fn handle_request(request: CommandRequest, mut client: Client, writer: Rc<Writer>) {
+/// Aborts the in-flight request with `target_callback_idx`, answering it with a `Cancelled` error.
+/// Requests that already sent a blocking command aren't cancelled, and are answered once the command completes.
+fn cancel_request(
+    request: &CommandRequest,
+    target_callback_idx: u32,
+    writer: Rc<Writer>,
+    inflight_tasks: &InflightTasks,
+) {
+    let cancelled_task = {
+        let mut tasks = inflight_tasks.borrow_mut();
+        match tasks.get(&target_callback_idx) {
+            Some(task) if task.cancellable.get() => tasks.remove(&target_callback_idx),
+            _ => None,
+        }
+    };
+    if let Some(task) = &cancelled_task {
+        task.abort_handle.abort();
+    }
+    let callback_idx = request.callback_idx;
+    let root_span_ptr = request.root_span_ptr;
     task::spawn_local(async move {
-        let mut updated_inflight_counter = true;
-        let client_clone = client.clone();
-
-        let result = match client.reserve_inflight_request() {
-            false => {
-                updated_inflight_counter = false;
-                Err(ClientUsageError::User(
-                    "Reached maximum inflight requests".to_string(),
-                ))
+        if let Some(task) = &cancelled_task {
+            let _res = write_result(
+                Err(ClientUsageError::Cancelled),
+                target_callback_idx,
+                &writer,
+                task.root_span_ptr,
+                None,
+            )
+            .await;
+        }
+        let _res = write_result(
+            Ok(Value::Boolean(cancelled_task.is_some())),
+            callback_idx,
+            &writer,
+            root_span_ptr,
+            None,
+        )
+        .await;
+    });
+}
+
+/// Returns true if the request carries pointers into the listener's memory, which are only valid for
+/// clients in the listener's process.
+fn carries_pointers(request: &CommandRequest) -> bool {
+    let has_args_pointer =
+        |command: &Command| matches!(command.args, Some(command::Args::ArgsVecPointer(_)));
+    request.root_span_ptr.is_some()
+        || match &request.command {
+            Some(command_request::Command::SingleCommand(command)) => has_args_pointer(command),
+            Some(command_request::Command::Batch(batch)) => {
+                batch.commands.iter().any(has_args_pointer)
             }
-            true => match request.command {
+            Some(command_request::Command::ScriptInvocationPointers(_)) => true,
+            _ => false,
+        }
+}
+
+fn handle_request(
+    request: CommandRequest,
+    mut client: Client,
+    writer: Rc<Writer>,
+    inflight_tasks: &InflightTasks,
+) {
+    if writer.response_encoding.get() == ResponseEncoding::Resp && carries_pointers(&request) {
+        let callback_idx = request.callback_idx;
+        task::spawn_local(async move {
+            let error = ClientUsageError::User(
+                "Requests with pointers aren't supported with the RESP response encoding"
+                    .to_string(),
+            );
+            let _res = write_result(Err(error), callback_idx, &writer, None, None).await;
+        });
+        return;
+    }
+    if let Some(command_request::Command::CancelRequest(cancel)) = &request.command {
+        cancel_request(&request, cancel.callback_idx, writer, inflight_tasks);
+        return;
+    }
+    let callback_idx = request.callback_idx;
+    let root_span_ptr = request.root_span_ptr;
+    let tasks = inflight_tasks.clone();
+    let cancellable = Rc::new(Cell::new(true));
+    let task_cancellable = cancellable.clone();
+    let handle = task::spawn_local(async move {
+        let mut retries = None;
+        let inflight_guard = InflightRequestGuard::reserve(&client);
+        let result = match inflight_guard {
+            None => Err(ClientUsageError::User(
+                "Reached maximum inflight requests".to_string(),
+            )),
+            Some(_) => match request.command {
                 Some(action) => match action {
                     command_request::Command::ClusterScan(cluster_scan_command) => {
                         //TODO: handle scan command - https://github.com/valkey-io/valkey-glide/issues/3506
@@ -553,8 +780,19 @@ fn handle_request(request: CommandRequest, mut client: Client, writer: Rc<Writer
                         match get_redis_command(&command) {
                             Ok(mut cmd) => match get_route(request.route.0, Some(&cmd)) {
                                 Ok(routes) => {
+                                    if is_blocking_command(&cmd) {
+                                        task_cancellable.set(false);
+                                    }
                                     cmd.set_span(get_unsafe_span_from_ptr(request.root_span_ptr));
-                                    send_command(cmd, client, routes).await
+                                    let (result, command_retries) = send_command(
+                                        cmd,
+                                        client,
+                                        routes,
+                                        get_retry_policy(&command),
+                                    )
+                                    .await;
+                                    retries = (command_retries > 0).then_some(command_retries);
+                                    result
                                 }
                                 Err(e) => Err(e),
                             },
@@ -566,7 +804,14 @@ fn handle_request(request: CommandRequest, mut client: Client, writer: Rc<Writer
                             Ok(routes) => {
                                 let otel_command_span =
                                     get_unsafe_span_from_ptr(request.root_span_ptr);
-                                send_batch(batch, &mut client, routes, otel_command_span).await
+                                send_batch(
+                                    batch,
+                                    &mut client,
+                                    routes,
+                                    otel_command_span,
+                                    &task_cancellable,
+                                )
+                                .await
                             }
                             Err(e) => Err(e),
                         }
@@ -617,6 +862,36 @@ fn handle_request(request: CommandRequest, mut client: Client, writer: Rc<Writer
                         .await
                         .map(|_| Value::SimpleString("OK".into()))
                         .map_err(|err| err.into()),
+
+                    command_request::Command::Subscribe(subscribe) => {
+                        match get_subscription_kind(&subscribe.channel_type) {
+                            Ok(kind) => client
+                                .subscribe(
+                                    kind,
+                                    get_channels_or_patterns(subscribe.channels_or_patterns),
+                                )
+                                .await
+                                .map_err(|err| err.into()),
+                            Err(e) => Err(e),
+                        }
+                    }
+
+                    command_request::Command::Unsubscribe(unsubscribe) => {
+                        match get_subscription_kind(&unsubscribe.channel_type) {
+                            Ok(kind) => client
+                                .unsubscribe(
+                                    kind,
+                                    get_channels_or_patterns(unsubscribe.channels_or_patterns),
+                                )
+                                .await
+                                .map_err(|err| err.into()),
+                            Err(e) => Err(e),
+                        }
+                    }
+
+                    command_request::Command::CancelRequest(_) => {
+                        unreachable!("Cancel requests are handled without spawning a task")
+                    }
                 },
                 None => {
                     log_debug(
@@ -633,21 +908,37 @@ fn handle_request(request: CommandRequest, mut client: Client, writer: Rc<Writer
             },
         };
 
-        if updated_inflight_counter {
-            client_clone.release_inflight_request();
-        }
+        drop(inflight_guard);
+        // Once the request is no longer registered, it can't be aborted while its response is being written.
+        tasks.borrow_mut().remove(&request.callback_idx);
 
-        let _res = write_result(result, request.callback_idx, &writer, request.root_span_ptr).await;
+        let _res = write_result(
+            result,
+            request.callback_idx,
+            &writer,
+            request.root_span_ptr,
+            retries,
+        )
+        .await;
     });
+    inflight_tasks.borrow_mut().insert(
+        callback_idx,
+        InflightTask {
+            abort_handle: handle.abort_handle(),
+            root_span_ptr,
+            cancellable,
+        },
+    );
 }
 
 async fn handle_requests(
     received_requests: Vec<CommandRequest>,
     client: &Client,
     writer: &Rc<Writer>,
+    inflight_tasks: &InflightTasks,
 ) {
     for request in received_requests {
-        handle_request(request, client.clone(), writer.clone());
+        handle_request(request, client.clone(), writer.clone(), inflight_tasks);
     }
     // Yield to ensure that the subtasks aren't starved.
     task::yield_now().await;
@@ -689,21 +980,98 @@ pub fn close_socket(socket_path: &String) {
 async fn create_client(
     writer: &Rc<Writer>,
     request: ConnectionRequest,
-    push_tx: Option<mpsc::UnboundedSender<PushInfo>>,
-) -> Result<Client, ClientCreationError> {
-    let client = match Client::new(request.into(), push_tx).await {
-        Ok(client) => client,
-        Err(err) => return Err(ClientCreationError::ConnectionError(err)),
+    push_tx: mpsc::UnboundedSender<PushInfo>,
+) -> Result<(Client, Option<SharedClientLease>), ClientCreationError> {
+    let response_encoding = request.response_encoding.enum_value_or_default();
+    writer.response_encoding.set(response_encoding);
+    let (client, lease) = match response_encoding {
+        ResponseEncoding::Pointer => {
+            let request = request
+                .try_into()
+                .map_err(|err: RedisError| ClientCreationError::UnhandledError(err.to_string()))?;
+            match Client::new(request, Some(push_tx)).await {
+                Ok(client) => (client, None),
+                Err(err) => return Err(ClientCreationError::ConnectionError(err)),
+            }
+        }
+        ResponseEncoding::Resp => {
+            let (client, lease) = get_or_create_shared_client(request, push_tx).await?;
+            (client, Some(lease))
+        }
+    };
+    write_result(Ok(Value::Okay), 0, writer, None, None).await?;
+    Ok((client, lease))
+}
+
+/// Returns the client shared by the connections with an identical connection request, creating it if needed.
+async fn get_or_create_shared_client(
+    request: ConnectionRequest,
+    push_tx: mpsc::UnboundedSender<PushInfo>,
+) -> Result<(Client, SharedClientLease), ClientCreationError> {
+    let key = request
+        .write_to_bytes()
+        .map_err(|err| ClientCreationError::UnhandledError(err.to_string()))?;
+    let attach = |shared_client: &mut SharedClient| {
+        shared_client.connections += 1;
+        shared_client
+            .push_senders
+            .lock()
+            .expect("Failed to acquire push senders lock")
+            .push(push_tx.clone());
+        shared_client.client.clone()
     };
-    write_result(Ok(Value::Okay), 0, writer, None).await?;
-    Ok(client)
+    if let Some(shared_client) = SHARED_CLIENTS
+        .lock()
+        .expect("Failed to acquire shared clients lock")
+        .get_mut(&key)
+    {
+        return Ok((attach(shared_client), SharedClientLease { key }));
+    }
+
+    let request = request
+        .try_into()
+        .map_err(|err: RedisError| ClientCreationError::UnhandledError(err.to_string()))?;
+    let (shared_push_tx, shared_push_rx) = mpsc::unbounded_channel();
+    let client = Client::new(request, Some(shared_push_tx))
+        .await
+        .map_err(ClientCreationError::ConnectionError)?;
+    let mut shared_clients = SHARED_CLIENTS
+        .lock()
+        .expect("Failed to acquire shared clients lock");
+    // Another connection with the same request might have created a client in the meantime, in which case it's used instead.
+    let shared_client = shared_clients.entry(key.clone()).or_insert_with(|| {
+        let push_senders = Arc::default();
+        task::spawn(forward_shared_pushes(
+            shared_push_rx,
+            Arc::clone(&push_senders),
+        ));
+        SharedClient {
+            client,
+            push_senders,
+            connections: 0,
+        }
+    });
+    Ok((attach(shared_client), SharedClientLease { key }))
+}
+
+/// Sends the push notifications of a shared client to all the connections that share it.
+async fn forward_shared_pushes(
+    mut push_rx: mpsc::UnboundedReceiver<PushInfo>,
+    push_senders: Arc<std::sync::Mutex<Vec<mpsc::UnboundedSender<PushInfo>>>>,
+) {
+    while let Some(push_msg) = push_rx.recv().await {
+        push_senders
+            .lock()
+            .expect("Failed to acquire push senders lock")
+            .retain(|push_tx| push_tx.send(push_msg.clone()).is_ok());
+    }
 }
 
 async fn wait_for_connection_configuration_and_create_client(
     client_listener: &mut UnixStreamListener,
     writer: &Rc<Writer>,
-    push_tx: Option<mpsc::UnboundedSender<PushInfo>>,
-) -> Result<Client, ClientCreationError> {
+    push_tx: mpsc::UnboundedSender<PushInfo>,
+) -> Result<(Client, Option<SharedClientLease>), ClientCreationError> {
     // Wait for the server's address
     match client_listener.next_values::<ConnectionRequest>().await {
         Closed(reason) => Err(ClientCreationError::SocketListenerClosed(reason)),
@@ -724,13 +1092,14 @@ async fn read_values_loop(
     client: &Client,
     writer: Rc<Writer>,
 ) -> ClosingReason {
+    let inflight_tasks = InflightTasks::default();
     loop {
         match client_listener.next_values().await {
             Closed(reason) => {
                 return reason;
             }
             ReceivedValues(received_requests) => {
-                handle_requests(received_requests, client, &writer).await;
+                handle_requests(received_requests, client, &writer, &inflight_tasks).await;
             }
         }
     }
@@ -749,15 +1118,11 @@ async fn push_manager_loop(mut push_rx: mpsc::UnboundedReceiver<PushInfo>, write
                 let mut response = Response::new();
                 response.callback_idx = 0; // callback_idx is not used with push notifications
                 response.is_push = true;
-                response.value = {
-                    let push_val = Value::Push {
-                        kind: (push_msg.kind),
-                        data: (push_msg.data),
-                    };
-                    let reference = Box::leak(Box::new(push_val));
-                    let raw_pointer = from_mut(reference);
-                    Some(response::response::Value::RespPointer(raw_pointer as u64))
+                let push_val = Value::Push {
+                    kind: (push_msg.kind),
+                    data: (push_msg.data),
                 };
+                response.value = Some(encode_response_value(push_val, &writer));
 
                 _ = write_to_writer(response, &writer).await;
             }
@@ -778,13 +1143,12 @@ async fn listen_on_client_stream(socket: UnixStream) {
         lock: write_lock,
         accumulated_outputs,
         closing_sender: sender,
+        response_encoding: Cell::new(ResponseEncoding::Pointer),
     });
-    let client_creation = wait_for_connection_configuration_and_create_client(
-        &mut client_listener,
-        &writer,
-        Some(push_tx),
-    );
-    let client = match client_creation.await {
+    let client_creation =
+        wait_for_connection_configuration_and_create_client(&mut client_listener, &writer, push_tx);
+    // The lease is held until the connection closes.
+    let (client, _shared_client_lease) = match client_creation.await {
         Ok(conn) => conn,
         Err(ClientCreationError::SocketListenerClosed(ClosingReason::ReadSocketClosed)) => {
             // This isn't an error - it can happen when a new wrapper-client creates a connection in order to check whether something already listens on the socket.
@@ -883,6 +1247,9 @@ enum ClientUsageError {
     /// An error that stems from wrong behavior of the user.
     #[error("User error: {0}")]
     User(String),
+    /// The request was cancelled by the user before it completed.
+    #[error("Request was cancelled")]
+    Cancelled,
 }
 
 type ClientUsageResult<T> = Result<T, ClientUsageError>;
@@ -2,6 +2,7 @@ use lazy_static::lazy_static;
 use serde::Serialize;
 use std::sync::RwLock as StdRwLock;
 mod metrics_exporter_file;
+mod metrics_exporter_prometheus;
 mod open_telemetry;
 mod span_exporter_file;
 
@@ -16,6 +17,8 @@ pub struct Telemetry {
     total_connections: usize,
     /// Total number of GLIDE clients
     total_clients: usize,
+    /// Total number of cluster topology refreshes
+    total_topology_refreshes: usize,
 }
 
 lazy_static! {
@@ -58,6 +61,14 @@ impl Telemetry {
         t.total_clients
     }
 
+    /// Increment the total number of topology refreshes by `incr_by`
+    /// Return the number of total topology refreshes after the increment
+    pub fn incr_total_topology_refreshes(incr_by: usize) -> usize {
+        let mut t = TELEMETRY.write().expect(MUTEX_WRITE_ERR);
+        t.total_topology_refreshes = t.total_topology_refreshes.saturating_add(incr_by);
+        t.total_topology_refreshes
+    }
+
     /// Return the number of active connections
     pub fn total_connections() -> usize {
         TELEMETRY.read().expect(MUTEX_READ_ERR).total_connections
@@ -68,6 +79,14 @@ impl Telemetry {
         TELEMETRY.read().expect(MUTEX_READ_ERR).total_clients
     }
 
+    /// Return the number of topology refreshes
+    pub fn total_topology_refreshes() -> usize {
+        TELEMETRY
+            .read()
+            .expect(MUTEX_READ_ERR)
+            .total_topology_refreshes
+    }
+
     /// Reset the telemetry collected thus far
     pub fn reset() {
         *TELEMETRY.write().expect(MUTEX_WRITE_ERR) = Telemetry::default();
@@ -163,24 +163,9 @@ fn to_json(metrics: &ResourceMetrics) -> Result<Value, MetricError> {
                     data_points.push(Value::Object(dp));
                 }
             } else if let Some(gauge) = aggregation.downcast_ref::<Gauge<f64>>() {
-                for point in gauge.data_points.iter() {
-                    let mut dp = Map::new();
-                    dp.insert("value".to_owned(), Value::String(point.value.to_string()));
-                    let time = point
-                        .time
-                        .ok_or_else(|| MetricError::Other("Missing time".to_string()))?;
-                    let time: DateTime<Utc> = time.into();
-                    dp.insert(
-                        "time".to_owned(),
-                        Value::String(time.timestamp_micros().to_string()),
-                    );
-
-                    dp.insert(
-                        "attributes".to_owned(),
-                        attributes_to_json(&point.attributes),
-                    );
-                    data_points.push(Value::Object(dp));
-                }
+                data_points = gauge_to_json(gauge, |value| Value::String(value.to_string()))?;
+            } else if let Some(gauge) = aggregation.downcast_ref::<Gauge<u64>>() {
+                data_points = gauge_to_json(gauge, |value| Value::Number(value.into()))?;
             } else if let Some(histogram) = aggregation.downcast_ref::<Histogram<f64>>() {
                 for point in histogram.data_points.iter() {
                     let mut dp = Map::new();
@@ -237,6 +222,33 @@ fn to_json(metrics: &ResourceMetrics) -> Result<Value, MetricError> {
     Ok(Value::Object(root))
 }
 
+// Helper function to convert the data points of a gauge to JSON
+fn gauge_to_json<T: Copy>(
+    gauge: &Gauge<T>,
+    value_to_json: impl Fn(T) -> Value,
+) -> Result<Vec<Value>, MetricError> {
+    let mut data_points = Vec::new();
+    for point in gauge.data_points.iter() {
+        let mut dp = Map::new();
+        dp.insert("value".to_owned(), value_to_json(point.value));
+        let time = point
+            .time
+            .ok_or_else(|| MetricError::Other("Missing time".to_string()))?;
+        let time: DateTime<Utc> = time.into();
+        dp.insert(
+            "time".to_owned(),
+            Value::String(time.timestamp_micros().to_string()),
+        );
+
+        dp.insert(
+            "attributes".to_owned(),
+            attributes_to_json(&point.attributes),
+        );
+        data_points.push(Value::Object(dp));
+    }
+    Ok(data_points)
+}
+
 // Helper function to convert attributes to JSON
 fn attributes_to_json(attributes: &[opentelemetry::KeyValue]) -> Value {
     let mut json_attributes = Map::new();
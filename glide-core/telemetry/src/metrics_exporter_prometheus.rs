@@ -0,0 +1,399 @@
+use crate::Telemetry;
+use opentelemetry::KeyValue;
+use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::metrics::data::ResourceMetrics;
+use opentelemetry_sdk::metrics::data::{DataPoint, Gauge, Histogram, HistogramDataPoint, Sum};
+use opentelemetry_sdk::metrics::reader::MetricReader;
+use opentelemetry_sdk::metrics::{
+    InstrumentKind, ManualReader, MetricResult, Pipeline, Temporality,
+};
+use std::any::Any;
+use std::fmt::Write;
+use std::sync::{Arc, Weak};
+
+/// A metrics reader that collects the metrics on demand, and renders them in the Prometheus text
+/// exposition format. Unlike the other exporters, it doesn't push the metrics anywhere: the
+/// bindings are expected to serve the rendered text from their own HTTP handlers.
+#[derive(Clone, Debug)]
+pub(crate) struct PrometheusMetricsReader {
+    reader: Arc<ManualReader>,
+}
+
+impl PrometheusMetricsReader {
+    pub(crate) fn new() -> Self {
+        Self {
+            reader: Arc::new(
+                ManualReader::builder()
+                    .with_temporality(Temporality::Cumulative)
+                    .build(),
+            ),
+        }
+    }
+
+    /// Collects the current value of every metric, and renders them in the Prometheus text format.
+    pub(crate) fn render(&self) -> MetricResult<String> {
+        let mut metrics = ResourceMetrics {
+            resource: Resource::empty(),
+            scope_metrics: Vec::new(),
+        };
+        self.reader.collect(&mut metrics)?;
+        Ok(encode_metrics(&metrics))
+    }
+}
+
+impl MetricReader for PrometheusMetricsReader {
+    fn register_pipeline(&self, pipeline: Weak<Pipeline>) {
+        self.reader.register_pipeline(pipeline)
+    }
+
+    fn collect(&self, rm: &mut ResourceMetrics) -> MetricResult<()> {
+        self.reader.collect(rm)
+    }
+
+    fn force_flush(&self) -> MetricResult<()> {
+        self.reader.force_flush()
+    }
+
+    fn shutdown(&self) -> MetricResult<()> {
+        self.reader.shutdown()
+    }
+
+    fn temporality(&self, kind: InstrumentKind) -> Temporality {
+        self.reader.temporality(kind)
+    }
+}
+
+/// Renders the statistics collected by [`Telemetry`], which are tracked even when OpenTelemetry
+/// isn't initialized. If `with_otel_metrics` is set, the open connections are skipped, since the
+/// OpenTelemetry metrics already report them under the same name.
+pub(crate) fn encode_statistics(with_otel_metrics: bool) -> String {
+    let mut out = String::new();
+    let open_connections = (
+        "glide_open_connections",
+        "Number of open connections to the servers",
+        Telemetry::total_connections(),
+    );
+    let total_clients = (
+        "glide_total_clients",
+        "Number of active clients",
+        Telemetry::total_clients(),
+    );
+    let statistics = if with_otel_metrics {
+        vec![total_clients]
+    } else {
+        vec![open_connections, total_clients]
+    };
+    for (name, description, value) in statistics {
+        write_header(&mut out, name, description, "gauge");
+        let _ = writeln!(out, "{name} {value}");
+    }
+    out
+}
+
+/// Renders `metrics` in the Prometheus text exposition format. Monotonic sums are rendered as
+/// counters, and aggregations that have no Prometheus equivalent are skipped.
+pub(crate) fn encode_metrics(metrics: &ResourceMetrics) -> String {
+    let mut out = String::new();
+    for metric in metrics
+        .scope_metrics
+        .iter()
+        .flat_map(|scope| scope.metrics.iter())
+    {
+        let name = metric_name(&metric.name, &metric.unit);
+        let aggregation = metric.data.as_ref() as &dyn Any;
+        if let Some(sum) = aggregation.downcast_ref::<Sum<u64>>() {
+            encode_sum(&mut out, &name, &metric.description, sum);
+        } else if let Some(sum) = aggregation.downcast_ref::<Sum<i64>>() {
+            encode_sum(&mut out, &name, &metric.description, sum);
+        } else if let Some(sum) = aggregation.downcast_ref::<Sum<f64>>() {
+            encode_sum(&mut out, &name, &metric.description, sum);
+        } else if let Some(gauge) = aggregation.downcast_ref::<Gauge<u64>>() {
+            encode_gauge(&mut out, &name, &metric.description, &gauge.data_points);
+        } else if let Some(gauge) = aggregation.downcast_ref::<Gauge<i64>>() {
+            encode_gauge(&mut out, &name, &metric.description, &gauge.data_points);
+        } else if let Some(gauge) = aggregation.downcast_ref::<Gauge<f64>>() {
+            encode_gauge(&mut out, &name, &metric.description, &gauge.data_points);
+        } else if let Some(histogram) = aggregation.downcast_ref::<Histogram<u64>>() {
+            encode_histogram(&mut out, &name, &metric.description, histogram);
+        } else if let Some(histogram) = aggregation.downcast_ref::<Histogram<f64>>() {
+            encode_histogram(&mut out, &name, &metric.description, histogram);
+        }
+    }
+    out
+}
+
+trait PrometheusValue: Copy {
+    fn render(self) -> String;
+}
+
+impl PrometheusValue for u64 {
+    fn render(self) -> String {
+        self.to_string()
+    }
+}
+
+impl PrometheusValue for i64 {
+    fn render(self) -> String {
+        self.to_string()
+    }
+}
+
+impl PrometheusValue for f64 {
+    fn render(self) -> String {
+        if self.is_nan() {
+            "NaN".to_string()
+        } else if self == f64::INFINITY {
+            "+Inf".to_string()
+        } else if self == f64::NEG_INFINITY {
+            "-Inf".to_string()
+        } else {
+            self.to_string()
+        }
+    }
+}
+
+fn encode_sum<T: PrometheusValue>(out: &mut String, name: &str, description: &str, sum: &Sum<T>) {
+    if sum.is_monotonic {
+        let name = if name.ends_with("_total") {
+            name.to_string()
+        } else {
+            format!("{name}_total")
+        };
+        write_header(out, &name, description, "counter");
+        write_data_points(out, &name, &sum.data_points);
+    } else {
+        encode_gauge(out, name, description, &sum.data_points);
+    }
+}
+
+fn encode_gauge<T: PrometheusValue>(
+    out: &mut String,
+    name: &str,
+    description: &str,
+    data_points: &[DataPoint<T>],
+) {
+    write_header(out, name, description, "gauge");
+    write_data_points(out, name, data_points);
+}
+
+fn encode_histogram<T: PrometheusValue>(
+    out: &mut String,
+    name: &str,
+    description: &str,
+    histogram: &Histogram<T>,
+) {
+    write_header(out, name, description, "histogram");
+    for point in histogram.data_points.iter() {
+        write_histogram_data_point(out, name, point);
+    }
+}
+
+fn write_histogram_data_point<T: PrometheusValue>(
+    out: &mut String,
+    name: &str,
+    point: &HistogramDataPoint<T>,
+) {
+    let labels = labels(&point.attributes);
+    // Prometheus buckets are cumulative, while OpenTelemetry buckets count only their own range.
+    let mut cumulative_count = 0;
+    for (bound, count) in point.bounds.iter().zip(point.bucket_counts.iter()) {
+        cumulative_count += count;
+        let le = format!("le=\"{}\"", bound.render());
+        let _ = writeln!(
+            out,
+            "{name}_bucket{} {cumulative_count}",
+            join_labels(&labels, &le)
+        );
+    }
+    let _ = writeln!(
+        out,
+        "{name}_bucket{} {}",
+        join_labels(&labels, "le=\"+Inf\""),
+        point.count
+    );
+    let labels = join_labels(&labels, "");
+    let _ = writeln!(out, "{name}_sum{labels} {}", point.sum.render());
+    let _ = writeln!(out, "{name}_count{labels} {}", point.count);
+}
+
+fn write_header(out: &mut String, name: &str, description: &str, metric_type: &str) {
+    if !description.is_empty() {
+        let description = description.replace('\\', "\\\\").replace('\n', "\\n");
+        let _ = writeln!(out, "# HELP {name} {description}");
+    }
+    let _ = writeln!(out, "# TYPE {name} {metric_type}");
+}
+
+fn write_data_points<T: PrometheusValue>(
+    out: &mut String,
+    name: &str,
+    data_points: &[DataPoint<T>],
+) {
+    for point in data_points {
+        let labels = join_labels(&labels(&point.attributes), "");
+        let _ = writeln!(out, "{name}{labels} {}", point.value.render());
+    }
+}
+
+/// Converts the OpenTelemetry metric name to a Prometheus one, e.g. `glide.request_latency` with
+/// the `ms` unit becomes `glide_request_latency_milliseconds`.
+fn metric_name(name: &str, unit: &str) -> String {
+    let mut name = sanitize_name(name);
+    let unit_suffix = match unit {
+        "ms" => Some("milliseconds"),
+        "s" => Some("seconds"),
+        "By" => Some("bytes"),
+        _ => None,
+    };
+    if let Some(suffix) = unit_suffix.filter(|suffix| !name.ends_with(suffix)) {
+        name.push('_');
+        name.push_str(suffix);
+    }
+    name
+}
+
+fn sanitize_name(name: &str) -> String {
+    name.chars()
+        .enumerate()
+        .map(|(i, c)| match c {
+            'a'..='z' | 'A'..='Z' | '_' => c,
+            '0'..='9' if i > 0 => c,
+            _ => '_',
+        })
+        .collect()
+}
+
+fn labels(attributes: &[KeyValue]) -> Vec<String> {
+    attributes
+        .iter()
+        .map(|attribute| {
+            let value = attribute
+                .value
+                .to_string()
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n");
+            format!("{}=\"{value}\"", sanitize_name(attribute.key.as_str()))
+        })
+        .collect()
+}
+
+/// Joins the labels of a data point with an optional extra label, e.g. `le` for histogram buckets.
+fn join_labels(labels: &[String], extra: &str) -> String {
+    let mut all: Vec<&str> = labels.iter().map(String::as_str).collect();
+    if !extra.is_empty() {
+        all.push(extra);
+    }
+    if all.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", all.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::InstrumentationScope;
+    use opentelemetry_sdk::metrics::data::{Aggregation, Metric, ScopeMetrics};
+    use std::borrow::Cow;
+    use std::time::SystemTime;
+
+    fn metric(name: &'static str, unit: &'static str, data: Box<dyn Aggregation>) -> Metric {
+        Metric {
+            name: Cow::Borrowed(name),
+            description: Cow::Borrowed("A metric"),
+            unit: Cow::Borrowed(unit),
+            data,
+        }
+    }
+
+    fn resource_metrics(metrics: Vec<Metric>) -> ResourceMetrics {
+        ResourceMetrics {
+            resource: Resource::empty(),
+            scope_metrics: vec![ScopeMetrics {
+                scope: InstrumentationScope::builder("test").build(),
+                metrics,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_encode_counter() {
+        let sum = Sum {
+            data_points: vec![DataPoint {
+                attributes: vec![KeyValue::new("address", "node\"1\":6379")],
+                start_time: None,
+                time: None,
+                value: 3u64,
+                exemplars: vec![],
+            }],
+            temporality: Temporality::Cumulative,
+            is_monotonic: true,
+        };
+        let text = encode_metrics(&resource_metrics(vec![metric(
+            "glide.timeout_errors",
+            "1",
+            Box::new(sum),
+        )]));
+        assert_eq!(
+            text,
+            "# HELP glide_timeout_errors_total A metric\n\
+             # TYPE glide_timeout_errors_total counter\n\
+             glide_timeout_errors_total{address=\"node\\\"1\\\":6379\"} 3\n"
+        );
+    }
+
+    #[test]
+    fn test_encode_histogram() {
+        let histogram = Histogram {
+            data_points: vec![HistogramDataPoint {
+                attributes: vec![KeyValue::new("command", "GET")],
+                start_time: SystemTime::now(),
+                time: SystemTime::now(),
+                count: 3,
+                bounds: vec![1.0, 5.0],
+                bucket_counts: vec![1, 1, 1],
+                min: Some(0.5),
+                max: Some(7.0),
+                sum: 10.5,
+                exemplars: vec![],
+            }],
+            temporality: Temporality::Cumulative,
+        };
+        let text = encode_metrics(&resource_metrics(vec![metric(
+            "glide.request_latency",
+            "ms",
+            Box::new(histogram),
+        )]));
+        assert_eq!(
+            text,
+            "# HELP glide_request_latency_milliseconds A metric\n\
+             # TYPE glide_request_latency_milliseconds histogram\n\
+             glide_request_latency_milliseconds_bucket{command=\"GET\",le=\"1\"} 1\n\
+             glide_request_latency_milliseconds_bucket{command=\"GET\",le=\"5\"} 2\n\
+             glide_request_latency_milliseconds_bucket{command=\"GET\",le=\"+Inf\"} 3\n\
+             glide_request_latency_milliseconds_sum{command=\"GET\"} 10.5\n\
+             glide_request_latency_milliseconds_count{command=\"GET\"} 3\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_prometheus_endpoint() {
+        assert!(matches!(
+            "prometheus://".parse(),
+            Ok(crate::GlideOpenTelemetrySignalsExporter::Prometheus)
+        ));
+    }
+
+    #[test]
+    fn test_encode_statistics() {
+        let text = encode_statistics(false);
+        assert!(text.contains("# TYPE glide_open_connections gauge\n"));
+        assert!(text.contains("# TYPE glide_total_clients gauge\n"));
+
+        let text = encode_statistics(true);
+        assert!(!text.contains("glide_open_connections"));
+        assert!(text.contains("# TYPE glide_total_clients gauge\n"));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::metrics_exporter_prometheus::{PrometheusMetricsReader, encode_statistics};
 use logger_core::log_warn;
 use once_cell::sync::OnceCell;
 use opentelemetry::global::ObjectSafeSpan;
@@ -11,8 +12,10 @@ use opentelemetry_sdk::runtime::Tokio;
 use opentelemetry_sdk::trace::{BatchConfig, BatchSpanProcessor, TracerProvider};
 use std::io::{Error, ErrorKind};
 use std::path::PathBuf;
+use std::sync::atomic::AtomicU64;
 #[cfg(test)]
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::{Arc, OnceLock, RwLock};
 use std::time::Duration;
 use thiserror::Error;
@@ -26,6 +29,16 @@ const TRACE_SCOPE: &str = "valkey_glide";
 const TIMEOUT_ERROR_METRIC: &str = "glide.timeout_errors";
 const RETRIES_METRIC: &str = "glide.retry_attempts";
 const MOVED_ERROR_METRIC: &str = "glide.moved_errors";
+const CIRCUIT_BREAKER_METRIC: &str = "glide.circuit_breaker_state";
+const REQUEST_LATENCY_METRIC: &str = "glide.request_latency";
+const NODE_REQUEST_LATENCY_METRIC: &str = "glide.node_request_latency";
+const INFLIGHT_REQUESTS_METRIC: &str = "glide.inflight_requests";
+const OPEN_CONNECTIONS_METRIC: &str = "glide.open_connections";
+const TOPOLOGY_REFRESHES_METRIC: &str = "glide.topology_refreshes";
+const KEY_SAMPLES_METRIC: &str = "glide.key_samples";
+const BIG_VALUES_METRIC: &str = "glide.big_values";
+/// The number of slots in each range that key samples are counted by, so the metric has at most 16 series.
+const KEY_SAMPLES_SLOT_RANGE_SIZE: u16 = 1024;
 
 /// Custom error type for OpenTelemetry errors in Glide
 #[derive(Debug, Error)]
@@ -63,7 +76,8 @@ pub enum GlideSpanStatus {
 #[derive(Clone, Debug)]
 /// Defines the method that exporter connects to the collector. It can be:
 /// gRPC or HTTP. The third type (i.e. "File") defines an exporter that does not connect to a collector
-/// instead, it writes the collected signals to files.
+/// instead, it writes the collected signals to files. The fourth type (i.e. "Prometheus") supports
+/// only metrics, and keeps them in memory to be rendered by [`GlideOpenTelemetry::render_prometheus_metrics`].
 pub enum GlideOpenTelemetrySignalsExporter {
     /// Collector is listening on grpc
     Grpc(String),
@@ -72,6 +86,9 @@ pub enum GlideOpenTelemetrySignalsExporter {
     /// No collector. Instead, write the signals collected to a file. The contained value "PathBuf"
     /// points to the folder where the collected data should be placed.
     File(PathBuf),
+    /// No collector. Instead, the metrics are collected when they're scraped, and rendered in the
+    /// Prometheus text exposition format.
+    Prometheus,
 }
 
 /// Signal types supported when reading protocol configuration from the
@@ -128,6 +145,7 @@ fn parse_endpoint(endpoint: &str) -> Result<GlideOpenTelemetrySignalsExporter, E
         "grpc" => Ok(GlideOpenTelemetrySignalsExporter::Grpc(
             endpoint.to_string(),
         )), // gRPC endpoint
+        "prometheus" => Ok(GlideOpenTelemetrySignalsExporter::Prometheus),
         "file" => {
             // For file, we need to extract the path without the 'file://' prefix
             let file_prefix = "file://";
@@ -274,6 +292,17 @@ impl GlideSpanInner {
         }
     }
 
+    /// Set an attribute on this span, replacing any previous value of `key`.
+    pub fn set_attribute(&self, key: &str, value: &str) {
+        self.span
+            .write()
+            .expect(SPAN_WRITE_LOCK_ERR)
+            .set_attribute(opentelemetry::KeyValue::new(
+                key.to_string(),
+                value.to_string(),
+            ));
+    }
+
     /// Create new span, add it as a child to this span and return it.
     /// Returns an error if the child span creation fails.
     pub fn add_span(&self, name: &str) -> Result<GlideSpanInner, TraceError> {
@@ -301,6 +330,18 @@ impl GlideSpanInner {
             .to_string()
     }
 
+    /// Return the W3C `traceparent` of this span, e.g. `00-<trace ID>-<span ID>-01`
+    pub fn traceparent(&self) -> String {
+        let span = self.span.read().expect(SPAN_READ_LOCK_ERR);
+        let span_context = span.span_context();
+        format!(
+            "00-{}-{}-{:02x}",
+            span_context.trace_id(),
+            span_context.span_id(),
+            span_context.trace_flags().to_u8()
+        )
+    }
+
     /// Finishes the `Span`.
     pub fn end(&self) {
         self.span.write().expect(SPAN_READ_LOCK_ERR).end()
@@ -361,6 +402,11 @@ impl GlideSpan {
         self.inner.set_status(status)
     }
 
+    /// Set an attribute on this span, replacing any previous value of `key`.
+    pub fn set_attribute(&self, key: &str, value: &str) {
+        self.inner.set_attribute(key, value)
+    }
+
     /// Add child span to this span and return it
     pub fn add_span(&self, name: &str) -> Result<GlideSpan, opentelemetry::trace::TraceError> {
         let inner_span = self.inner.add_span(name).map_err(|err| {
@@ -374,6 +420,11 @@ impl GlideSpan {
         self.inner.id()
     }
 
+    /// Return the W3C `traceparent` of this span, used to propagate its trace context to the server.
+    pub fn traceparent(&self) -> String {
+        self.inner.traceparent()
+    }
+
     /// Finishes the `Span`.
     pub fn end(&self) {
         self.inner.end()
@@ -412,6 +463,9 @@ pub struct GlideOpenTelemetryTracesConfig {
     trace_exporter: GlideOpenTelemetrySignalsExporter,
     /// The percentage of requests to sample and create a span for, used to measure command duration.
     trace_sample_percentage: u32,
+    /// Whether glide-core samples requests and creates their spans by itself, instead of relying on the
+    /// spans created by the bindings.
+    automatic_spans: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -436,6 +490,8 @@ pub struct GlideOpenTelemetryConfigBuilder {
     traces_config: Option<GlideOpenTelemetryTracesConfig>,
     /// Optional configuration for exporting metrics data. If `None`, metrics data will not be exported.
     metrics_config: Option<GlideOpenTelemetryMetricsConfig>,
+    /// Whether glide-core creates the spans of sampled requests by itself.
+    automatic_spans: bool,
 }
 
 impl Default for GlideOpenTelemetryConfigBuilder {
@@ -444,6 +500,7 @@ impl Default for GlideOpenTelemetryConfigBuilder {
             flush_interval_ms: Duration::from_millis(DEFAULT_FLUSH_SIGNAL_INTERVAL_MS as u64),
             traces_config: None,
             metrics_config: None,
+            automatic_spans: false,
         }
     }
 }
@@ -470,10 +527,21 @@ impl GlideOpenTelemetryConfigBuilder {
         self.traces_config = Some(GlideOpenTelemetryTracesConfig {
             trace_exporter: exporter,
             trace_sample_percentage: sample_percentage.unwrap_or(DEFAULT_TRACE_SAMPLE_PERCENTAGE),
+            automatic_spans: false,
         });
         self
     }
 
+    /// Configure glide-core to sample requests and create their spans by itself, using the sample percentage
+    /// of the trace exporter. Requests that already carry a span created by the binding aren't sampled again,
+    /// so bindings that enable this should stop creating spans of their own.
+    ///
+    /// - `enabled`: Whether to create spans automatically. Has no effect if no trace exporter is configured.
+    pub fn with_automatic_spans(mut self, enabled: bool) -> Self {
+        self.automatic_spans = enabled;
+        self
+    }
+
     /// Configure the metrics exporter
     ///
     /// - `exporter`: The exporter endpoint to use for metrics data.
@@ -487,7 +555,12 @@ impl GlideOpenTelemetryConfigBuilder {
     pub fn build(self) -> GlideOpenTelemetryConfig {
         GlideOpenTelemetryConfig {
             flush_interval_ms: self.flush_interval_ms,
-            traces: self.traces_config,
+            traces: self
+                .traces_config
+                .map(|traces_config| GlideOpenTelemetryTracesConfig {
+                    automatic_spans: self.automatic_spans,
+                    ..traces_config
+                }),
             metrics: self.metrics_config,
         }
     }
@@ -502,15 +575,87 @@ fn build_span_exporter(
         .build()
 }
 
+/// Returns the label of the range of slots that contains `slot`, e.g. `1024-2047`.
+fn slot_range_label(slot: u16) -> String {
+    let start = slot - slot % KEY_SAMPLES_SLOT_RANGE_SIZE;
+    format!("{start}-{}", start + KEY_SAMPLES_SLOT_RANGE_SIZE - 1)
+}
+
+/// Returns true if the `request`th request should be sampled, spreading the sampled requests evenly.
+pub fn is_sampled(request: u64, percentage: u32) -> bool {
+    let percentage = u64::from(percentage.min(100));
+    request * percentage / 100 != (request + 1) * percentage / 100
+}
+
+/// The outcome of a request, recorded as the `outcome` attribute of the request latency metrics.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RequestOutcome {
+    Success,
+    /// The server responded with an error.
+    ServerError,
+    Timeout,
+    /// Any other error, e.g. a connection error.
+    Error,
+}
+
+impl RequestOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RequestOutcome::Success => "success",
+            RequestOutcome::ServerError => "server_error",
+            RequestOutcome::Timeout => "timeout",
+            RequestOutcome::Error => "error",
+        }
+    }
+}
+
+/// The state of a node's circuit breaker, recorded as the value of the `glide.circuit_breaker_state` gauge.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CircuitBreakerState {
+    Closed = 0,
+    HalfOpen = 1,
+    Open = 2,
+}
+
+/// Counts a request as in flight, for the `glide.inflight_requests` gauge, until it's dropped.
+pub struct InflightRequest(());
+
+impl Drop for InflightRequest {
+    fn drop(&mut self) {
+        INFLIGHT_REQUESTS.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
 #[derive(Clone)]
 pub struct GlideOpenTelemetry {}
 
 static TIMEOUT_COUNTER: OnceLock<opentelemetry::metrics::Counter<u64>> = OnceLock::new();
 static RETRIES_COUNTER: OnceLock<opentelemetry::metrics::Counter<u64>> = OnceLock::new();
 static MOVED_COUNTER: OnceLock<opentelemetry::metrics::Counter<u64>> = OnceLock::new();
+static CIRCUIT_BREAKER_GAUGE: OnceLock<opentelemetry::metrics::Gauge<u64>> = OnceLock::new();
+static REQUEST_LATENCY_HISTOGRAM: OnceLock<opentelemetry::metrics::Histogram<f64>> =
+    OnceLock::new();
+static NODE_REQUEST_LATENCY_HISTOGRAM: OnceLock<opentelemetry::metrics::Histogram<f64>> =
+    OnceLock::new();
+static KEY_SAMPLES_COUNTER: OnceLock<opentelemetry::metrics::Counter<u64>> = OnceLock::new();
+static BIG_VALUES_HISTOGRAM: OnceLock<opentelemetry::metrics::Histogram<f64>> = OnceLock::new();
+static INFLIGHT_REQUESTS_GAUGE: OnceLock<opentelemetry::metrics::ObservableGauge<u64>> =
+    OnceLock::new();
+static OPEN_CONNECTIONS_GAUGE: OnceLock<opentelemetry::metrics::ObservableGauge<u64>> =
+    OnceLock::new();
+static TOPOLOGY_REFRESHES_COUNTER: OnceLock<opentelemetry::metrics::ObservableCounter<u64>> =
+    OnceLock::new();
+static PROMETHEUS_READER: OnceLock<PrometheusMetricsReader> = OnceLock::new();
+/// The number of requests currently in flight. Tracked even when OpenTelemetry isn't initialized,
+/// so that requests that started before the initialization are accounted for.
+static INFLIGHT_REQUESTS: AtomicU64 = AtomicU64::new(0);
 
 /// Singleton instance of GlideOpenTelemetry. Ensures that telemetry setup happens only once across the application.
 static OTEL: OnceCell<RwLock<GlideOpenTelemetry>> = OnceCell::new();
+/// The percentage of requests for which glide-core creates spans, if automatic spans are enabled.
+static AUTOMATIC_SPANS_SAMPLE_PERCENTAGE: OnceLock<u32> = OnceLock::new();
+/// The number of requests considered for automatic spans so far.
+static AUTOMATIC_SPANS_REQUESTS: AtomicU64 = AtomicU64::new(0);
 
 /// Our interface to OpenTelemetry
 impl GlideOpenTelemetry {
@@ -618,6 +763,10 @@ impl GlideOpenTelemetry {
                     config.flush_interval_ms,
                     &traces_config.trace_exporter,
                 )?;
+                if traces_config.automatic_spans {
+                    let _ = AUTOMATIC_SPANS_SAMPLE_PERCENTAGE
+                        .set(traces_config.trace_sample_percentage);
+                }
             }
 
             if let Some(metrics_config) = config.metrics.as_ref() {
@@ -677,6 +826,11 @@ impl GlideOpenTelemetry {
                 })?;
                 build_span_exporter(batch_config, exporter)
             }
+            GlideOpenTelemetrySignalsExporter::Prometheus => {
+                return Err(GlideOTELError::Other(
+                    "InvalidInput: The Prometheus exporter supports only metrics".to_string(),
+                ));
+            }
             GlideOpenTelemetrySignalsExporter::Http(url) => {
                 match env_protocol.unwrap_or(Protocol::HttpBinary) {
                     Protocol::Grpc => {
@@ -744,6 +898,17 @@ impl GlideOpenTelemetry {
     ) -> Result<(), GlideOTELError> {
         let env_protocol = protocol_from_env(OtelSignal::Metrics);
         let metrics_exporter = match metrics_exporter {
+            GlideOpenTelemetrySignalsExporter::Prometheus => {
+                let reader = PrometheusMetricsReader::new();
+                PROMETHEUS_READER.set(reader.clone()).map_err(|_| {
+                    GlideOTELError::Other(
+                        "OpenTelemetry error: Prometheus reader is already initialized".to_owned(),
+                    )
+                })?;
+                let meter_provider = SdkMeterProvider::builder().with_reader(reader).build();
+                global::set_meter_provider(meter_provider);
+                return Ok(());
+            }
             GlideOpenTelemetrySignalsExporter::File(p) => {
                 let exporter = crate::FileMetricExporter::new(p.clone()).map_err(|e| {
                     GlideOTELError::Other(format!("Failed to create metrics exporter: {}", e))
@@ -855,6 +1020,134 @@ impl GlideOpenTelemetry {
                 )
             })?;
 
+        // Create circuit breaker state gauge
+        CIRCUIT_BREAKER_GAUGE
+            .set(
+                meter
+                    .u64_gauge(CIRCUIT_BREAKER_METRIC)
+                    .with_description(
+                        "Circuit breaker state per node: 0 when closed, 1 when half open, 2 when open",
+                    )
+                    .with_unit("1")
+                    .build(),
+            )
+            .map_err(|_| {
+                GlideOTELError::Other(
+                    "OpenTelemetry error: Failed to initialize circuit breaker gauge".to_owned(),
+                )
+            })?;
+
+        // Create request latency histograms
+        REQUEST_LATENCY_HISTOGRAM
+            .set(
+                meter
+                    .f64_histogram(REQUEST_LATENCY_METRIC)
+                    .with_description("Latency of requests, including retries and redirections")
+                    .with_unit("ms")
+                    .build(),
+            )
+            .map_err(|_| {
+                GlideOTELError::Other(
+                    "OpenTelemetry error: Failed to initialize request latency histogram"
+                        .to_owned(),
+                )
+            })?;
+        NODE_REQUEST_LATENCY_HISTOGRAM
+            .set(
+                meter
+                    .f64_histogram(NODE_REQUEST_LATENCY_METRIC)
+                    .with_description("Latency of single attempts of requests to a node")
+                    .with_unit("ms")
+                    .build(),
+            )
+            .map_err(|_| {
+                GlideOTELError::Other(
+                    "OpenTelemetry error: Failed to initialize node request latency histogram"
+                        .to_owned(),
+                )
+            })?;
+
+        // Create key sampling metrics
+        KEY_SAMPLES_COUNTER
+            .set(
+                meter
+                    .u64_counter(KEY_SAMPLES_METRIC)
+                    .with_description("Number of sampled requests by the slot range of their key")
+                    .with_unit("1")
+                    .build(),
+            )
+            .map_err(|_| {
+                GlideOTELError::Other(
+                    "OpenTelemetry error: Failed to initialize key samples counter".to_owned(),
+                )
+            })?;
+        BIG_VALUES_HISTOGRAM
+            .set(
+                meter
+                    .f64_histogram(BIG_VALUES_METRIC)
+                    .with_description(
+                        "Size of sampled responses that exceeded the big value threshold",
+                    )
+                    .with_unit("By")
+                    .build(),
+            )
+            .map_err(|_| {
+                GlideOTELError::Other(
+                    "OpenTelemetry error: Failed to initialize big values histogram".to_owned(),
+                )
+            })?;
+
+        // Create observable instruments
+        INFLIGHT_REQUESTS_GAUGE
+            .set(
+                meter
+                    .u64_observable_gauge(INFLIGHT_REQUESTS_METRIC)
+                    .with_description("Number of requests in flight")
+                    .with_unit("1")
+                    .with_callback(|observer| {
+                        observer.observe(INFLIGHT_REQUESTS.load(Ordering::Relaxed), &[])
+                    })
+                    .build(),
+            )
+            .map_err(|_| {
+                GlideOTELError::Other(
+                    "OpenTelemetry error: Failed to initialize inflight requests gauge".to_owned(),
+                )
+            })?;
+        OPEN_CONNECTIONS_GAUGE
+            .set(
+                meter
+                    .u64_observable_gauge(OPEN_CONNECTIONS_METRIC)
+                    .with_description("Number of open connections to the servers")
+                    .with_unit("1")
+                    .with_callback(|observer| {
+                        observer.observe(crate::Telemetry::total_connections() as u64, &[])
+                    })
+                    .build(),
+            )
+            .map_err(|_| {
+                GlideOTELError::Other(
+                    "OpenTelemetry error: Failed to initialize open connections gauge".to_owned(),
+                )
+            })?;
+        TOPOLOGY_REFRESHES_COUNTER
+            .set(
+                meter
+                    .u64_observable_counter(TOPOLOGY_REFRESHES_METRIC)
+                    .with_description("Number of cluster topology refreshes")
+                    .with_unit("1")
+                    .with_callback(|observer| {
+                        observer.observe(crate::Telemetry::total_topology_refreshes() as u64, &[])
+                    })
+                    .build(),
+            )
+            .map_err(|_| {
+                GlideOTELError::Other(
+                    "OpenTelemetry error: Failed to initialize topology refreshes counter"
+                        .to_owned(),
+                )
+            })?;
+
         Ok(())
     }
 
@@ -909,6 +1202,150 @@ impl GlideOpenTelemetry {
         Ok(())
     }
 
+    /// Record the circuit breaker state of the node in `address`
+    ///
+    /// If OpenTelemetry is not initialized, this method will do nothing.
+    pub fn record_circuit_breaker_state(
+        address: &str,
+        state: CircuitBreakerState,
+    ) -> Result<(), GlideOTELError> {
+        if GlideOpenTelemetry::is_initialized() {
+            CIRCUIT_BREAKER_GAUGE
+                .get()
+                .ok_or_else(|| {
+                    GlideOTELError::Other(
+                        "OpenTelemetry error: Circuit breaker gauge not initialized".to_string(),
+                    )
+                })?
+                .record(
+                    state as u64,
+                    &[opentelemetry::KeyValue::new("address", address.to_string())],
+                );
+        }
+        Ok(())
+    }
+
+    /// Record the end-to-end latency of a request
+    ///
+    /// If OpenTelemetry is not initialized, this method will do nothing.
+    pub fn record_request_latency(
+        command: &str,
+        outcome: RequestOutcome,
+        latency: Duration,
+    ) -> Result<(), GlideOTELError> {
+        if GlideOpenTelemetry::is_initialized() {
+            REQUEST_LATENCY_HISTOGRAM
+                .get()
+                .ok_or_else(|| {
+                    GlideOTELError::Other(
+                        "OpenTelemetry error: Request latency histogram not initialized"
+                            .to_string(),
+                    )
+                })?
+                .record(
+                    latency.as_secs_f64() * 1000.0,
+                    &[
+                        opentelemetry::KeyValue::new("command", command.to_string()),
+                        opentelemetry::KeyValue::new("outcome", outcome.as_str()),
+                    ],
+                );
+        }
+        Ok(())
+    }
+
+    /// Record the latency of a single attempt of a request to the node in `address`
+    ///
+    /// If OpenTelemetry is not initialized, this method will do nothing.
+    pub fn record_node_request_latency(
+        command: &str,
+        address: &str,
+        outcome: RequestOutcome,
+        latency: Duration,
+    ) -> Result<(), GlideOTELError> {
+        if GlideOpenTelemetry::is_initialized() {
+            NODE_REQUEST_LATENCY_HISTOGRAM
+                .get()
+                .ok_or_else(|| {
+                    GlideOTELError::Other(
+                        "OpenTelemetry error: Node request latency histogram not initialized"
+                            .to_string(),
+                    )
+                })?
+                .record(
+                    latency.as_secs_f64() * 1000.0,
+                    &[
+                        opentelemetry::KeyValue::new("command", command.to_string()),
+                        opentelemetry::KeyValue::new("address", address.to_string()),
+                        opentelemetry::KeyValue::new("outcome", outcome.as_str()),
+                    ],
+                );
+        }
+        Ok(())
+    }
+
+    /// Record a sampled request to a key in `slot`, counted by the range of slots that contains it
+    ///
+    /// If OpenTelemetry is not initialized, this method will do nothing.
+    pub fn record_key_sample(slot: u16) -> Result<(), GlideOTELError> {
+        if GlideOpenTelemetry::is_initialized() {
+            KEY_SAMPLES_COUNTER
+                .get()
+                .ok_or_else(|| {
+                    GlideOTELError::Other(
+                        "OpenTelemetry error: Key samples counter not initialized".to_owned(),
+                    )
+                })?
+                .add(
+                    1,
+                    &[opentelemetry::KeyValue::new(
+                        "slot_range",
+                        slot_range_label(slot),
+                    )],
+                );
+        }
+        Ok(())
+    }
+
+    /// Record the size of a sampled response that exceeded the big value threshold
+    ///
+    /// If OpenTelemetry is not initialized, this method will do nothing.
+    pub fn record_big_value(command: &str, size: usize) -> Result<(), GlideOTELError> {
+        if GlideOpenTelemetry::is_initialized() {
+            BIG_VALUES_HISTOGRAM
+                .get()
+                .ok_or_else(|| {
+                    GlideOTELError::Other(
+                        "OpenTelemetry error: Big values histogram not initialized".to_owned(),
+                    )
+                })?
+                .record(
+                    size as f64,
+                    &[opentelemetry::KeyValue::new("command", command.to_string())],
+                );
+        }
+        Ok(())
+    }
+
+    /// Render the client metrics in the Prometheus text exposition format.
+    ///
+    /// The statistics tracked by [`crate::Telemetry`] are always rendered. The OpenTelemetry metrics are
+    /// rendered only if OpenTelemetry was initialized with the [`GlideOpenTelemetrySignalsExporter::Prometheus`]
+    /// metrics exporter.
+    pub fn render_prometheus_metrics() -> Result<String, GlideOTELError> {
+        let reader = PROMETHEUS_READER.get();
+        let mut text = encode_statistics(reader.is_some());
+        if let Some(reader) = reader {
+            text.push_str(&reader.render()?);
+        }
+        Ok(text)
+    }
+
+    /// Count a request as in flight until the returned guard is dropped
+    pub fn track_inflight_request() -> InflightRequest {
+        INFLIGHT_REQUESTS.fetch_add(1, Ordering::Relaxed);
+        InflightRequest(())
+    }
+
     /// Get the flush interval milliseconds
     pub fn get_flush_interval_ms(config: GlideOpenTelemetryConfig) -> Duration {
         config.flush_interval_ms
@@ -919,6 +1356,16 @@ impl GlideOpenTelemetry {
         GlideSpan::new(name)
     }
 
+    /// Create a span for a request, if automatic spans are enabled and the request is sampled.
+    ///
+    /// Requests are sampled deterministically, e.g. with a sample percentage of 1, one of every 100 requests
+    /// gets a span.
+    pub fn sample_span(name: &str) -> Option<GlideSpan> {
+        let percentage = *AUTOMATIC_SPANS_SAMPLE_PERCENTAGE.get()?;
+        let request = AUTOMATIC_SPANS_REQUESTS.fetch_add(1, Ordering::Relaxed);
+        is_sampled(request, percentage).then(|| GlideSpan::new(name))
+    }
+
     /// Trigger a shutdown procedure flushing all remaining traces
     pub fn shutdown() {
         global::shutdown_tracer_provider();
@@ -946,6 +1393,23 @@ mod tests {
         RUNTIME.get_or_init(|| Runtime::new().expect("Failed to create runtime"))
     }
 
+    /// Returns the metric called `name` from the first export in the metrics file.
+    fn read_metric(name: &str) -> serde_json::Value {
+        let file_content = std::fs::read_to_string(METRICS_JSON).unwrap();
+        let line = file_content
+            .split('\n')
+            .find(|l| !l.trim().is_empty())
+            .unwrap();
+        let metric_json: serde_json::Value = serde_json::from_str(line).unwrap();
+        metric_json["scope_metrics"][0]["metrics"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|metric| metric["name"] == name)
+            .unwrap_or_else(|| panic!("missing metric {name}"))
+            .clone()
+    }
+
     fn string_property_to_u64(json: &serde_json::Value, prop: &str) -> u64 {
         let s = json[prop].to_string().replace('"', "");
         s.parse::<u64>().unwrap()
@@ -1062,6 +1526,26 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_span_traceparent() {
+        let rt = shared_runtime();
+        rt.block_on(async {
+            init_otel().await.unwrap();
+            let span = GlideOpenTelemetry::new_span("Root_Span");
+            let child = span.add_span("Child_Span").unwrap();
+            let traceparent = child.traceparent();
+            let parts: Vec<&str> = traceparent.split('-').collect();
+            assert_eq!(parts.len(), 4);
+            assert_eq!(parts[0], "00");
+            assert_eq!(parts[1].len(), 32);
+            assert_eq!(parts[2], child.id());
+            // The child span belongs to the trace of its parent.
+            assert!(span.traceparent().contains(parts[1]));
+            child.end();
+            span.end();
+        });
+    }
+
     #[test]
     fn test_record_timeout_error() {
         let rt = shared_runtime();
@@ -1176,6 +1660,115 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_record_request_latency() {
+        let rt = shared_runtime();
+        rt.block_on(async {
+            let _ = std::fs::remove_file(METRICS_JSON);
+            init_otel().await.unwrap();
+            let inflight = GlideOpenTelemetry::track_inflight_request();
+            GlideOpenTelemetry::record_request_latency(
+                "GET",
+                RequestOutcome::Success,
+                Duration::from_millis(3),
+            )
+            .unwrap();
+            GlideOpenTelemetry::record_request_latency(
+                "GET",
+                RequestOutcome::Success,
+                Duration::from_millis(5),
+            )
+            .unwrap();
+            GlideOpenTelemetry::record_node_request_latency(
+                "GET",
+                "node1:6379",
+                RequestOutcome::Timeout,
+                Duration::from_millis(7),
+            )
+            .unwrap();
+
+            // Add a sleep to wait for the metrics to be flushed
+            sleep(Duration::from_millis(2100)).await;
+            drop(inflight);
+
+            let request_latency = read_metric("glide.request_latency");
+            assert_eq!(request_latency["unit"], "ms");
+            let data_point = &request_latency["data_points"][0];
+            assert_eq!(data_point["count"], 2);
+            assert_eq!(data_point["sum"], "8");
+            assert_eq!(data_point["attributes"]["command"], "GET");
+            assert_eq!(data_point["attributes"]["outcome"], "success");
+
+            let node_latency = read_metric("glide.node_request_latency");
+            let data_point = &node_latency["data_points"][0];
+            assert_eq!(data_point["count"], 1);
+            assert_eq!(data_point["attributes"]["address"], "node1:6379");
+            assert_eq!(data_point["attributes"]["outcome"], "timeout");
+
+            assert!(
+                read_metric("glide.inflight_requests")["data_points"][0]["value"]
+                    .as_u64()
+                    .unwrap()
+                    >= 1
+            );
+            assert!(read_metric("glide.open_connections")["data_points"][0]["value"].is_u64());
+            let topology_refreshes = read_metric("glide.topology_refreshes");
+            let data_point = &topology_refreshes["data_points"][0];
+            assert!(data_point["value"].is_u64());
+            // Only sums carry a start time.
+            assert!(data_point["start_time"].is_string());
+        });
+    }
+
+    #[test]
+    fn test_record_key_samples_and_big_values() {
+        let rt = shared_runtime();
+        rt.block_on(async {
+            let _ = std::fs::remove_file(METRICS_JSON);
+            init_otel().await.unwrap();
+            GlideOpenTelemetry::record_key_sample(42).unwrap();
+            GlideOpenTelemetry::record_key_sample(1023).unwrap();
+            GlideOpenTelemetry::record_big_value("GET", 2048).unwrap();
+
+            // Add a sleep to wait for the metrics to be flushed
+            sleep(Duration::from_millis(2100)).await;
+
+            let key_samples = read_metric("glide.key_samples");
+            let data_point = &key_samples["data_points"][0];
+            assert_eq!(string_property_to_u64(data_point, "value"), 2);
+            assert_eq!(data_point["attributes"]["slot_range"], "0-1023");
+
+            let big_values = read_metric("glide.big_values");
+            assert_eq!(big_values["unit"], "By");
+            let data_point = &big_values["data_points"][0];
+            assert_eq!(data_point["count"], 1);
+            assert_eq!(data_point["sum"], "2048");
+            assert_eq!(data_point["attributes"]["command"], "GET");
+        });
+    }
+
+    #[test]
+    fn test_slot_range_label() {
+        assert_eq!(slot_range_label(0), "0-1023");
+        assert_eq!(slot_range_label(1024), "1024-2047");
+        assert_eq!(slot_range_label(16383), "15360-16383");
+    }
+
+    #[test]
+    fn test_is_sampled() {
+        let sampled = |percentage| {
+            (0..1000)
+                .filter(|request| is_sampled(*request, percentage))
+                .count()
+        };
+        assert_eq!(sampled(0), 0);
+        assert_eq!(sampled(1), 10);
+        assert_eq!(sampled(25), 250);
+        assert_eq!(sampled(100), 1000);
+        assert!(is_sampled(99, 1));
+        assert!(!is_sampled(100, 1));
+    }
+
     #[test]
     fn test_set_status_ok() {
         let rt = shared_runtime();
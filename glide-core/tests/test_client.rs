@@ -72,7 +72,8 @@ pub(crate) mod shared_client_tests {
                             std::slice::from_ref(&connection_addr),
                             &configuration,
                         )
-                        .into(),
+                        .try_into()
+                        .unwrap(),
                         None,
                     )
                     .await
@@ -450,7 +451,7 @@ pub(crate) mod shared_client_tests {
             );
 
             // Attempt to create client with IAM authentication
-            let client_result = Client::new(connection_request.into(), None).await;
+            let client_result = Client::new(connection_request.try_into().unwrap(), None).await;
 
             match client_result {
                 Ok(mut client) => {
@@ -511,7 +512,7 @@ pub(crate) mod shared_client_tests {
             );
 
             // Attempt to create client with IAM authentication
-            let client_result = Client::new(connection_request.into(), None).await;
+            let client_result = Client::new(connection_request.try_into().unwrap(), None).await;
 
             match client_result {
                 Ok(mut client) => {
@@ -572,7 +573,7 @@ pub(crate) mod shared_client_tests {
             );
 
             // Attempt to create client with IAM authentication
-            let client_result = Client::new(connection_request.into(), None).await;
+            let client_result = Client::new(connection_request.try_into().unwrap(), None).await;
 
             match client_result {
                 Ok(mut client) => {
@@ -636,7 +637,7 @@ pub(crate) mod shared_client_tests {
             connection_request.lazy_connect = true;
 
             // Create client with lazy connection
-            let client_result = Client::new(connection_request.into(), None).await;
+            let client_result = Client::new(connection_request.try_into().unwrap(), None).await;
 
             match client_result {
                 Ok(mut client) => {
@@ -732,7 +733,7 @@ pub(crate) mod shared_client_tests {
             connection_request.lazy_connect = true;
 
             // Create client with lazy connection
-            let client_result = Client::new(connection_request.into(), None).await;
+            let client_result = Client::new(connection_request.try_into().unwrap(), None).await;
 
             match client_result {
                 Ok(mut client) => {
@@ -834,7 +835,7 @@ pub(crate) mod shared_client_tests {
             );
 
             // Attempt to create client with IAM authentication
-            let client_result = Client::new(connection_request.into(), None).await;
+            let client_result = Client::new(connection_request.try_into().unwrap(), None).await;
 
             match client_result {
                 Ok(mut client) => {
@@ -950,7 +951,7 @@ pub(crate) mod shared_client_tests {
             );
 
             // Attempt to create client with IAM authentication
-            let client_result = Client::new(connection_request.into(), None).await;
+            let client_result = Client::new(connection_request.try_into().unwrap(), None).await;
 
             match client_result {
                 Ok(mut client) => {
@@ -1045,7 +1046,7 @@ pub(crate) mod shared_client_tests {
             );
 
             // Attempt to create client with IAM authentication
-            let client_result = Client::new(connection_request.into(), None).await;
+            let client_result = Client::new(connection_request.try_into().unwrap(), None).await;
 
             match client_result {
                 Ok(mut client) => {
@@ -1162,7 +1163,7 @@ pub(crate) mod shared_client_tests {
             );
 
             // Attempt to create client with IAM authentication
-            let client_result = Client::new(connection_request.into(), None).await;
+            let client_result = Client::new(connection_request.try_into().unwrap(), None).await;
 
             match client_result {
                 Ok(mut client) => {
@@ -2695,4 +2696,343 @@ pub(crate) mod shared_client_tests {
             }
         });
     }
+
+    async fn wait_for_number_of_subscribers(client: &mut Client, channel: &str, expected: i64) {
+        let mut cmd = redis::cmd("PUBSUB");
+        cmd.arg("NUMSUB").arg(channel);
+        // In cluster mode, subscriptions are served by the primary that owns the channel's slot.
+        let route = RoutingInfo::SingleNode(SingleNodeRoutingInfo::SpecificNode(Route::new(
+            get_slot(channel.as_bytes()),
+            SlotAddr::Master,
+        )));
+        for _ in 0..50 {
+            let value = client
+                .send_command(&cmd, Some(route.clone()))
+                .await
+                .unwrap();
+            let subscribers: HashMap<String, i64> = redis::from_owned_redis_value(value).unwrap();
+            if subscribers.get(channel) == Some(&expected) {
+                return;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+        panic!("Channel `{channel}` didn't reach {expected} subscribers");
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_runtime_subscriptions_are_restored_after_reconnect(
+        #[values(false, true)] use_cluster: bool,
+    ) {
+        block_on_all(async move {
+            let mut test_basics = setup_test_basics(
+                use_cluster,
+                TestConfiguration {
+                    shared_server: false,
+                    protocol: ProtocolVersion::RESP3,
+                    ..Default::default()
+                },
+            )
+            .await;
+            let channel = generate_random_string(10);
+
+            test_basics
+                .client
+                .subscribe(
+                    redis::PubSubSubscriptionKind::Exact,
+                    vec![channel.as_bytes().to_vec()],
+                )
+                .await
+                .unwrap();
+            wait_for_number_of_subscribers(&mut test_basics.client, &channel, 1).await;
+
+            kill_connection(&mut test_basics.client).await;
+            wait_for_number_of_subscribers(&mut test_basics.client, &channel, 1).await;
+
+            test_basics
+                .client
+                .unsubscribe(redis::PubSubSubscriptionKind::Exact, vec![])
+                .await
+                .unwrap();
+            wait_for_number_of_subscribers(&mut test_basics.client, &channel, 0).await;
+
+            kill_connection(&mut test_basics.client).await;
+            wait_for_number_of_subscribers(&mut test_basics.client, &channel, 0).await;
+        });
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_runtime_subscriptions_require_resp3(#[values(false, true)] use_cluster: bool) {
+        block_on_all(async move {
+            let mut test_basics = setup_test_basics(
+                use_cluster,
+                TestConfiguration {
+                    shared_server: true,
+                    protocol: ProtocolVersion::RESP2,
+                    ..Default::default()
+                },
+            )
+            .await;
+            let err = test_basics
+                .client
+                .subscribe(
+                    redis::PubSubSubscriptionKind::Pattern,
+                    vec![b"news.*".to_vec()],
+                )
+                .await
+                .unwrap_err();
+            assert_eq!(err.kind(), redis::ErrorKind::InvalidClientConfig);
+        });
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_client_side_cache_is_invalidated_by_other_clients(
+        #[values(false, true)] use_cluster: bool,
+        #[values(false, true)] broadcast: bool,
+    ) {
+        block_on_all(async move {
+            let configuration = TestConfiguration {
+                shared_server: true,
+                protocol: ProtocolVersion::RESP3,
+                client_side_cache: Some(glide_core::connection_request::ClientSideCache {
+                    max_entries: 100,
+                    broadcast,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+            let mut test_basics = setup_test_basics(use_cluster, configuration.clone()).await;
+            let mut other_client = create_client(
+                &test_basics.server,
+                TestConfiguration {
+                    client_side_cache: None,
+                    ..configuration
+                },
+            )
+            .await;
+            let key = generate_random_string(10);
+            let mut get = cmd("GET");
+            get.arg(&key);
+            let mut set = cmd("SET");
+            set.arg(&key).arg("first");
+            other_client.send_command(&set, None).await.unwrap();
+
+            assert_eq!(
+                test_basics.client.send_command(&get, None).await.unwrap(),
+                Value::BulkString(b"first".to_vec())
+            );
+
+            // A cache hit is served without reaching the server.
+            assert_eq!(
+                test_basics.client.send_command(&get, None).await.unwrap(),
+                Value::BulkString(b"first".to_vec())
+            );
+
+            let mut set = cmd("SET");
+            set.arg(&key).arg("second");
+            other_client.send_command(&set, None).await.unwrap();
+
+            for _ in 0..100 {
+                if test_basics.client.send_command(&get, None).await.unwrap()
+                    == Value::BulkString(b"second".to_vec())
+                {
+                    return;
+                }
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            }
+            panic!("Cached response wasn't invalidated");
+        });
+    }
+
+    #[rstest]
+    fn test_client_side_cache_requires_resp3(#[values(false, true)] use_cluster: bool) {
+        block_on_all(async move {
+            let configuration = TestConfiguration {
+                protocol: ProtocolVersion::RESP2,
+                cluster_mode: if use_cluster {
+                    ClusterMode::Enabled
+                } else {
+                    ClusterMode::Disabled
+                },
+                client_side_cache: Some(glide_core::connection_request::ClientSideCache {
+                    max_entries: 100,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+            // The configuration is rejected before connecting.
+            let addresses = [redis::ConnectionAddr::Tcp("127.0.0.1".to_string(), 6379)];
+            let Err(err) = Client::new(
+                create_connection_request(&addresses, &configuration)
+                    .try_into()
+                    .unwrap(),
+                None,
+            )
+            .await
+            else {
+                panic!("Client creation should fail");
+            };
+            assert!(format!("{err:?}").contains("RESP3"), "{err:?}");
+        });
+    }
+
+    #[rstest]
+    fn test_circuit_breaker_requires_cluster_mode() {
+        block_on_all(async move {
+            let configuration = TestConfiguration {
+                circuit_breaker: Some(glide_core::connection_request::CircuitBreaker {
+                    failure_threshold: 3,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+            // The configuration is rejected before connecting.
+            let addresses = [redis::ConnectionAddr::Tcp("127.0.0.1".to_string(), 6379)];
+            let Err(err) = Client::new(
+                create_connection_request(&addresses, &configuration)
+                    .try_into()
+                    .unwrap(),
+                None,
+            )
+            .await
+            else {
+                panic!("Client creation should fail");
+            };
+            assert!(format!("{err:?}").contains("cluster mode"), "{err:?}");
+        });
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_blocking_client_from_multiple_threads(#[values(false, true)] use_cluster: bool) {
+        let configuration = TestConfiguration {
+            shared_server: true,
+            cluster_mode: if use_cluster {
+                ClusterMode::Enabled
+            } else {
+                ClusterMode::Disabled
+            },
+            ..Default::default()
+        };
+        // Make sure the shared server is up, using the async client.
+        let _test_basics = block_on_all(setup_test_basics(use_cluster, configuration.clone()));
+        let addresses = if use_cluster {
+            get_shared_cluster_addresses(false)
+        } else {
+            vec![get_shared_server_address(false)]
+        };
+        let client = glide_core::blocking::Client::new(
+            create_connection_request(&addresses, &configuration)
+                .try_into()
+                .unwrap(),
+            None,
+        )
+        .unwrap();
+
+        let threads: Vec<_> = (0..4)
+            .map(|_| {
+                let client = client.clone();
+                std::thread::spawn(move || {
+                    let key = generate_random_string(6);
+                    let value = generate_random_string(6);
+                    let mut set_command = redis::Cmd::new();
+                    set_command.arg("SET").arg(&key).arg(&value);
+                    assert_eq!(
+                        client.send_command(&set_command, None).unwrap(),
+                        Value::Okay
+                    );
+                    let mut get_command = redis::Cmd::new();
+                    get_command.arg("GET").arg(&key);
+                    assert_eq!(
+                        client.send_command(&get_command, None).unwrap(),
+                        Value::BulkString(value.into_bytes())
+                    );
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_CLUSTER_TEST_TIMEOUT)]
+    fn test_typed_commands(#[values(false, true)] use_cluster: bool) {
+        block_on_all(async move {
+            let mut test_basics = setup_test_basics(
+                use_cluster,
+                TestConfiguration {
+                    shared_server: true,
+                    ..Default::default()
+                },
+            )
+            .await;
+            let client = &mut test_basics.client;
+            let key = format!("{{typed}}{}", generate_random_string(6));
+            let missing_key = format!("{{typed}}{}", generate_random_string(6));
+
+            assert!(client.set(&key, "value", &["EX", "100"]).await.unwrap());
+            assert!(!client.set(&key, "other", &["NX"]).await.unwrap());
+            assert_eq!(client.get(&key).await.unwrap(), Some(b"value".to_vec()));
+            assert_eq!(client.get(&missing_key).await.unwrap(), None);
+            assert_eq!(client.append(&key, "!").await.unwrap(), 6);
+            assert_eq!(client.del((&key, &missing_key)).await.unwrap(), 1);
+
+            assert_eq!(
+                client
+                    .zadd(&key, &["NX"], (1.5, "a", 2.5, "b"))
+                    .await
+                    .unwrap(),
+                2
+            );
+            assert_eq!(client.zrank(&key, "b").await.unwrap(), Some(1));
+            assert_eq!(client.zscore(&key, "b").await.unwrap(), Some(2.5));
+            assert_eq!(
+                client.zmscore(&key, ("a", "c")).await.unwrap(),
+                vec![Some(1.5), None]
+            );
+            assert_eq!(
+                client.zpopmin(&key, &[]).await.unwrap(),
+                HashMap::from([(b"a".to_vec(), 1.5)])
+            );
+            assert_eq!(client.zintercard(&[&key], &[]).await.unwrap(), 1);
+
+            let id = client
+                .xadd(&missing_key, &[], "*", ("field", "value"))
+                .await
+                .unwrap()
+                .unwrap();
+            let streams = client
+                .xread(&["COUNT", "10"], &[&missing_key], &["0"])
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(
+                streams,
+                vec![(
+                    missing_key.clone().into_bytes(),
+                    vec![(id, vec![(b"field".to_vec(), b"value".to_vec())])]
+                )]
+            );
+
+            let err = client.incr(&missing_key).await.unwrap_err();
+            assert_eq!(err.code(), Some("WRONGTYPE"));
+
+            client.del(&key).await.unwrap();
+            client.rpush(&key, ("a", "b", "c")).await.unwrap();
+            assert_eq!(client.lpop(&key).await.unwrap(), Some(b"a".to_vec()));
+            assert_eq!(
+                client.rpop_count(&key, 5).await.unwrap(),
+                Some(vec![b"c".to_vec(), b"b".to_vec()])
+            );
+            assert_eq!(client.lpop_count(&key, 1).await.unwrap(), None);
+        });
+    }
 }
@@ -279,9 +279,10 @@ mod cluster_client_tests {
                             connection_request.pubsub_subscriptions =
                                 protobuf::MessageField::from_option(Some(subs.clone()));
 
-                            let _client = Client::new(connection_request.clone().into(), None)
-                                .await
-                                .unwrap();
+                            let _client =
+                                Client::new(connection_request.clone().try_into().unwrap(), None)
+                                    .await
+                                    .unwrap();
 
                             // Now try to create a client with a Sharded subscription which should fail
                             subs.channels_or_patterns_by_type
@@ -289,7 +290,8 @@ mod cluster_client_tests {
                             connection_request.pubsub_subscriptions =
                                 protobuf::MessageField::from_option(Some(subs));
 
-                            let client = Client::new(connection_request.into(), None).await;
+                            let client =
+                                Client::new(connection_request.try_into().unwrap(), None).await;
                             assert!(client.is_err());
                         }
                     }
@@ -527,9 +529,10 @@ mod cluster_client_tests {
             );
 
             // 5. Create the client
-            let mut lazy_glide_client = Client::new(lazy_connection_request.into(), None)
-                .await
-                .expect("Failed to create lazy client for Cluster A");
+            let mut lazy_glide_client =
+                Client::new(lazy_connection_request.try_into().unwrap(), None)
+                    .await
+                    .expect("Failed to create lazy client for Cluster A");
 
             // 6. Assert that no new connections were made yet by the lazy client on Cluster A.
             let clients_after_lazy_init =
@@ -612,7 +615,7 @@ mod cluster_client_tests {
             connection_request.root_certs = vec![ca_cert_bytes.into()];
 
             // Test that connection works with custom root cert
-            let mut client = Client::new(connection_request.into(), None)
+            let mut client = Client::new(connection_request.try_into().unwrap(), None)
                 .await
                 .expect("Failed to create cluster client with custom root cert");
 
@@ -663,7 +666,7 @@ mod cluster_client_tests {
             connection_request.root_certs = vec![wrong_ca_cert_bytes.into()];
 
             // Connection should fail due to certificate mismatch
-            let client_result = Client::new(connection_request.into(), None).await;
+            let client_result = Client::new(connection_request.try_into().unwrap(), None).await;
             assert!(
                 client_result.is_err(),
                 "Expected cluster connection to fail with wrong root certificate"
@@ -0,0 +1,93 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+mod utilities;
+
+#[cfg(test)]
+mod opentelemetry_tests {
+    use super::*;
+    use crate::utilities::mocks::{Mock, ServerMock};
+    use glide_core::client::Client;
+    use glide_core::{
+        GlideOpenTelemetry, GlideOpenTelemetryConfigBuilder, GlideOpenTelemetrySignalsExporter,
+    };
+    use redis::Value;
+    use rstest::rstest;
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+    use std::time::Duration;
+    use utilities::*;
+
+    const SPANS_JSON: &str = "/tmp/glide_core_spans.json";
+    const FLUSH_INTERVAL: Duration = Duration::from_millis(500);
+
+    fn read_spans_json() -> Vec<serde_json::Value> {
+        let file_content =
+            std::fs::read_to_string(SPANS_JSON).expect("Failed to read spans JSON file");
+        file_content
+            .lines()
+            .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+            .filter(|span| span["span_id"].is_string())
+            .collect()
+    }
+
+    fn span_attribute<'a>(span: &'a serde_json::Value, key: &str) -> Option<&'a str> {
+        span["span_attributes"]
+            .as_array()?
+            .iter()
+            .find_map(|attribute| attribute[key].as_str())
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_automatic_request_span_is_exported() {
+        let _ = std::fs::remove_file(SPANS_JSON);
+        let mut responses = HashMap::new();
+        responses.insert(
+            "*2\r\n$4\r\nINFO\r\n$11\r\nREPLICATION\r\n".to_string(),
+            Value::BulkString(b"role:master\r\n".to_vec()),
+        );
+        let server_mock = ServerMock::new(responses);
+        let address = server_mock.get_addresses()[0].clone();
+        let mut get_command = redis::cmd("GET");
+        get_command.arg("foo");
+        server_mock.add_response(&get_command, "$3\r\nbar\r\n".to_string());
+
+        block_on_all(async {
+            let config = GlideOpenTelemetryConfigBuilder::default()
+                .with_flush_interval(FLUSH_INTERVAL)
+                .with_trace_exporter(
+                    GlideOpenTelemetrySignalsExporter::File(PathBuf::from(SPANS_JSON)),
+                    Some(100),
+                )
+                .with_automatic_spans(true)
+                .build();
+            GlideOpenTelemetry::initialise(config).unwrap();
+
+            let connection_request =
+                create_connection_request(std::slice::from_ref(&address), &Default::default());
+            let mut client = Client::new(connection_request.try_into().unwrap(), None)
+                .await
+                .unwrap();
+            let value = client.send_command(&get_command, None).await.unwrap();
+            assert_eq!(value, Value::BulkString(b"bar".to_vec()));
+
+            tokio::time::sleep(FLUSH_INTERVAL * 2).await;
+        });
+
+        let spans = read_spans_json();
+        let request_span = spans
+            .iter()
+            .find(|span| span["name"] == "GET")
+            .unwrap_or_else(|| panic!("No GET span in {spans:?}"));
+        assert_eq!(request_span["parent_span_id"], "0000000000000000");
+        assert_eq!(request_span["status"], "Ok");
+        assert_eq!(span_attribute(request_span, "db.system"), Some("valkey"));
+        assert_eq!(span_attribute(request_span, "db.operation"), Some("GET"));
+        assert_eq!(
+            span_attribute(request_span, "server.address"),
+            Some(address.to_string().as_str())
+        );
+        assert_eq!(span_attribute(request_span, "db.namespace"), Some("0"));
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+mod utilities;
+
+#[cfg(test)]
+mod sentinel_client_tests {
+    use super::*;
+    use glide_core::{
+        client::{Client as GlideClient, ConnectionError, StandaloneClient},
+        connection_request::ReadFrom,
+    };
+    use redis::Value;
+    use std::time::Duration;
+    use utilities::sentinel::{RedisSentinelCluster, SENTINEL_SERVICE_NAME, setup_test_basics};
+    use utilities::*;
+
+    async fn send_set(client: &mut StandaloneClient, key: &str) -> redis::RedisResult<Value> {
+        let mut cmd = redis::cmd("SET");
+        cmd.arg(key).arg("value");
+        client.send_command(&cmd).await
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_sentinel_client_discovers_primary_and_replicas() {
+        block_on_all(async {
+            let mut test_basics = setup_test_basics(
+                2,
+                3,
+                TestConfiguration {
+                    read_from: Some(ReadFrom::PreferReplica),
+                    ..Default::default()
+                },
+            )
+            .await;
+            let client = &mut test_basics.client;
+
+            assert_eq!(send_set(client, "sentinel_key").await.unwrap(), Value::Okay);
+
+            // INFO is sent to all of the client's nodes.
+            let mut cmd = redis::cmd("INFO");
+            cmd.arg("REPLICATION");
+            let Value::Array(replication_infos) = client.send_command(&cmd).await.unwrap() else {
+                panic!("Expected a response from every node");
+            };
+            assert_eq!(replication_infos.len(), 3);
+        });
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_sentinel_client_follows_switch_master() {
+        block_on_all(async {
+            let mut test_basics = setup_test_basics(2, 3, TestConfiguration::default()).await;
+            let client = &mut test_basics.client;
+            assert_eq!(send_set(client, "sentinel_key").await.unwrap(), Value::Okay);
+
+            test_basics.cluster.failover().await;
+
+            // Writes succeed once the `+switch-master` event has been received, without recreating the client.
+            for _ in 0..100 {
+                if matches!(send_set(client, "sentinel_key").await, Ok(Value::Okay)) {
+                    return;
+                }
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+            panic!("Client didn't follow the new primary after failover");
+        });
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_sentinel_client_with_unknown_service_name() {
+        block_on_all(async {
+            let cluster = RedisSentinelCluster::new(1, 1).await;
+            let connection_request = create_connection_request(
+                &cluster.get_sentinel_addresses(),
+                &TestConfiguration {
+                    sentinel_service_name: Some("unknown_service".to_string()),
+                    ..Default::default()
+                },
+            );
+            let err =
+                StandaloneClient::create_client(connection_request.try_into().unwrap(), None, None)
+                    .await
+                    .unwrap_err();
+            assert!(format!("{err:?}").contains("Master with given name not found in sentinel"));
+        });
+    }
+
+    #[test]
+    fn test_sentinel_configuration_is_rejected_in_cluster_mode() {
+        block_on_all(async {
+            let connection_request = create_connection_request(
+                &[redis::ConnectionAddr::Tcp("127.0.0.1".to_string(), 26379)],
+                &TestConfiguration {
+                    cluster_mode: ClusterMode::Enabled,
+                    sentinel_service_name: Some(SENTINEL_SERVICE_NAME.to_string()),
+                    ..Default::default()
+                },
+            );
+            let Err(err) = GlideClient::new(connection_request.try_into().unwrap(), None).await
+            else {
+                panic!("Client creation should fail");
+            };
+            assert!(
+                matches!(err, ConnectionError::Cluster(ref err) if err.kind() == redis::ErrorKind::InvalidClientConfig),
+                "{err:?}"
+            );
+        });
+    }
+}
@@ -364,6 +364,74 @@ mod socket_listener {
         )
     }
 
+    fn write_cancel_request(
+        buffer: &mut Vec<u8>,
+        socket: &mut UnixStream,
+        callback_index: u32,
+        cancelled_callback_index: u32,
+    ) {
+        let mut request = CommandRequest::new();
+        request.callback_idx = callback_index;
+        request.command = Some(command_request::command_request::Command::CancelRequest(
+            command_request::CancelRequest {
+                callback_idx: cancelled_callback_index,
+                ..Default::default()
+            },
+        ));
+        write_request(buffer, socket, request);
+    }
+
+    /// Reads `count` responses from the socket, which might arrive in a single read.
+    fn read_responses(socket: &mut UnixStream, count: usize) -> Vec<Response> {
+        let mut buffer = Vec::new();
+        let mut cursor = 0;
+        let mut responses = Vec::with_capacity(count);
+        while responses.len() < count {
+            match u32::decode_var(&buffer[cursor..]) {
+                Some((length, header_bytes))
+                    if buffer.len() >= cursor + header_bytes + length as usize =>
+                {
+                    responses.push(decode_response(
+                        &buffer,
+                        cursor + header_bytes,
+                        length as usize,
+                    ));
+                    cursor += header_bytes + length as usize;
+                }
+                _ => {
+                    let mut read_buffer = [0_u8; 300];
+                    let size = socket.read(&mut read_buffer).unwrap();
+                    buffer.extend_from_slice(&read_buffer[..size]);
+                }
+            }
+        }
+        responses
+    }
+
+    /// Reads responses from the socket until the reply to a request, skipping push notifications.
+    fn read_request_response(socket: &mut UnixStream) -> Response {
+        let mut buffer = Vec::new();
+        let mut cursor = 0;
+        loop {
+            match u32::decode_var(&buffer[cursor..]) {
+                Some((length, header_bytes))
+                    if buffer.len() >= cursor + header_bytes + length as usize =>
+                {
+                    let response = decode_response(&buffer, cursor + header_bytes, length as usize);
+                    cursor += header_bytes + length as usize;
+                    if !response.is_push {
+                        return response;
+                    }
+                }
+                _ => {
+                    let mut read_buffer = [0_u8; 300];
+                    let size = socket.read(&mut read_buffer).unwrap();
+                    buffer.extend_from_slice(&read_buffer[..size]);
+                }
+            }
+        }
+    }
+
     fn parse_header(buffer: &[u8]) -> (u32, usize) {
         u32::decode_var(buffer).unwrap()
     }
@@ -375,7 +443,6 @@ mod socket_listener {
         cluster_mode: ClusterMode,
     ) {
         // Send the server address
-        const CALLBACK_INDEX: u32 = 0;
         let connection_request = create_connection_request(
             addresses,
             &TestConfiguration {
@@ -385,6 +452,14 @@ mod socket_listener {
                 ..Default::default()
             },
         );
+        send_connection_request(socket, connection_request);
+    }
+
+    fn send_connection_request(
+        socket: &UnixStream,
+        connection_request: connection_request::ConnectionRequest,
+    ) {
+        const CALLBACK_INDEX: u32 = 0;
         let approx_message_length =
             APPROX_RESP_HEADER_LEN + connection_request.compute_size() as usize;
         let mut buffer = Vec::with_capacity(approx_message_length);
@@ -400,6 +475,13 @@ mod socket_listener {
         addresses: &[ConnectionAddr],
         cluster_mode: ClusterMode,
     ) -> UnixStream {
+        let socket = UnixStream::connect(start_socket_listener(socket_path)).unwrap();
+        connect_to_redis(addresses, &socket, use_tls, cluster_mode);
+        socket
+    }
+
+    /// Starts the socket listener, and returns the path of its socket.
+    fn start_socket_listener(socket_path: Option<String>) -> String {
         let socket_listener_state: Arc<ManualResetEvent> =
             Arc::new(ManualResetEvent::new(EventState::Unset));
         let cloned_state = socket_listener_state.clone();
@@ -416,10 +498,7 @@ mod socket_listener {
         );
         socket_listener_state.wait();
         let path = path_arc.lock().unwrap();
-        let path = path.as_ref().expect("Didn't get any socket path");
-        let socket = std::os::unix::net::UnixStream::connect(path).unwrap();
-        connect_to_redis(addresses, &socket, use_tls, cluster_mode);
-        socket
+        path.clone().expect("Didn't get any socket path")
     }
 
     fn setup_mocked_test_basics(socket_path: Option<String>) -> ServerTestBasicsWithMock {
@@ -824,6 +903,319 @@ mod socket_listener {
         assert_null_response(&mut buffer, &mut test_basics.socket, CALLBACK_INDEX);
     }
 
+    #[rstest]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_socket_retry_policy_reports_retries() {
+        const CALLBACK_INDEX: u32 = 99;
+        let key = generate_random_string(KEY_LENGTH);
+        let mut expected_command = Cmd::new();
+        expected_command.arg("GET").arg(key.clone());
+        let mut test_basics = setup_mocked_test_basics(None);
+        test_basics
+            .server_mock
+            .add_response(&expected_command, "-TRYAGAIN mock\r\n".to_string());
+        test_basics
+            .server_mock
+            .add_response(&expected_command, "$3\r\nbar\r\n".to_string());
+
+        let mut request = get_command_request(
+            CALLBACK_INDEX,
+            vec![key.into()],
+            RequestType::Get.into(),
+            false,
+        );
+        let Some(command_request::command_request::Command::SingleCommand(command)) =
+            request.command.as_mut()
+        else {
+            unreachable!()
+        };
+        command.retry_policy = protobuf::MessageField::some(command_request::RetryPolicy {
+            retry_server_errors: Some(true),
+            ..Default::default()
+        });
+        let mut buffer = Vec::with_capacity(100);
+        write_request(&mut buffer, &mut test_basics.socket, request);
+
+        let response = assert_value_response(
+            &mut buffer,
+            Some(&mut test_basics.socket),
+            CALLBACK_INDEX,
+            Value::BulkString(b"bar".to_vec()),
+        );
+        assert_eq!(response.retries, Some(1));
+        assert_eq!(test_basics.server_mock.get_number_of_received_commands(), 2);
+    }
+
+    #[rstest]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_socket_does_not_report_retries_without_a_retry() {
+        const CALLBACK_INDEX: u32 = 99;
+        let key = generate_random_string(KEY_LENGTH);
+        let mut expected_command = Cmd::new();
+        expected_command.arg("GET").arg(key.clone());
+        let mut test_basics = setup_mocked_test_basics(None);
+        test_basics
+            .server_mock
+            .add_response(&expected_command, "$3\r\nbar\r\n".to_string());
+
+        let request = get_command_request(
+            CALLBACK_INDEX,
+            vec![key.into()],
+            RequestType::Get.into(),
+            false,
+        );
+        let mut buffer = Vec::with_capacity(100);
+        write_request(&mut buffer, &mut test_basics.socket, request);
+
+        let response = assert_value_response(
+            &mut buffer,
+            Some(&mut test_basics.socket),
+            CALLBACK_INDEX,
+            Value::BulkString(b"bar".to_vec()),
+        );
+        assert_eq!(response.retries, None);
+    }
+
+    #[rstest]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_socket_retry_policy_reports_retries_of_failed_request() {
+        const CALLBACK_INDEX: u32 = 99;
+        let key = generate_random_string(KEY_LENGTH);
+        let mut expected_command = Cmd::new();
+        expected_command.arg("GET").arg(key.clone());
+        let mut test_basics = setup_mocked_test_basics(None);
+        for _ in 0..2 {
+            test_basics
+                .server_mock
+                .add_response(&expected_command, "-TRYAGAIN mock\r\n".to_string());
+        }
+
+        let mut request = get_command_request(
+            CALLBACK_INDEX,
+            vec![key.into()],
+            RequestType::Get.into(),
+            false,
+        );
+        let Some(command_request::command_request::Command::SingleCommand(command)) =
+            request.command.as_mut()
+        else {
+            unreachable!()
+        };
+        command.retry_policy = protobuf::MessageField::some(command_request::RetryPolicy {
+            max_retries: Some(1),
+            retry_server_errors: Some(true),
+            ..Default::default()
+        });
+        let mut buffer = Vec::with_capacity(100);
+        write_request(&mut buffer, &mut test_basics.socket, request);
+
+        let response = assert_error_response(
+            &mut buffer,
+            &mut test_basics.socket,
+            CALLBACK_INDEX,
+            ResponseType::RequestError,
+        );
+        assert_eq!(response.retries, Some(1));
+        assert_eq!(test_basics.server_mock.get_number_of_received_commands(), 2);
+    }
+
+    fn write_subscription_request(
+        socket: &mut UnixStream,
+        callback_index: u32,
+        channel: &str,
+        subscribe: bool,
+    ) {
+        let mut request = CommandRequest::new();
+        request.callback_idx = callback_index;
+        let channel_type = command_request::PubSubChannelType::Exact.into();
+        let channels_or_patterns = vec![channel.to_string().into()];
+        request.command = Some(if subscribe {
+            command_request::command_request::Command::Subscribe(
+                command_request::SubscribeRequest {
+                    channel_type,
+                    channels_or_patterns,
+                    ..Default::default()
+                },
+            )
+        } else {
+            command_request::command_request::Command::Unsubscribe(
+                command_request::UnsubscribeRequest {
+                    channel_type,
+                    channels_or_patterns,
+                    ..Default::default()
+                },
+            )
+        });
+        let mut buffer = Vec::with_capacity(100);
+        write_request(&mut buffer, socket, request);
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_socket_subscribe_and_unsubscribe() {
+        let server = RedisServer::new(ServerType::Tcp { tls: false });
+        let address = server.get_client_addr();
+        let mut socket = UnixStream::connect(start_socket_listener(None)).unwrap();
+        let connection_request = create_connection_request(
+            std::slice::from_ref(&address),
+            &TestConfiguration {
+                protocol: connection_request::ProtocolVersion::RESP3,
+                request_timeout: Some(REQUEST_TIMEOUT_MS),
+                ..Default::default()
+            },
+        );
+        send_connection_request(&socket, connection_request);
+        let channel = generate_random_string(KEY_LENGTH);
+        let number_of_subscribers = || {
+            block_on_all(async {
+                let mut connection = get_connection(&address).await;
+                let (_, count): (String, i64) = redis::cmd("PUBSUB")
+                    .arg("NUMSUB")
+                    .arg(&channel)
+                    .query_async(&mut connection)
+                    .await
+                    .unwrap();
+                count
+            })
+        };
+
+        for (callback_index, subscribe, expected_subscribers) in [(1, true, 1), (2, false, 0)] {
+            write_subscription_request(&mut socket, callback_index, &channel, subscribe);
+            let response = read_request_response(&mut socket);
+            assert_eq!(response.callback_idx, callback_index);
+            assert_eq!(
+                response.value,
+                Some(response::Value::ConstantResponse(
+                    ConstantResponse::OK.into()
+                )),
+                "Received {response:?}"
+            );
+            assert_eq!(number_of_subscribers(), expected_subscribers);
+        }
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_socket_cancel_blocking_request_is_refused() {
+        const BLPOP_CALLBACK_INDEX: u32 = 1;
+        const CANCEL_CALLBACK_INDEX: u32 = 2;
+        let mut test_basics = setup_server_test_basics(Tls::NoTls, TestServer::Shared);
+        let key = generate_random_string(KEY_LENGTH);
+
+        let mut buffer = Vec::with_capacity(1);
+        write_blpop(
+            &mut buffer,
+            &mut test_basics.socket,
+            BLPOP_CALLBACK_INDEX,
+            &key,
+            1,
+        );
+        // Let the listener send the BLPOP before it's cancelled.
+        thread::sleep(std::time::Duration::from_millis(100));
+        let mut buffer = Vec::with_capacity(1);
+        write_cancel_request(
+            &mut buffer,
+            &mut test_basics.socket,
+            CANCEL_CALLBACK_INDEX,
+            BLPOP_CALLBACK_INDEX,
+        );
+
+        // The cancellation is refused, and the BLPOP is answered once its timeout expires.
+        let responses = read_responses(&mut test_basics.socket, 2);
+        assert_eq!(responses[0].callback_idx, CANCEL_CALLBACK_INDEX);
+        assert_value(responses[0].resp_pointer(), Some(Value::Boolean(false)));
+        assert_eq!(responses[1].callback_idx, BLPOP_CALLBACK_INDEX);
+        assert!(responses[1].value.is_none());
+    }
+
+    #[rstest]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_socket_cancel_request_that_is_not_in_flight() {
+        const CALLBACK_INDEX: u32 = 99;
+        let mut test_basics = setup_mocked_test_basics(None);
+
+        let mut buffer = Vec::with_capacity(1);
+        write_cancel_request(&mut buffer, &mut test_basics.socket, CALLBACK_INDEX, 7);
+
+        assert_value_response(
+            &mut buffer,
+            Some(&mut test_basics.socket),
+            CALLBACK_INDEX,
+            Value::Boolean(false),
+        );
+        assert_eq!(test_basics.server_mock.get_number_of_received_commands(), 0);
+    }
+
+    fn setup_resp_encoded_socket(socket_path: &str, server_mock: &ServerMock) -> UnixStream {
+        let mut connection_request = create_connection_request(
+            &server_mock.get_addresses(),
+            &TestConfiguration {
+                request_timeout: Some(REQUEST_TIMEOUT_MS),
+                ..Default::default()
+            },
+        );
+        connection_request.response_encoding = connection_request::ResponseEncoding::Resp.into();
+        let socket = UnixStream::connect(socket_path).unwrap();
+        send_connection_request(&socket, connection_request);
+        socket
+    }
+
+    #[rstest]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_socket_resp_encoding_shares_client_between_connections() {
+        let mut responses = std::collections::HashMap::new();
+        responses.insert(
+            "*2\r\n$4\r\nINFO\r\n$11\r\nREPLICATION\r\n".to_string(),
+            Value::BulkString(b"role:master\r\nconnected_slaves:0\r\n".to_vec()),
+        );
+        // The mock accepts a single connection, so the second socket can only be served by the same client.
+        let server_mock = ServerMock::new(responses);
+        let socket_path = start_socket_listener(None);
+        let mut sockets = [
+            setup_resp_encoded_socket(&socket_path, &server_mock),
+            setup_resp_encoded_socket(&socket_path, &server_mock),
+        ];
+
+        let key = generate_random_string(KEY_LENGTH);
+        let mut expected_command = Cmd::new();
+        expected_command.arg("GET").arg(key.clone());
+        for (callback_index, socket) in (0..).zip(sockets.iter_mut()) {
+            server_mock.add_response(&expected_command, "$3\r\nbar\r\n".to_string());
+            let mut buffer = Vec::with_capacity(1);
+            write_get(&mut buffer, socket, callback_index, &key, false);
+            let response = get_response(&mut buffer, Some(socket));
+            assert_eq!(response.callback_idx, callback_index);
+            assert_eq!(response.resp_value(), b"$3\r\nbar\r\n");
+        }
+        assert_eq!(server_mock.get_number_of_received_commands(), 2);
+    }
+
+    #[rstest]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_socket_resp_encoding_rejects_pointers() {
+        const CALLBACK_INDEX: u32 = 99;
+        let mut responses = std::collections::HashMap::new();
+        responses.insert(
+            "*2\r\n$4\r\nINFO\r\n$11\r\nREPLICATION\r\n".to_string(),
+            Value::BulkString(b"role:master\r\nconnected_slaves:0\r\n".to_vec()),
+        );
+        let server_mock = ServerMock::new(responses);
+        let mut socket = setup_resp_encoded_socket(&start_socket_listener(None), &server_mock);
+
+        let mut buffer = Vec::with_capacity(1);
+        write_get(&mut buffer, &mut socket, CALLBACK_INDEX, "key", true);
+
+        assert_error_response(
+            &mut buffer,
+            &mut socket,
+            CALLBACK_INDEX,
+            ResponseType::RequestError,
+        );
+        assert_eq!(server_mock.get_number_of_received_commands(), 0);
+    }
+
     #[rstest]
     #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
     fn test_socket_report_error() {
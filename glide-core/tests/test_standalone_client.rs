@@ -8,11 +8,12 @@ mod standalone_client_tests {
     use std::collections::HashMap;
 
     use super::*;
+    use glide_core::GlideSpan;
     use glide_core::{
         client::{Client as GlideClient, ConnectionError, StandaloneClient},
         connection_request::{ProtocolVersion, ReadFrom},
     };
-    use redis::{FromRedisValue, Value};
+    use redis::{CommandRetryPolicy, FromRedisValue, Value};
     use rstest::rstest;
     use utilities::*;
 
@@ -157,6 +158,56 @@ mod standalone_client_tests {
         primary_responses
     }
 
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_traced_command_is_retried_with_its_trace_context() {
+        let server_mock = ServerMock::new(create_primary_responses());
+        let span = GlideSpan::new("GET");
+        let mut cmd = redis::cmd("GET");
+        cmd.arg("foo").set_span(Some(span.clone()));
+        let mut traced_pipeline = redis::pipe();
+        traced_pipeline
+            .cmd("CLIENT")
+            .arg("SETNAME")
+            .arg(span.traceparent())
+            .add_command(cmd.clone())
+            .cmd("CLIENT")
+            .arg("SETNAME")
+            .arg("");
+        server_mock.add_pipeline_response(
+            &traced_pipeline,
+            "+OK\r\n-TRYAGAIN mock\r\n+OK\r\n".to_string(),
+        );
+        server_mock
+            .add_pipeline_response(&traced_pipeline, "+OK\r\n$3\r\nbar\r\n+OK\r\n".to_string());
+
+        let mut connection_request = create_connection_request(
+            &get_mock_addresses(std::slice::from_ref(&server_mock)),
+            &Default::default(),
+        );
+        connection_request.propagate_trace_context = true;
+        block_on_all(async {
+            let mut client = GlideClient::new(connection_request.try_into().unwrap(), None)
+                .await
+                .unwrap();
+            let (result, retries) = client
+                .send_command_with_retry_policy(
+                    &cmd,
+                    None,
+                    Some(CommandRetryPolicy {
+                        max_retries: Some(1),
+                        ..Default::default()
+                    }),
+                )
+                .await;
+            assert_eq!(result, Ok(Value::BulkString(b"bar".to_vec())));
+            assert_eq!(retries, 1);
+        });
+        assert_eq!(server_mock.get_number_of_received_commands(), 2);
+        span.end();
+    }
+
     fn create_replica_response() -> HashMap<String, Value> {
         let mut replica_responses = std::collections::HashMap::new();
         replica_responses.insert(
@@ -211,6 +262,8 @@ mod standalone_client_tests {
         read_from: ReadFrom,
         expected_primary_reads: u16,
         expected_replica_reads: Vec<u16>,
+        // Whether the sorted replica reads must equal `expected_replica_reads`, instead of being at least them
+        exact_replica_reads: bool,
         number_of_initial_replicas: usize,
         number_of_missing_replicas: usize,
         number_of_replicas_dropped_after_connection: usize,
@@ -223,6 +276,7 @@ mod standalone_client_tests {
                 read_from: ReadFrom::Primary,
                 expected_primary_reads: 3,
                 expected_replica_reads: vec![0, 0, 0],
+                exact_replica_reads: false,
                 number_of_initial_replicas: 3,
                 number_of_missing_replicas: 0,
                 number_of_replicas_dropped_after_connection: 0,
@@ -256,9 +310,10 @@ mod standalone_client_tests {
         connection_request.read_from = config.read_from.into();
 
         block_on_all(async {
-            let mut client = StandaloneClient::create_client(connection_request.into(), None, None)
-                .await
-                .unwrap();
+            let mut client =
+                StandaloneClient::create_client(connection_request.try_into().unwrap(), None, None)
+                    .await
+                    .unwrap();
             logger_core::log_info(
                 "Test",
                 format!(
@@ -290,7 +345,11 @@ mod standalone_client_tests {
             .map(|mock| mock.get_number_of_received_commands())
             .collect();
         replica_reads.sort();
-        assert!(config.expected_replica_reads <= replica_reads);
+        if config.exact_replica_reads {
+            assert_eq!(config.expected_replica_reads, replica_reads);
+        } else {
+            assert!(config.expected_replica_reads <= replica_reads);
+        }
     }
 
     #[rstest]
@@ -337,6 +396,65 @@ mod standalone_client_tests {
         });
     }
 
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_read_from_replica_lowest_latency() {
+        // The latencies measured on connection are stable until the next periodic check,
+        // so all reads should be routed to the same replica.
+        test_read_from_replica(ReadFromReplicaTestConfig {
+            read_from: ReadFrom::LowestLatency,
+            expected_primary_reads: 0,
+            expected_replica_reads: vec![0, 0, 3],
+            exact_replica_reads: true,
+            ..Default::default()
+        });
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_primary_rediscovery_after_readonly_error() {
+        const PRIMARY_REPLICATION_INFO: &str = "$13\r\nrole:master\r\n\r\n";
+        const REPLICA_REPLICATION_INFO: &str = "$12\r\nrole:slave\r\n\r\n";
+        let mut listeners: Vec<std::net::TcpListener> =
+            (0..2).map(|_| get_listener_on_available_port()).collect();
+        let old_primary = ServerMock::new_with_listener(HashMap::new(), listeners.pop().unwrap());
+        let new_primary = ServerMock::new_with_listener(HashMap::new(), listeners.pop().unwrap());
+        let mut info_cmd = redis::cmd("INFO");
+        info_cmd.arg("REPLICATION");
+        let mut set_cmd = redis::cmd("SET");
+        set_cmd.arg("foo").arg("bar");
+
+        // Roles on client creation.
+        old_primary.add_response(&info_cmd, PRIMARY_REPLICATION_INFO.to_string());
+        new_primary.add_response(&info_cmd, REPLICA_REPLICATION_INFO.to_string());
+        // The old primary was demoted, so the write fails and triggers a role scan.
+        old_primary.add_response(
+            &set_cmd,
+            "-READONLY You can't write against a read only replica.\r\n".to_string(),
+        );
+        old_primary.add_response(&info_cmd, REPLICA_REPLICATION_INFO.to_string());
+        new_primary.add_response(&info_cmd, PRIMARY_REPLICATION_INFO.to_string());
+        // The write is retried on the new primary.
+        new_primary.add_response(&set_cmd, "+OK\r\n".to_string());
+
+        let servers = vec![old_primary, new_primary];
+        let connection_request =
+            create_connection_request(&get_mock_addresses(&servers), &Default::default());
+
+        block_on_all(async {
+            let mut client =
+                StandaloneClient::create_client(connection_request.try_into().unwrap(), None, None)
+                    .await
+                    .unwrap();
+            let result = client.send_command(&set_cmd).await.unwrap();
+            assert_eq!(result, Value::Okay);
+        });
+        assert_eq!(servers[0].get_number_of_received_commands(), 3);
+        assert_eq!(servers[1].get_number_of_received_commands(), 3);
+    }
+
     #[rstest]
     #[serial_test::serial]
     #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
@@ -402,9 +520,10 @@ mod standalone_client_tests {
         let connection_request =
             create_connection_request(addresses.as_slice(), &Default::default());
         block_on_all(async {
-            let client_res = StandaloneClient::create_client(connection_request.into(), None, None)
-                .await
-                .map_err(ConnectionError::Standalone);
+            let client_res =
+                StandaloneClient::create_client(connection_request.try_into().unwrap(), None, None)
+                    .await
+                    .map_err(ConnectionError::Standalone);
             assert!(client_res.is_err());
             let error = client_res.unwrap_err();
             assert!(matches!(error, ConnectionError::Standalone(_),));
@@ -441,9 +560,10 @@ mod standalone_client_tests {
             create_connection_request(addresses.as_slice(), &Default::default());
 
         block_on_all(async {
-            let mut client = StandaloneClient::create_client(connection_request.into(), None, None)
-                .await
-                .unwrap();
+            let mut client =
+                StandaloneClient::create_client(connection_request.try_into().unwrap(), None, None)
+                    .await
+                    .unwrap();
 
             let result = client.send_command(&cmd).await;
             assert_eq!(result, Ok(Value::Okay));
@@ -587,9 +707,10 @@ mod standalone_client_tests {
                 lazy_client_connection_request_pb;
 
             // We need to use the generic Client::new for lazy loading behavior
-            let mut lazy_glide_client_enum = GlideClient::new(core_connection_request.into(), None)
-                .await
-                .expect("Failed to create lazy GlideClient for dedicated server");
+            let mut lazy_glide_client_enum =
+                GlideClient::new(core_connection_request.try_into().unwrap(), None)
+                    .await
+                    .expect("Failed to create lazy GlideClient for dedicated server");
 
             // 6. Assert that no new connection was made yet by the lazy client
             tokio::time::sleep(std::time::Duration::from_millis(100)).await;
@@ -684,9 +805,10 @@ mod standalone_client_tests {
             connection_request.root_certs = vec![ca_cert_bytes.into()];
 
             // Test that connection works with custom root cert
-            let mut client = StandaloneClient::create_client(connection_request.into(), None, None)
-                .await
-                .expect("Failed to create client with custom root cert");
+            let mut client =
+                StandaloneClient::create_client(connection_request.try_into().unwrap(), None, None)
+                    .await
+                    .expect("Failed to create client with custom root cert");
 
             // Verify connection works by sending a command
             let ping_result = client.send_command(&redis::cmd("PING")).await;
@@ -755,7 +877,8 @@ mod standalone_client_tests {
 
             // Connection should fail due to certificate mismatch
             let client_result =
-                StandaloneClient::create_client(connection_request.into(), None, None).await;
+                StandaloneClient::create_client(connection_request.try_into().unwrap(), None, None)
+                    .await;
             assert!(
                 client_result.is_err(),
                 "Expected connection to fail with wrong root certificate"
@@ -794,7 +917,8 @@ mod standalone_client_tests {
 
             // Client creation should fail during certificate parsing
             let client_result =
-                StandaloneClient::create_client(connection_request.into(), None, None).await;
+                StandaloneClient::create_client(connection_request.try_into().unwrap(), None, None)
+                    .await;
             assert!(
                 client_result.is_err(),
                 "Expected client creation to fail with invalid certificate bytes"
@@ -824,7 +948,8 @@ mod standalone_client_tests {
 
             // Client creation should fail due to invalid configuration
             let client_result =
-                StandaloneClient::create_client(connection_request.into(), None, None).await;
+                StandaloneClient::create_client(connection_request.try_into().unwrap(), None, None)
+                    .await;
             assert!(
                 client_result.is_err(),
                 "Expected client creation to fail when custom certs provided with NoTls mode"
@@ -889,9 +1014,70 @@ mod standalone_client_tests {
                 vec![invalid_ca_cert_bytes.into(), valid_ca_cert_bytes.into()];
 
             // Connection should succeed using the second (valid) certificate
-            let mut client = StandaloneClient::create_client(connection_request.into(), None, None)
-                .await
-                .expect("Failed to create client with multiple root certs");
+            let mut client =
+                StandaloneClient::create_client(connection_request.try_into().unwrap(), None, None)
+                    .await
+                    .expect("Failed to create client with multiple root certs");
+
+            let ping_result = client.send_command(&redis::cmd("PING")).await;
+            assert_eq!(
+                ping_result.unwrap(),
+                Value::SimpleString("PONG".to_string())
+            );
+        });
+    }
+
+    #[rstest]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_mtls_connection_with_client_cert() {
+        block_on_all(async move {
+            let tempdir = tempfile::Builder::new()
+                .prefix("mtls_test")
+                .tempdir()
+                .expect("Failed to create temp dir");
+            let tls_paths = build_keys_and_certs_for_tls(&tempdir);
+            let ca_cert_bytes = tls_paths.read_ca_cert_as_bytes();
+            // The server's certificate is signed by the same CA, so it's also accepted as a client certificate.
+            let client_cert_bytes = tls_paths.read_redis_cert_as_bytes();
+            let client_key_bytes = tls_paths.read_redis_key_as_bytes();
+
+            let server = RedisServer::new_with_addr_tls_modules_and_spawner(
+                redis::ConnectionAddr::TcpTls {
+                    host: "127.0.0.1".to_string(),
+                    port: get_available_port(),
+                    insecure: false,
+                    tls_params: None,
+                },
+                Some(tls_paths),
+                &[],
+                |cmd| {
+                    cmd.arg("--tls-auth-clients")
+                        .arg("yes")
+                        .spawn()
+                        .expect("Failed to spawn server")
+                },
+            );
+            let server_addr = server.get_client_addr();
+            tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+
+            let mut connection_request = create_connection_request(
+                &[server_addr],
+                &TestConfiguration {
+                    use_tls: true,
+                    shared_server: false,
+                    ..Default::default()
+                },
+            );
+            connection_request.tls_mode = glide_core::connection_request::TlsMode::SecureTls.into();
+            connection_request.root_certs = vec![ca_cert_bytes.into()];
+            connection_request.client_cert = client_cert_bytes.into();
+            connection_request.client_key = client_key_bytes.into();
+
+            let mut client =
+                StandaloneClient::create_client(connection_request.try_into().unwrap(), None, None)
+                    .await
+                    .expect("Failed to create client with client certificate");
 
             let ping_result = client.send_command(&redis::cmd("PING")).await;
             assert_eq!(
@@ -900,4 +1086,55 @@ mod standalone_client_tests {
             );
         });
     }
+
+    #[rstest]
+    #[case::mismatched_key(true, true, "do not match")]
+    #[case::missing_key(true, false, "provided together")]
+    #[case::no_tls(false, true, "tls is disabled")]
+    #[serial_test::serial]
+    #[timeout(SHORT_STANDALONE_TEST_TIMEOUT)]
+    fn test_mtls_invalid_client_cert_configuration(
+        #[case] use_tls: bool,
+        #[case] provide_key: bool,
+        #[case] expected_error: &str,
+    ) {
+        block_on_all(async move {
+            let tempdir = tempfile::Builder::new()
+                .prefix("mtls_test")
+                .tempdir()
+                .expect("Failed to create temp dir");
+            let tls_paths = build_keys_and_certs_for_tls(&tempdir);
+            let other_tempdir = tempfile::Builder::new()
+                .prefix("mtls_test_other")
+                .tempdir()
+                .expect("Failed to create temp dir");
+            let other_tls_paths = build_keys_and_certs_for_tls(&other_tempdir);
+
+            // No server is needed, as the configuration is rejected before connecting.
+            let server_addr =
+                redis::ConnectionAddr::Tcp("127.0.0.1".to_string(), get_available_port());
+            let mut connection_request = create_connection_request(
+                &[server_addr],
+                &TestConfiguration {
+                    use_tls,
+                    shared_server: false,
+                    ..Default::default()
+                },
+            );
+            connection_request.client_cert = tls_paths.read_redis_cert_as_bytes().into();
+            if provide_key {
+                connection_request.client_key = other_tls_paths.read_redis_key_as_bytes().into();
+            }
+
+            let err =
+                StandaloneClient::create_client(connection_request.try_into().unwrap(), None, None)
+                    .await
+                    .expect_err("Expected client creation to fail");
+            let err_msg = format!("{err:?}").to_lowercase();
+            assert!(
+                err_msg.contains(expected_error),
+                "Error message should contain `{expected_error}`, got: {err_msg}"
+            );
+        });
+    }
 }
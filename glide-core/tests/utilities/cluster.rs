@@ -125,7 +125,12 @@ impl RedisCluster {
         shards: Option<u16>,
         replicas: Option<u16>,
     ) -> RedisCluster {
-        Self::new_with_tls_paths(use_tls, conn_info, shards, replicas, None)
+        Self::new_with_tls_paths(use_tls, conn_info, true, shards, replicas, None)
+    }
+
+    /// Starts a standalone primary with `replicas` replicas replicating from it.
+    pub fn new_standalone(use_tls: bool, replicas: u16) -> RedisCluster {
+        Self::new_with_tls_paths(use_tls, &None, false, None, Some(replicas), None)
     }
 
     pub fn new_with_tls(
@@ -133,17 +138,21 @@ impl RedisCluster {
         replicas: u16,
         tls_paths: Option<super::TlsFilePaths>,
     ) -> RedisCluster {
-        Self::new_with_tls_paths(true, &None, Some(shards), Some(replicas), tls_paths)
+        Self::new_with_tls_paths(true, &None, true, Some(shards), Some(replicas), tls_paths)
     }
 
     fn new_with_tls_paths(
         use_tls: bool,
         conn_info: &Option<RedisConnectionInfo>,
+        cluster_mode: bool,
         shards: Option<u16>,
         replicas: Option<u16>,
         tls_paths: Option<super::TlsFilePaths>,
     ) -> RedisCluster {
-        let mut script_args = vec!["start", "--cluster-mode"];
+        let mut script_args = vec!["start"];
+        if cluster_mode {
+            script_args.push("--cluster-mode");
+        }
         let shards_num: String;
         let replicas_num: String;
         if let Some(shards) = shards {
@@ -285,6 +294,14 @@ impl RedisCluster {
             .map(|server| ClusterType::build_addr(self.use_tls, &server.host, server.port as u16))
             .collect()
     }
+
+    pub fn get_primary_addresses(&self) -> Vec<ConnectionAddr> {
+        self.servers
+            .iter()
+            .filter(|server| server.is_primary)
+            .map(|server| ClusterType::build_addr(self.use_tls, &server.host, server.port as u16))
+            .collect()
+    }
 }
 
 pub struct ClusterTestBasics {
@@ -323,7 +340,9 @@ pub async fn create_cluster_client(
     configuration.request_timeout = configuration.request_timeout.or(Some(10000));
     let connection_request = create_connection_request(&addresses, &configuration);
 
-    Client::new(connection_request.into(), None).await.unwrap()
+    Client::new(connection_request.try_into().unwrap(), None)
+        .await
+        .unwrap()
 }
 
 pub async fn setup_test_basics_internal(configuration: TestConfiguration) -> ClusterTestBasics {
@@ -1,7 +1,7 @@
 // Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
 
 use futures_intrusive::sync::ManualResetEvent;
-use redis::{Cmd, ConnectionAddr, Value};
+use redis::{Cmd, ConnectionAddr, Pipeline, Value};
 use std::collections::HashMap;
 use std::io;
 use std::io::Read;
@@ -195,6 +195,15 @@ impl ServerMock {
         self.closing_signal.set();
         self.closing_completed_signal.wait().await;
     }
+
+    /// Expects the commands of `pipeline` in a single message, and responds to it with `response`.
+    pub fn add_pipeline_response(&self, pipeline: &Pipeline, response: String) {
+        let expected_message = String::from_utf8(pipeline.get_packed_pipeline()).unwrap();
+        let _ = self.request_sender.send(MockedRequest {
+            expected_message,
+            response,
+        });
+    }
 }
 
 impl Mock for ServerMock {
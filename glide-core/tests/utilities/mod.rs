@@ -10,6 +10,7 @@ use once_cell::sync::Lazy;
 use rand::{Rng, distributions::Alphanumeric};
 use redis::{
     ConnectionAddr, GlideConnectionOptions, PushInfo, RedisConnectionInfo, RedisResult, Value,
+    aio::MultiplexedConnection,
     cluster_routing::{MultipleNodeRoutingInfo, RoutingInfo},
 };
 use socket2::{Domain, Socket, Type};
@@ -23,6 +24,7 @@ use versions::Versioning;
 
 pub mod cluster;
 pub mod mocks;
+pub mod sentinel;
 
 pub(crate) const SHORT_STANDALONE_TEST_TIMEOUT: Duration = Duration::from_millis(20_000);
 pub(crate) const LONG_STANDALONE_TEST_TIMEOUT: Duration = Duration::from_millis(40_000);
@@ -452,6 +454,14 @@ impl TlsFilePaths {
     pub fn read_ca_cert_as_bytes(&self) -> Vec<u8> {
         fs::read(&self.ca_crt).expect("Failed to read CA certificate file")
     }
+
+    pub fn read_redis_cert_as_bytes(&self) -> Vec<u8> {
+        fs::read(&self.redis_crt).expect("Failed to read certificate file")
+    }
+
+    pub fn read_redis_key_as_bytes(&self) -> Vec<u8> {
+        fs::read(&self.redis_key).expect("Failed to read key file")
+    }
 }
 
 pub async fn wait_for_server_to_become_ready(server_address: &ConnectionAddr) {
@@ -573,10 +583,10 @@ fn set_connection_info_to_connection_request(
     connection_request: &mut connection_request::ConnectionRequest,
 ) {
     connection_request.protocol = convert_to_protobuf_protocol(connection_info.protocol).into();
-    if connection_info.password.is_some() {
+    if let Some(password) = connection_info.password {
         connection_request.authentication_info =
             protobuf::MessageField(Some(Box::new(AuthenticationInfo {
-                password: connection_info.password.unwrap().into(),
+                password: password.into(),
                 username: connection_info.username.unwrap_or_default().into(),
                 iam_credentials: protobuf::MessageField::none(),
                 ..Default::default()
@@ -584,32 +594,51 @@ fn set_connection_info_to_connection_request(
     }
 }
 
-pub async fn repeat_try_create<T, Fut>(f: impl Fn() -> Fut) -> T
+/// Calls `f` up to `attempts` times, waiting `interval` between attempts, until it returns a value.
+pub async fn repeat_try<T, Fut>(
+    attempts: usize,
+    interval: Duration,
+    f: impl Fn() -> Fut,
+) -> Option<T>
 where
     Fut: Future<Output = Option<T>>,
 {
-    for _ in 0..500 {
+    for _ in 0..attempts {
         if let Some(value) = f().await {
-            return value;
+            return Some(value);
         }
-        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        tokio::time::sleep(interval).await;
     }
-    panic!("Couldn't create object");
+    None
 }
 
-pub async fn setup_acl(addr: &ConnectionAddr, connection_info: &RedisConnectionInfo) {
+pub async fn repeat_try_create<T, Fut>(f: impl Fn() -> Fut) -> T
+where
+    Fut: Future<Output = Option<T>>,
+{
+    repeat_try(500, Duration::from_millis(5), f)
+        .await
+        .expect("Couldn't create object")
+}
+
+/// Opens a connection to the server in `addr`, retrying until the server accepts connections.
+pub async fn get_connection(addr: &ConnectionAddr) -> MultiplexedConnection {
     let client = redis::Client::open(redis::ConnectionInfo {
         addr: addr.clone(),
         redis: RedisConnectionInfo::default(),
     })
     .unwrap();
-    let mut connection = repeat_try_create(|| async {
+    repeat_try_create(|| async {
         client
             .get_multiplexed_async_connection(GlideConnectionOptions::default())
             .await
             .ok()
     })
-    .await;
+    .await
+}
+
+pub async fn setup_acl(addr: &ConnectionAddr, connection_info: &RedisConnectionInfo) {
+    let mut connection = get_connection(addr).await;
 
     let password = connection_info.password.clone().unwrap();
     let username = connection_info
@@ -671,6 +700,17 @@ pub fn create_connection_request(
     }
     connection_request.lazy_connect = configuration.lazy_connect;
     connection_request.protocol = configuration.protocol.into();
+    connection_request.client_side_cache =
+        protobuf::MessageField::from_option(configuration.client_side_cache.clone());
+    connection_request.circuit_breaker =
+        protobuf::MessageField::from_option(configuration.circuit_breaker.clone());
+    if let Some(service_name) = &configuration.sentinel_service_name {
+        connection_request.sentinel_configuration =
+            protobuf::MessageField::some(connection_request::SentinelConfiguration {
+                service_name: service_name.deref().into(),
+                ..Default::default()
+            });
+    }
     connection_request
 }
 
@@ -688,6 +728,10 @@ pub struct TestConfiguration {
     pub client_az: Option<String>,
     pub protocol: ProtocolVersion,
     pub lazy_connect: bool,
+    pub client_side_cache: Option<connection_request::ClientSideCache>,
+    pub circuit_breaker: Option<connection_request::CircuitBreaker>,
+    // When set, the addresses are of sentinels monitoring the primary of this name
+    pub sentinel_service_name: Option<String>,
 }
 
 pub(crate) async fn setup_test_basics_internal(configuration: &TestConfiguration) -> TestBasics {
@@ -714,10 +758,13 @@ pub(crate) async fn setup_test_basics_internal(configuration: &TestConfiguration
     connection_request.cluster_mode_enabled = false;
     connection_request.protocol = configuration.protocol.into();
     let (push_sender, push_receiver) = tokio::sync::mpsc::unbounded_channel();
-    let client =
-        StandaloneClient::create_client(connection_request.into(), Some(push_sender), None)
-            .await
-            .unwrap();
+    let client = StandaloneClient::create_client(
+        connection_request.try_into().unwrap(),
+        Some(push_sender),
+        None,
+    )
+    .await
+    .unwrap();
 
     TestBasics {
         server,
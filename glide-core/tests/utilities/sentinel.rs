@@ -0,0 +1,180 @@
+// Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+
+use super::cluster::RedisCluster;
+use super::{
+    RedisServer, TestConfiguration, create_connection_request, get_available_port, get_connection,
+    repeat_try,
+};
+use glide_core::client::StandaloneClient;
+use redis::{ConnectionAddr, RedisResult, Value};
+use std::fs::File;
+use std::future::Future;
+use std::io::Write;
+use std::process;
+use std::time::Duration;
+
+pub const SENTINEL_SERVICE_NAME: &str = "master1";
+const LOCALHOST: &str = "127.0.0.1";
+
+pub struct RedisSentinelCluster {
+    pub servers: RedisCluster,
+    pub sentinel_servers: Vec<RedisServer>,
+}
+
+fn spawn_sentinel_server(port: u16, primary: &ConnectionAddr) -> RedisServer {
+    let ConnectionAddr::Tcp(primary_host, primary_port) = primary else {
+        panic!("Sentinels are only tested without TLS");
+    };
+    let tempdir = tempfile::Builder::new()
+        .prefix("redis")
+        .tempdir()
+        .expect("failed to create tempdir");
+    // Sentinels rewrite their configuration file, so it must be passed first and be writable.
+    let config_file_path = tempdir.path().join("sentinel.conf");
+    let mut file = File::create(&config_file_path).unwrap();
+    file.write_all(
+        format!(
+            "sentinel monitor {SENTINEL_SERVICE_NAME} {primary_host} {primary_port} 1\n\
+             sentinel down-after-milliseconds {SENTINEL_SERVICE_NAME} 1000\n\
+             sentinel failover-timeout {SENTINEL_SERVICE_NAME} 1000\n"
+        )
+        .as_bytes(),
+    )
+    .unwrap();
+    file.flush().unwrap();
+
+    let mut server = RedisServer::new_with_addr_tls_modules_and_spawner(
+        ConnectionAddr::Tcp(LOCALHOST.to_string(), port),
+        None,
+        &[],
+        |cmd| {
+            let mut sentinel_cmd = process::Command::new(cmd.get_program());
+            sentinel_cmd
+                .arg(&config_file_path)
+                .args(cmd.get_args())
+                .arg("--sentinel")
+                .current_dir(tempdir.path())
+                .stdout(process::Stdio::null())
+                .stderr(process::Stdio::null());
+            sentinel_cmd.spawn().unwrap()
+        },
+    );
+    server.tempdir = Some(tempdir);
+    server
+}
+
+async fn send_command(addr: &ConnectionAddr, cmd: &redis::Cmd) -> RedisResult<Value> {
+    get_connection(addr).await.send_packed_command(cmd).await
+}
+
+/// Sentinels take a few seconds to discover replicas and to complete a failover, so this waits
+/// longer than `repeat_try_create`.
+async fn wait_for<T, Fut>(f: impl Fn() -> Fut) -> T
+where
+    Fut: Future<Output = Option<T>>,
+{
+    repeat_try(300, Duration::from_millis(100), f)
+        .await
+        .expect("Sentinel deployment didn't reach the expected state")
+}
+
+impl RedisSentinelCluster {
+    /// Starts a primary with `replicas` replicas, monitored by `sentinels` sentinels.
+    pub async fn new(replicas: u16, sentinels: u16) -> RedisSentinelCluster {
+        let servers = RedisCluster::new_standalone(false, replicas);
+        let primary = servers.get_primary_addresses().remove(0);
+        let sentinel_servers = (0..sentinels)
+            .map(|_| spawn_sentinel_server(get_available_port(), &primary))
+            .collect();
+
+        let cluster = RedisSentinelCluster {
+            servers,
+            sentinel_servers,
+        };
+        cluster.wait_for_replicas(replicas).await;
+        cluster
+    }
+
+    pub fn get_sentinel_addresses(&self) -> Vec<ConnectionAddr> {
+        self.sentinel_servers
+            .iter()
+            .map(RedisServer::get_client_addr)
+            .collect()
+    }
+
+    /// Returns the address of the primary, as reported by the first sentinel.
+    pub async fn get_primary_address(&self) -> ConnectionAddr {
+        let mut cmd = redis::cmd("SENTINEL");
+        cmd.arg("GET-MASTER-ADDR-BY-NAME")
+            .arg(SENTINEL_SERVICE_NAME);
+        let (host, port): (String, u16) = redis::from_owned_redis_value(
+            send_command(&self.sentinel_servers[0].get_client_addr(), &cmd)
+                .await
+                .unwrap(),
+        )
+        .unwrap();
+        ConnectionAddr::Tcp(host, port)
+    }
+
+    /// Waits until the sentinels know the primary and all of its replicas.
+    async fn wait_for_replicas(&self, replicas: u16) {
+        let mut cmd = redis::cmd("SENTINEL");
+        cmd.arg("REPLICAS").arg(SENTINEL_SERVICE_NAME);
+        for sentinel in self.sentinel_servers.iter() {
+            wait_for(|| async {
+                let known_replicas: Vec<Value> = redis::from_owned_redis_value(
+                    send_command(&sentinel.get_client_addr(), &cmd).await.ok()?,
+                )
+                .ok()?;
+                (known_replicas.len() == replicas as usize).then_some(())
+            })
+            .await;
+        }
+    }
+
+    /// Forces a failover, and waits until a replica was promoted.
+    pub async fn failover(&self) -> ConnectionAddr {
+        let old_primary = self.get_primary_address().await;
+        let mut cmd = redis::cmd("SENTINEL");
+        cmd.arg("FAILOVER").arg(SENTINEL_SERVICE_NAME);
+        // The failover is rejected until the sentinel considers one of the replicas eligible.
+        wait_for(|| async {
+            send_command(&self.sentinel_servers[0].get_client_addr(), &cmd)
+                .await
+                .ok()
+        })
+        .await;
+        wait_for(|| async {
+            let primary = self.get_primary_address().await;
+            (primary != old_primary).then_some(primary)
+        })
+        .await
+    }
+}
+
+pub struct SentinelTestBasics {
+    pub cluster: RedisSentinelCluster,
+    pub client: StandaloneClient,
+}
+
+/// Starts a sentinel deployment of a primary with `replicas` replicas, monitored by `sentinels` sentinels,
+/// and a client that discovers it through the sentinels.
+pub async fn setup_test_basics(
+    replicas: u16,
+    sentinels: u16,
+    configuration: TestConfiguration,
+) -> SentinelTestBasics {
+    let cluster = RedisSentinelCluster::new(replicas, sentinels).await;
+    let connection_request = create_connection_request(
+        &cluster.get_sentinel_addresses(),
+        &TestConfiguration {
+            sentinel_service_name: Some(SENTINEL_SERVICE_NAME.to_string()),
+            ..configuration
+        },
+    );
+    let client =
+        StandaloneClient::create_client(connection_request.try_into().unwrap(), None, None)
+            .await
+            .unwrap();
+    SentinelTestBasics { cluster, client }
+}
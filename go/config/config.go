@@ -167,6 +167,9 @@ const (
 	// robin manner, prioritizing local replicas, then the local primary, and falling back to any
 	// replica or the primary if needed.
 	AzAffinityReplicaAndPrimary
+	// LowestLatency - Route the read requests to the replica with the lowest measured round-trip latency. If no replica is
+	// available, route the requests to the primary.
+	LowestLatency
 )
 
 func mapReadFrom(readFrom ReadFrom) protobuf.ReadFrom {
@@ -174,6 +177,10 @@ func mapReadFrom(readFrom ReadFrom) protobuf.ReadFrom {
 		return protobuf.ReadFrom_PreferReplica
 	}
 
+	if readFrom == LowestLatency {
+		return protobuf.ReadFrom_LowestLatency
+	}
+
 	if readFrom == AzAffinity {
 		return protobuf.ReadFrom_AZAffinity
 	}
@@ -361,6 +368,10 @@ func (config *ClientConfiguration) ToProtobuf() (*protobuf.ConnectionRequest, er
 			}
 			request.RootCerts = [][]byte{tlsConfig.RootCertificates}
 		}
+
+		if err := setClientCertificate(tlsConfig, request); err != nil {
+			return nil, err
+		}
 	}
 
 	return request, nil
@@ -534,6 +545,10 @@ func (config *ClusterClientConfiguration) ToProtobuf() (*protobuf.ConnectionRequ
 			}
 			request.RootCerts = [][]byte{tlsConfig.RootCertificates}
 		}
+
+		if err := setClientCertificate(tlsConfig, request); err != nil {
+			return nil, err
+		}
 	}
 
 	return request, nil
@@ -679,6 +694,15 @@ type TlsConfiguration struct {
 	//
 	// Default: false (verification is enforced).
 	UseInsecureTLS bool
+
+	// ClientCertificate contains the client certificate chain for mutual TLS (mTLS) in PEM format.
+	//
+	// Must be provided together with ClientKey, and the key must match the certificate.
+	// If nil, the client doesn't authenticate itself with a certificate.
+	ClientCertificate []byte
+
+	// ClientKey contains the private key of the client certificate in PEM format.
+	ClientKey []byte
 }
 
 // NewTlsConfiguration returns a new [TlsConfiguration] with default settings (uses platform verifier).
@@ -695,6 +719,14 @@ func (config *TlsConfiguration) WithRootCertificates(rootCerts []byte) *TlsConfi
 	return config
 }
 
+// WithClientCertificate sets the client certificate and private key used for mutual TLS (mTLS).
+// Both should be in PEM format.
+func (config *TlsConfiguration) WithClientCertificate(cert []byte, key []byte) *TlsConfiguration {
+	config.ClientCertificate = cert
+	config.ClientKey = key
+	return config
+}
+
 // WithInsecureTLS enables or disables insecure TLS mode.
 //
 // When enabled (true), TLS certificate verification is bypassed. This is useful for development
@@ -709,6 +741,21 @@ func (config *TlsConfiguration) WithInsecureTLS(insecure bool) *TlsConfiguration
 	return config
 }
 
+func setClientCertificate(tlsConfig *TlsConfiguration, request *protobuf.ConnectionRequest) error {
+	if tlsConfig.ClientCertificate == nil && tlsConfig.ClientKey == nil {
+		return nil
+	}
+	if len(tlsConfig.ClientCertificate) == 0 || len(tlsConfig.ClientKey) == 0 {
+		return errors.New("client certificate and client key must be provided together")
+	}
+	if request.TlsMode == protobuf.TlsMode_NoTls {
+		return errors.New("client certificate cannot be used when UseTLS is disabled")
+	}
+	request.ClientCert = tlsConfig.ClientCertificate
+	request.ClientKey = tlsConfig.ClientKey
+	return nil
+}
+
 // LoadRootCertificatesFromFile reads a PEM-encoded certificate file and returns its contents as a byte array.
 // This is a convenience function for loading custom root certificates from disk.
 //
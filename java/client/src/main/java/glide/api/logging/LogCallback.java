@@ -0,0 +1,32 @@
+/** Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0 */
+package glide.api.logging;
+
+import java.util.Map;
+
+/**
+ * Receives the logs of GLIDE core, for example to forward them to SLF4J. Set it with {@link
+ * Logger#setLogCallback(Logger.Level, LogCallback)}.
+ *
+ * @example
+ *     <pre>{@code
+ * org.slf4j.Logger slf4jLogger = LoggerFactory.getLogger("glide");
+ * Logger.setLogCallback(
+ *         Logger.Level.INFO,
+ *         (level, identifier, message, fields) ->
+ *                 slf4jLogger.info("{} - {} {}", identifier, message, fields));
+ * Logger.setLoggerConfig(Logger.Level.OFF);
+ * }</pre>
+ */
+@FunctionalInterface
+public interface LogCallback {
+    /**
+     * Called with each log of the callback's level or above.
+     *
+     * @param level The level of the log.
+     * @param identifier The identifier of the log, such as the module that wrote it.
+     * @param message The message of the log.
+     * @param fields The other fields of the log, such as the <code>client_id</code> and <code>
+     *     connection_id</code> of the client and connection that wrote it.
+     */
+    void onLog(Logger.Level level, String identifier, String message, Map<String, String> fields);
+}
@@ -0,0 +1,63 @@
+/** Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0 */
+package glide.api.logging;
+
+import lombok.Builder;
+import lombok.Getter;
+import lombok.NonNull;
+import lombok.ToString;
+
+/**
+ * Represents the rotation and retention of the log files written by the {@link Logger}.
+ *
+ * @example
+ *     <pre>{@code
+ * LogFileConfig fileConfig = LogFileConfig.builder()
+ *     .rotation(LogFileConfig.Rotation.SIZE)
+ *     .rotationValue(10 * 1024 * 1024) // 10 MiB
+ *     .maxFiles(5)
+ *     .compress(true)
+ *     .build();
+ * Logger.init(Logger.Level.INFO, "glide.log", fileConfig);
+ * }</pre>
+ */
+@Getter
+@Builder
+@ToString
+public class LogFileConfig {
+    /** When the log file is rotated, closing it and writing the next logs to a new file. */
+    @Getter
+    public enum Rotation {
+        /** At the start of every hour. */
+        HOURLY(0),
+        /** At the start of every day. */
+        DAILY(1),
+        /** Every <code>rotationValue</code> seconds. */
+        INTERVAL(2),
+        /** Once the file grows beyond <code>rotationValue</code> bytes. */
+        SIZE(3);
+
+        private final int rotation;
+
+        Rotation(int rotation) {
+            this.rotation = rotation;
+        }
+    }
+
+    /** When the log file is rotated. Defaults to {@link Rotation#HOURLY}. */
+    @NonNull @Builder.Default private final Rotation rotation = Rotation.HOURLY;
+
+    /**
+     * The interval in seconds of {@link Rotation#INTERVAL} rotation, or the maximum file size in
+     * bytes of {@link Rotation#SIZE} rotation, which must be positive. Ignored by other rotations.
+     */
+    private final long rotationValue;
+
+    /**
+     * The maximum number of log files kept, including the one being written, after which the oldest
+     * files are deleted. If not set, all the files are kept.
+     */
+    private final Integer maxFiles;
+
+    /** Whether rotated files are compressed with gzip. */
+    private final boolean compress;
+}
@@ -3,10 +3,13 @@ package glide.api.logging;
 
 import static glide.ffi.resolvers.LoggerResolver.initInternal;
 import static glide.ffi.resolvers.LoggerResolver.logInternal;
+import static glide.ffi.resolvers.LoggerResolver.setLogCallbackInternal;
 
 import java.io.IOException;
 import java.io.PrintWriter;
 import java.io.StringWriter;
+import java.util.HashMap;
+import java.util.Map;
 import java.util.function.Supplier;
 import lombok.Getter;
 import lombok.NonNull;
@@ -62,10 +65,47 @@ public final class Logger {
         }
     }
 
+    /** The format of the logs written to the console or to files. */
+    @Getter
+    public enum Format {
+        /** Human readable lines, including the fields of the log context. */
+        TEXT(0),
+        /** A JSON object per line, with the level, identifier, message and fields of the log. */
+        JSON(1);
+
+        private final int format;
+
+        Format(int format) {
+            this.format = format;
+        }
+    }
+
     @Getter private static Level loggerLevel;
 
     private static void initLogger(@NonNull Level level, String fileName) {
-        loggerLevel = Level.fromInt(initInternal(level.getLevel(), fileName));
+        initLogger(level, fileName, LogFileConfig.builder().build());
+    }
+
+    private static void initLogger(
+            @NonNull Level level, String fileName, @NonNull LogFileConfig fileConfig) {
+        initLogger(level, fileName, fileConfig, Format.TEXT);
+    }
+
+    private static void initLogger(
+            @NonNull Level level,
+            String fileName,
+            @NonNull LogFileConfig fileConfig,
+            @NonNull Format format) {
+        loggerLevel =
+                Level.fromInt(
+                        initInternal(
+                                level.getLevel(),
+                                fileName,
+                                fileConfig.getRotation().getRotation(),
+                                fileConfig.getRotationValue(),
+                                fileConfig.getMaxFiles() == null ? 0 : fileConfig.getMaxFiles(),
+                                fileConfig.isCompress(),
+                                format.getFormat()));
     }
 
     /**
@@ -84,6 +124,71 @@ public final class Logger {
         }
     }
 
+    /**
+     * Initialize a logger if it wasn't initialized before - this method is meant to be used when
+     * there is no intention to replace an existing logger. The logger will filter all logs with a
+     * level lower than the given level, and write them in the given format.
+     *
+     * @param level Set the logger level to one of <code>
+     *     [DEFAULT, ERROR, WARN, INFO, DEBUG, TRACE, OFF]</code>. To turn off logging completely, set
+     *     the level to {@link Level#OFF}.
+     * @param fileName If provided, the target of the logs will be the file mentioned. Otherwise, logs
+     *     will be printed to the console.
+     * @param format The format of the logs, one of <code>[TEXT, JSON]</code>.
+     */
+    public static void init(@NonNull Level level, String fileName, @NonNull Format format) {
+        if (loggerLevel == null) {
+            initLogger(level, fileName, LogFileConfig.builder().build(), format);
+        }
+    }
+
+    /**
+     * Initialize a logger if it wasn't initialized before - this method is meant to be used when
+     * there is no intention to replace an existing logger. The logger will filter all logs with a
+     * level lower than the given level, and rotate the log files as configured by <code>fileConfig
+     * </code>.
+     *
+     * @param level Set the logger level to one of <code>
+     *     [DEFAULT, ERROR, WARN, INFO, DEBUG, TRACE, OFF]</code>. To turn off logging completely, set
+     *     the level to {@link Level#OFF}.
+     * @param fileName The target of the logs will be the file mentioned.
+     * @param fileConfig The rotation and retention of the log files. The <code>rotationValue</code>
+     *     of {@link LogFileConfig.Rotation#INTERVAL} and {@link LogFileConfig.Rotation#SIZE} rotation
+     *     must be positive, or an exception is thrown and the logger isn't changed.
+     */
+    public static void init(
+            @NonNull Level level, @NonNull String fileName, @NonNull LogFileConfig fileConfig) {
+        if (loggerLevel == null) {
+            initLogger(level, fileName, fileConfig);
+        }
+    }
+
+    /**
+     * Initialize a logger if it wasn't initialized before - this method is meant to be used when
+     * there is no intention to replace an existing logger. The logger will filter all logs with a
+     * level lower than the given level, and write them in the given format.
+     *
+     * @param level Set the logger level to one of <code>
+     *     [DEFAULT, ERROR, WARN, INFO, DEBUG, TRACE, OFF]</code>. To turn off logging completely, set
+     *     the level to {@link Level#OFF}.
+     * @param fileName If provided, the target of the logs will be the file mentioned. Otherwise, logs
+     *     will be printed to the console.
+     * @param fileConfig The rotation and retention of the log files, used when <code>fileName</code>
+     *     is provided. The <code>rotationValue</code> of {@link LogFileConfig.Rotation#INTERVAL} and
+     *     {@link LogFileConfig.Rotation#SIZE} rotation must be positive, or an exception is thrown
+     *     and the logger isn't changed.
+     * @param format The format of the logs, one of <code>[TEXT, JSON]</code>.
+     */
+    public static void init(
+            @NonNull Level level,
+            String fileName,
+            @NonNull LogFileConfig fileConfig,
+            @NonNull Format format) {
+        if (loggerLevel == null) {
+            initLogger(level, fileName, fileConfig, format);
+        }
+    }
+
     /**
      * Initialize a logger if it wasn't initialized before - this method is meant to be used when
      * there is no intention to replace an existing logger. The logger will filter all logs with a
@@ -214,6 +319,65 @@ public final class Logger {
         initLogger(level, fileName);
     }
 
+    /**
+     * Creates a new logger instance and configure it with the provided log level, file name and log
+     * format.
+     *
+     * @param level Set the logger level to one of <code>
+     *     [DEFAULT, ERROR, WARN, INFO, DEBUG, TRACE, OFF]
+     *     </code>. If log level isn't provided, the logger will be configured with default
+     *     configuration decided by Glide core.
+     * @param fileName If provided, the target of the logs will be the file mentioned. Otherwise, logs
+     *     will be printed to stdout.
+     * @param format The format of the logs, one of <code>[TEXT, JSON]</code>.
+     */
+    public static void setLoggerConfig(
+            @NonNull Level level, String fileName, @NonNull Format format) {
+        initLogger(level, fileName, LogFileConfig.builder().build(), format);
+    }
+
+    /**
+     * Creates a new logger instance and configure it with the provided log level and file name,
+     * rotating the log files as configured by <code>fileConfig</code>.
+     *
+     * @param level Set the logger level to one of <code>
+     *     [DEFAULT, ERROR, WARN, INFO, DEBUG, TRACE, OFF]
+     *     </code>. If log level isn't provided, the logger will be configured with default
+     *     configuration decided by Glide core.
+     * @param fileName The target of the logs will be the file mentioned.
+     * @param fileConfig The rotation and retention of the log files. The <code>rotationValue</code>
+     *     of {@link LogFileConfig.Rotation#INTERVAL} and {@link LogFileConfig.Rotation#SIZE} rotation
+     *     must be positive, or an exception is thrown and the logger isn't changed.
+     */
+    public static void setLoggerConfig(
+            @NonNull Level level, @NonNull String fileName, @NonNull LogFileConfig fileConfig) {
+        initLogger(level, fileName, fileConfig);
+    }
+
+    /**
+     * Creates a new logger instance and configure it with the provided log level, file name and log
+     * format.
+     *
+     * @param level Set the logger level to one of <code>
+     *     [DEFAULT, ERROR, WARN, INFO, DEBUG, TRACE, OFF]
+     *     </code>. If log level isn't provided, the logger will be configured with default
+     *     configuration decided by Glide core.
+     * @param fileName If provided, the target of the logs will be the file mentioned. Otherwise, logs
+     *     will be printed to stdout.
+     * @param fileConfig The rotation and retention of the log files, used when <code>fileName</code>
+     *     is provided. The <code>rotationValue</code> of {@link LogFileConfig.Rotation#INTERVAL} and
+     *     {@link LogFileConfig.Rotation#SIZE} rotation must be positive, or an exception is thrown
+     *     and the logger isn't changed.
+     * @param format The format of the logs, one of <code>[TEXT, JSON]</code>.
+     */
+    public static void setLoggerConfig(
+            @NonNull Level level,
+            String fileName,
+            @NonNull LogFileConfig fileConfig,
+            @NonNull Format format) {
+        initLogger(level, fileName, fileConfig, format);
+    }
+
     /**
      * Creates a new logger instance and configure it with the provided log level. The logs will be
      * written to stdout. To turn off the logger, use <code>setLoggerConfig(Level.OFF)</code>.
@@ -246,4 +410,38 @@ public final class Logger {
     public static void setLoggerConfig() {
         setLoggerConfig(Level.DEFAULT, null);
     }
+
+    /**
+     * Passes the logs of the given level or above to <code>callback</code>, in addition to the
+     * console or file of the logger, for example to forward them to SLF4J. To pass the logs only to
+     * the callback, configure the logger with {@link Level#OFF}.
+     *
+     * <p>The callback is called on a dedicated thread, in the order the logs were written, and must
+     * not log through the Logger. Logs are dropped while too many logs wait for a slow callback.
+     *
+     * @param level The minimal level of the logs passed to the callback, one of <code>
+     *     [DEFAULT, ERROR, WARN, INFO, DEBUG, TRACE]</code>. {@link Level#DEFAULT} passes the logs of
+     *     level {@link Level#WARN} or above.
+     * @param callback The callback that receives the logs. It replaces the previous callback, which
+     *     still receives the logs written before.
+     */
+    public static void setLogCallback(@NonNull Level level, @NonNull LogCallback callback) {
+        setLogCallbackInternal(
+                level.getLevel(),
+                (internalLevel, identifier, message, fields) -> {
+                    Map<String, String> fieldsMap = new HashMap<>();
+                    for (int i = 0; i + 1 < fields.length; i += 2) {
+                        fieldsMap.put(fields[i], fields[i + 1]);
+                    }
+                    callback.onLog(Level.fromInt(internalLevel), identifier, message, fieldsMap);
+                });
+    }
+
+    /**
+     * Stops passing the logs to the callback set by {@link #setLogCallback(Level, LogCallback)}. The
+     * callback still receives the logs written before.
+     */
+    public static void setLogCallback() {
+        setLogCallbackInternal(Level.DEFAULT.getLevel(), null);
+    }
 }
@@ -10,6 +10,11 @@ public enum ReadFrom {
      * route the requests to the primary.
      */
     PREFER_REPLICA,
+    /**
+     * Route the read requests to the replica with the lowest measured round-trip latency. If no
+     * replica is available, route the requests to the primary.
+     */
+    LOWEST_LATENCY,
     /**
      * Spread the read requests between replicas in the same client's AZ (Aviliablity zone) in a
      * round-robin manner, falling back to other replicas or the primary if needed.
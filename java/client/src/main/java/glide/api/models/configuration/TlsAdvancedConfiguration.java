@@ -52,6 +52,22 @@ public class TlsAdvancedConfiguration {
      */
     @Builder.Default private final byte[] rootCertificates = null;
 
+    /**
+     * Client certificate chain for mutual TLS (mTLS).
+     *
+     * <p>The certificate data should be in PEM format as a byte array. Must be provided together with
+     * {@link #clientKey}, and the key must match the certificate.
+     */
+    @Builder.Default private final byte[] clientCertificate = null;
+
+    /**
+     * Private key of the client certificate for mutual TLS (mTLS).
+     *
+     * <p>The key data should be in PEM format as a byte array. Must be provided together with {@link
+     * #clientCertificate}.
+     */
+    @Builder.Default private final byte[] clientKey = null;
+
     /**
      * Create TlsAdvancedConfiguration from a Java KeyStore file.
      *
@@ -7,7 +7,28 @@ public class LoggerResolver {
         NativeUtils.loadGlideLib();
     }
 
-    public static native int initInternal(int level, String fileName);
+    public static native int initInternal(
+            int level,
+            String fileName,
+            int rotation,
+            long rotationValue,
+            int maxFiles,
+            boolean compress,
+            int format);
 
     public static native void logInternal(int level, String logIdentifier, String message);
+
+    public static native int setLogCallbackInternal(int level, NativeLogCallback callback);
+
+    /** Receives the logs of GLIDE core from the native library. */
+    @FunctionalInterface
+    public interface NativeLogCallback {
+        /**
+         * @param level The level of the log, as in {@link glide.api.logging.Logger.Level}.
+         * @param identifier The identifier of the log.
+         * @param message The message of the log.
+         * @param fields The names and values of the other fields of the log, alternately.
+         */
+        void onLog(int level, String identifier, String message, String[] fields);
+    }
 }
@@ -251,6 +251,8 @@ public class ConnectionManager {
                             requestBuilder.setReadFrom(ReadFrom.Primary);
                         } else if ("PREFER_REPLICA".equals(readFromName)) {
                             requestBuilder.setReadFrom(ReadFrom.PreferReplica);
+                        } else if ("LOWEST_LATENCY".equals(readFromName)) {
+                            requestBuilder.setReadFrom(ReadFrom.LowestLatency);
                         } else if ("AZ_AFFINITY".equals(readFromName)) {
                             requestBuilder.setReadFrom(ReadFrom.AZAffinity);
                         } else if ("AZ_AFFINITY_PREFER_PRIMARY".equals(readFromName)) {
@@ -303,6 +305,15 @@ public class ConnectionManager {
                             requestBuilder.addRootCerts(com.google.protobuf.ByteString.copyFrom(rootCerts));
                         }
 
+                        // Set the client certificate and key if mTLS is configured
+                        TlsAdvancedConfiguration clientTlsConfig = extractClientTlsConfiguration(configuration);
+                        if (clientTlsConfig != null) {
+                            requestBuilder.setClientCert(
+                                    com.google.protobuf.ByteString.copyFrom(clientTlsConfig.getClientCertificate()));
+                            requestBuilder.setClientKey(
+                                    com.google.protobuf.ByteString.copyFrom(clientTlsConfig.getClientKey()));
+                        }
+
                         // Set pubsub subscriptions
                         if (subExact.length > 0 || subPattern.length > 0 || subSharded.length > 0) {
                             PubSubSubscriptions.Builder subBuilder = PubSubSubscriptions.newBuilder();
@@ -502,6 +513,27 @@ public class ConnectionManager {
         return tlsConfig.getRootCertificates();
     }
 
+    private static TlsAdvancedConfiguration extractClientTlsConfiguration(
+            BaseClientConfiguration configuration) {
+        AdvancedBaseClientConfiguration advanced = extractAdvancedConfiguration(configuration);
+        if (advanced == null || advanced.getTlsAdvancedConfiguration() == null) {
+            return null;
+        }
+        TlsAdvancedConfiguration tlsConfig = advanced.getTlsAdvancedConfiguration();
+        if (tlsConfig.getClientCertificate() == null && tlsConfig.getClientKey() == null) {
+            return null;
+        }
+        if (tlsConfig.getClientCertificate() == null || tlsConfig.getClientKey() == null) {
+            throw new ConfigurationError(
+                    "`clientCertificate` and `clientKey` must be provided together.");
+        }
+        if (!configuration.isUseTLS()) {
+            throw new ConfigurationError(
+                    "A client certificate cannot be used when `useTLS` is disabled.");
+        }
+        return tlsConfig;
+    }
+
     private static AdvancedBaseClientConfiguration extractAdvancedConfiguration(
             BaseClientConfiguration configuration) {
         if (configuration instanceof GlideClientConfiguration) {
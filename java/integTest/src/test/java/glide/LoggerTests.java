@@ -4,14 +4,21 @@ package glide;
 import static org.junit.jupiter.api.Assertions.assertEquals;
 import static org.junit.jupiter.api.Assertions.assertFalse;
 import static org.junit.jupiter.api.Assertions.assertNotNull;
+import static org.junit.jupiter.api.Assertions.assertThrows;
 import static org.junit.jupiter.api.Assertions.assertTrue;
 
+import glide.api.logging.LogFileConfig;
 import glide.api.logging.Logger;
 import java.io.File;
+import java.util.List;
+import java.util.Map;
 import java.util.Scanner;
 import java.util.UUID;
+import java.util.concurrent.CopyOnWriteArrayList;
 import lombok.SneakyThrows;
 import org.junit.jupiter.api.Test;
+import org.junit.jupiter.params.ParameterizedTest;
+import org.junit.jupiter.params.provider.EnumSource;
 
 public class LoggerTests {
 
@@ -35,6 +42,54 @@ public class LoggerTests {
         assertEquals(DEFAULT_TEST_LOG_LEVEL, Logger.getLoggerLevel());
     }
 
+    @ParameterizedTest
+    @EnumSource(
+            value = LogFileConfig.Rotation.class,
+            names = {"INTERVAL", "SIZE"})
+    public void zero_rotation_value_is_rejected(LogFileConfig.Rotation rotation) {
+        Logger.setLoggerConfig(DEFAULT_TEST_LOG_LEVEL);
+        LogFileConfig fileConfig =
+                LogFileConfig.builder().rotation(rotation).rotationValue(0).build();
+        Exception exception =
+                assertThrows(
+                        Exception.class,
+                        () ->
+                                Logger.setLoggerConfig(
+                                        Logger.Level.INFO, "zero_rotation.log", fileConfig));
+        assertTrue(exception.getMessage().contains("must be positive"));
+        // The logger isn't changed
+        assertEquals(DEFAULT_TEST_LOG_LEVEL, Logger.getLoggerLevel());
+    }
+
+    @SneakyThrows
+    @Test
+    public void log_callback() {
+        List<String> received = new CopyOnWriteArrayList<>();
+        Logger.setLogCallback(
+                Logger.Level.INFO,
+                (level, identifier, message, fields) -> {
+                    if (identifier.equals("callback_test")) {
+                        received.add(level + " " + message + " " + fields);
+                    }
+                });
+        Logger.setLoggerConfig(Logger.Level.INFO);
+        Logger.log(Logger.Level.INFO, "callback_test", "test callback log message");
+        Logger.log(Logger.Level.DEBUG, "callback_test", "message below the callback level");
+
+        // The callback is called on its own thread
+        for (int i = 0; i < 100 && received.isEmpty(); i++) {
+            Thread.sleep(10);
+        }
+        Logger.setLogCallback();
+        Logger.log(Logger.Level.INFO, "callback_test", "message after reset");
+        Thread.sleep(100);
+
+        assertEquals(List.of("INFO test callback log message " + Map.of()), received);
+
+        // Revert to the default test log level
+        Logger.setLoggerConfig(DEFAULT_TEST_LOG_LEVEL);
+    }
+
     @SneakyThrows
     @Test
     public void log_to_file() {
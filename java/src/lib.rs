@@ -21,7 +21,7 @@ use jni::errors::Error as JniError;
 use jni::objects::{
     GlobalRef, JByteArray, JClass, JMethodID, JObject, JObjectArray, JStaticMethodID, JString,
 };
-use jni::sys::{jint, jlong};
+use jni::sys::{jboolean, jint, jlong};
 use redis::Value;
 use std::str::FromStr;
 use std::sync::{Arc, OnceLock};
@@ -793,11 +793,17 @@ pub extern "system" fn Java_glide_ffi_resolvers_LoggerResolver_logInternal<'loca
 }
 
 #[unsafe(no_mangle)]
+#[allow(clippy::too_many_arguments)]
 pub extern "system" fn Java_glide_ffi_resolvers_LoggerResolver_initInternal<'local>(
     mut env: JNIEnv<'local>,
     _class: JClass<'local>,
     level: jint,
     file_name: JString<'local>,
+    rotation: jint,
+    rotation_value: jlong,
+    max_files: jint,
+    compress: jboolean,
+    format: jint,
 ) -> jint {
     handle_panics(
         move || {
@@ -805,6 +811,11 @@ pub extern "system" fn Java_glide_ffi_resolvers_LoggerResolver_initInternal<'loc
                 env: &mut JNIEnv<'_>,
                 level: jint,
                 file_name: JString<'_>,
+                rotation: jint,
+                rotation_value: jlong,
+                max_files: jint,
+                compress: jboolean,
+                format: jint,
             ) -> Result<jint, FFIError> {
                 let level = if level >= 0 { Some(level) } else { None };
                 let file_name: Option<String> = match env.get_string(&file_name) {
@@ -816,10 +827,51 @@ pub extern "system" fn Java_glide_ffi_resolvers_LoggerResolver_initInternal<'loc
                     Some(lvl) => Some(Level(lvl).try_into()?),
                     None => None,
                 };
-                let logger_level = logger_core::init(level, file_name.as_deref());
+                let rotation_value = rotation_value.max(0) as u64;
+                let rotation = match rotation {
+                    0 => logger_core::LogRotation::Hourly,
+                    1 => logger_core::LogRotation::Daily,
+                    2 => logger_core::LogRotation::Interval(std::time::Duration::from_secs(
+                        rotation_value,
+                    )),
+                    3 => logger_core::LogRotation::Size(rotation_value),
+                    _ => {
+                        return Err(FFIError::Logger(format!(
+                            "Invalid log rotation: {rotation:?}"
+                        )));
+                    }
+                };
+                let format = match format {
+                    0 => logger_core::LogFormat::Text,
+                    1 => logger_core::LogFormat::Json,
+                    _ => {
+                        return Err(FFIError::Logger(format!("Invalid log format: {format:?}")));
+                    }
+                };
+                let file_config = logger_core::LogFileConfig {
+                    rotation,
+                    max_files: (max_files > 0).then_some(max_files as usize),
+                    compress: compress != 0,
+                };
+                let logger_level = logger_core::init_with_file_config(
+                    level,
+                    file_name.as_deref(),
+                    format,
+                    file_config,
+                )
+                .map_err(FFIError::Logger)?;
                 Ok(Level::from(logger_level).0)
             }
-            let result = init_internal(&mut env, level, file_name);
+            let result = init_internal(
+                &mut env,
+                level,
+                file_name,
+                rotation,
+                rotation_value,
+                max_files,
+                compress,
+                format,
+            );
             handle_errors(&mut env, result)
         },
         "initInternal",
@@ -827,6 +879,126 @@ pub extern "system" fn Java_glide_ffi_resolvers_LoggerResolver_initInternal<'loc
     .unwrap_or(0)
 }
 
+/// The number of logs that can wait for the log callback before the next ones are dropped.
+const LOG_CALLBACK_QUEUE_SIZE: usize = 10_000;
+
+/// A log passed to the log callback.
+type LogCallbackRecord = (
+    logger_core::Level,
+    String,
+    String,
+    Vec<(&'static str, String)>,
+);
+
+/// Passes the logs of the given level or above to `callback`, a `LoggerResolver.NativeLogCallback`, in addition to
+/// the console or file set by `initInternal`. A null `callback` stops passing the logs to the previous callback.
+///
+/// The callback is called on a thread attached to the JVM rather than on the threads that write the logs, since these
+/// may hold locks that the callback waits for. Logs are dropped while [LOG_CALLBACK_QUEUE_SIZE] logs wait for it.
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_glide_ffi_resolvers_LoggerResolver_setLogCallbackInternal<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    level: jint,
+    callback: JObject<'local>,
+) -> jint {
+    handle_panics(
+        move || {
+            fn set_log_callback_internal(
+                env: &mut JNIEnv<'_>,
+                level: jint,
+                callback: JObject<'_>,
+            ) -> Result<jint, FFIError> {
+                let level = match level {
+                    level if level >= 0 => Some(Level(level).try_into()?),
+                    _ => None,
+                };
+                if callback.is_null() {
+                    return Ok(Level::from(logger_core::set_log_sink(level, None)).0);
+                }
+                let jvm = env.get_java_vm()?;
+                let callback = env.new_global_ref(callback)?;
+                let (sender, receiver) =
+                    std::sync::mpsc::sync_channel::<LogCallbackRecord>(LOG_CALLBACK_QUEUE_SIZE);
+                std::thread::Builder::new()
+                    .name("glide-log-callback".to_string())
+                    .spawn(move || {
+                        let mut env = match jvm.attach_current_thread_as_daemon() {
+                            Ok(env) => env,
+                            Err(err) => {
+                                log::error!(
+                                    "Failed to attach the log callback thread to the JVM: {err}"
+                                );
+                                return;
+                            }
+                        };
+                        // Ends once the callback is replaced or removed, and the previous logs were passed to it.
+                        for record in receiver {
+                            if call_log_callback(&mut env, &callback, record).is_err()
+                                && env.exception_check().unwrap_or(false)
+                            {
+                                let _ = env.exception_describe();
+                                let _ = env.exception_clear();
+                            }
+                        }
+                    })
+                    .map_err(|err| {
+                        FFIError::Logger(format!("Failed to start the log callback thread: {err}"))
+                    })?;
+                let sink = Arc::new(move |record: &logger_core::LogRecord<'_>| {
+                    _ = sender.try_send((
+                        record.level,
+                        record.identifier.to_string(),
+                        record.message.to_string(),
+                        record.fields.to_vec(),
+                    ));
+                }) as Arc<dyn logger_core::LogSink>;
+                Ok(Level::from(logger_core::set_log_sink(level, Some(sink))).0)
+            }
+            let result = set_log_callback_internal(&mut env, level, callback);
+            handle_errors(&mut env, result)
+        },
+        "setLogCallbackInternal",
+    )
+    .unwrap_or(0)
+}
+
+/// Calls `NativeLogCallback.onLog` with the level, identifier and message of the log, and its fields as alternating
+/// names and values.
+fn call_log_callback(
+    env: &mut JNIEnv<'_>,
+    callback: &GlobalRef,
+    (level, identifier, message, fields): LogCallbackRecord,
+) -> Result<(), JniError> {
+    env.with_local_frame(8, |env| {
+        let identifier = env.new_string(identifier)?;
+        let message = env.new_string(message)?;
+        let java_fields = env.new_object_array(
+            (fields.len() * 2) as i32,
+            "java/lang/String",
+            JObject::null(),
+        )?;
+        for (index, (name, value)) in fields.into_iter().enumerate() {
+            let name = env.new_string(name)?;
+            let value = env.new_string(value)?;
+            env.set_object_array_element(&java_fields, (index * 2) as i32, name)?;
+            env.set_object_array_element(&java_fields, (index * 2 + 1) as i32, value)?;
+        }
+        env.call_method(
+            callback,
+            "onLog",
+            "(ILjava/lang/String;Ljava/lang/String;[Ljava/lang/String;)V",
+            &[
+                Level::from(level).0.into(),
+                (&identifier).into(),
+                (&message).into(),
+                (&java_fields).into(),
+            ],
+        )?;
+        Ok(())
+    })
+}
+
 /// Releases a ClusterScanCursor handle allocated in Rust.
 ///
 /// This function is meant to be invoked by Java using JNI.
@@ -1234,7 +1406,14 @@ pub extern "system" fn Java_glide_internal_GlideNativeBridge_createClient(
             };
 
             // Convert protobuf to glide_core ConnectionRequest
-            let connection_request = glide_core::client::ConnectionRequest::from(request);
+            let connection_request = match glide_core::client::ConnectionRequest::try_from(request)
+            {
+                Ok(req) => req,
+                Err(e) => {
+                    log::error!("Invalid ConnectionRequest: {e}");
+                    return Some(0);
+                }
+            };
 
             // Cache JVM for push callbacks
             if let Ok(jvm) = env.get_java_vm() {
@@ -0,0 +1,224 @@
+/**
+ * Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+ */
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::field::RecordFields;
+use tracing_subscriber::fmt::FmtContext;
+use tracing_subscriber::fmt::format::{Format, FormatEvent, FormatFields, Writer};
+use tracing_subscriber::fmt::time::{FormatTime, SystemTime};
+use tracing_subscriber::registry::LookupSpan;
+
+/// The format of the logs written to the console or to a file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    /// Human-readable lines, such as `2023-07-07T06:57:54.446236Z DEBUG logger_core: identifier - message`.
+    #[default]
+    Text,
+    /// JSON lines, with the timestamp, level, target, identifier, message and context of each log as fields.
+    Json,
+}
+
+/// The fields of a log: the identifier and message given to the log functions, and the fields of its
+/// [crate::LogContext] or of the events of other crates.
+#[derive(Debug, Default)]
+pub(crate) struct LogFields {
+    pub(crate) identifier: String,
+    pub(crate) message: String,
+    pub(crate) fields: Vec<(&'static str, String)>,
+}
+
+impl LogFields {
+    pub(crate) fn from_fields(fields: impl RecordFields) -> Self {
+        let mut log_fields = Self::default();
+        fields.record(&mut log_fields);
+        log_fields
+    }
+
+    fn record(&mut self, field: &Field, value: String) {
+        match field.name() {
+            "identifier" => self.identifier = value,
+            "message" => self.message = value,
+            name => self.fields.push((name, value)),
+        }
+    }
+}
+
+impl Visit for LogFields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record(field, value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.record(field, format!("{value:?}"));
+    }
+}
+
+/// Formats the fields of text logs as `identifier - message`, followed by the other fields as `name=value`.
+pub(crate) struct TextFields;
+
+impl<'writer> FormatFields<'writer> for TextFields {
+    fn format_fields<R: RecordFields>(
+        &self,
+        mut writer: Writer<'writer>,
+        fields: R,
+    ) -> fmt::Result {
+        let log_fields = LogFields::from_fields(fields);
+        let mut separator = "";
+        if !log_fields.identifier.is_empty() {
+            write!(writer, "{} - ", log_fields.identifier)?;
+        }
+        if !log_fields.message.is_empty() {
+            write!(writer, "{}", log_fields.message)?;
+            separator = " ";
+        }
+        for (name, value) in log_fields.fields {
+            write!(writer, "{separator}{name}={value}")?;
+            separator = " ";
+        }
+        Ok(())
+    }
+}
+
+/// Formats logs as text or as JSON lines, as selected by the shared `json` flag.
+pub(crate) struct EventFormat {
+    json: Arc<AtomicBool>,
+    text: Format,
+}
+
+impl EventFormat {
+    pub(crate) fn new(json: Arc<AtomicBool>) -> Self {
+        Self {
+            json,
+            text: Format::default(),
+        }
+    }
+}
+
+impl<S, N> FormatEvent<S, N> for EventFormat
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        if !self.json.load(Ordering::Relaxed) {
+            return self.text.format_event(ctx, writer, event);
+        }
+
+        let mut timestamp = String::new();
+        SystemTime.format_time(&mut Writer::new(&mut timestamp))?;
+        let metadata = event.metadata();
+        let log_fields = LogFields::from_fields(event);
+
+        let mut json_fields = vec![
+            ("timestamp", timestamp),
+            ("level", metadata.level().to_string()),
+            ("target", metadata.target().to_string()),
+        ];
+        if !log_fields.identifier.is_empty() {
+            json_fields.push(("identifier", log_fields.identifier));
+        }
+        json_fields.push(("message", log_fields.message));
+        json_fields.extend(log_fields.fields);
+
+        let mut separator = '{';
+        for (name, value) in json_fields {
+            write!(
+                writer,
+                "{separator}{}:{}",
+                serde_json::Value::from(name),
+                serde_json::Value::from(value)
+            )?;
+            separator = ',';
+        }
+        writeln!(writer, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+    use std::sync::Mutex;
+    use tracing_subscriber::fmt::MakeWriter;
+    use tracing_subscriber::prelude::*;
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for Buffer {
+        type Writer = Buffer;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    fn format_log(json: bool) -> String {
+        let buffer = Buffer::default();
+        let subscriber = tracing_subscriber::registry().with(
+            tracing_subscriber::fmt::layer()
+                .fmt_fields(TextFields)
+                .event_format(EventFormat::new(Arc::new(AtomicBool::new(json))))
+                .with_writer(buffer.clone())
+                .with_ansi(false),
+        );
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::event!(
+                tracing::Level::INFO,
+                identifier = "id",
+                client_id = "client",
+                "a \"quoted\" message"
+            );
+        });
+        let output = buffer.0.lock().unwrap().clone();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_text_format() {
+        let log = format_log(false);
+        assert!(
+            log.ends_with(
+                " INFO logger_core::format::tests: id - a \"quoted\" message client_id=client\n"
+            ),
+            "Log: {log}"
+        );
+    }
+
+    #[test]
+    fn test_json_format() {
+        let log = format_log(true);
+        let json: serde_json::Value = serde_json::from_str(&log).unwrap();
+        assert_eq!(json["level"], "INFO");
+        assert_eq!(json["target"], "logger_core::format::tests");
+        assert_eq!(json["identifier"], "id");
+        assert_eq!(json["message"], "a \"quoted\" message");
+        assert_eq!(json["client_id"], "client");
+        assert!(
+            json["timestamp"]
+                .as_str()
+                .is_some_and(|timestamp| !timestamp.is_empty())
+        );
+        assert!(log.starts_with("{\"timestamp\":"), "Log: {log}");
+    }
+}
@@ -4,19 +4,13 @@
 use once_cell::sync::OnceCell;
 use std::{
     path::{Path, PathBuf},
-    sync::RwLock,
-};
-use tracing::{self, event};
-use tracing_appender::rolling::{RollingFileAppender, RollingWriter, Rotation};
-use tracing_subscriber::{
-    Registry,
-    filter::Filtered,
-    fmt::{
-        Layer,
-        format::{DefaultFields, Format},
+    sync::{
+        Arc, RwLock,
+        atomic::{AtomicBool, Ordering},
     },
-    layer::Layered,
 };
+use tracing::{self, event};
+use tracing_subscriber::{Registry, filter::Filtered, fmt::Layer, layer::Layered};
 
 use tracing_subscriber::{
     self,
@@ -27,23 +21,39 @@ use tracing_subscriber::{
 
 use std::str::FromStr;
 
+mod format;
+mod rotation;
+mod sink;
+
+pub use format::LogFormat;
+use format::{EventFormat, TextFields};
+use rotation::{FileAppender, FileWriter};
+pub use rotation::{LogFileConfig, LogRotation};
+use sink::SinkLayer;
+pub use sink::{LogRecord, LogSink};
+
 // Layer-Filter pair determines whether a log will be collected
-type InnerFiltered = Filtered<Layer<Registry>, LevelFilter, Registry>;
+type InnerFiltered = Filtered<Layer<Registry, TextFields, EventFormat>, LevelFilter, Registry>;
 // A Reloadable pair of layer-filter
 type InnerLayered = Layered<reload::Layer<InnerFiltered, Registry>, Registry>;
-// A reloadable layer of subscriber to a rolling file
-type FileReload = Handle<
-    Filtered<
-        Layer<InnerLayered, DefaultFields, Format, LazyRollingFileAppender>,
-        LevelFilter,
-        InnerLayered,
-    >,
+// Layer-Filter pair of the subscriber to a rolling file
+type FileFiltered = Filtered<
+    Layer<InnerLayered, TextFields, EventFormat, LazyRollingFileAppender>,
+    LevelFilter,
     InnerLayered,
 >;
+// A reloadable layer of subscriber to a rolling file
+type FileReload = Handle<FileFiltered, InnerLayered>;
+type FileLayered = Layered<reload::Layer<FileFiltered, InnerLayered>, InnerLayered>;
+// A reloadable layer of subscriber to a log sink
+type SinkReload = Handle<Filtered<SinkLayer, LevelFilter, FileLayered>, FileLayered>;
 
 pub struct Reloads {
     console_reload: RwLock<reload::Handle<InnerFiltered, Registry>>,
     file_reload: RwLock<FileReload>,
+    sink_reload: RwLock<SinkReload>,
+    // Whether the console and file logs are written as JSON lines, see [LogFormat]
+    json_format: Arc<AtomicBool>,
 }
 
 pub struct InitiateOnce {
@@ -57,25 +67,25 @@ pub static INITIATE_ONCE: InitiateOnce = InitiateOnce {
 const FILE_DIRECTORY: &str = "glide-logs";
 const ENV_GLIDE_LOG_DIR: &str = "GLIDE_LOG_DIR";
 
-/// Wraps [RollingFileAppender] to defer initialization until logging is required,
+/// Wraps [FileAppender] to defer initialization until logging is required,
 /// allowing [init] to disable file logging on read-only filesystems.
-/// This is needed because [RollingFileAppender] tries to create the log directory on initialization.
+/// This is needed because [FileAppender] tries to create the log directory on initialization.
 struct LazyRollingFileAppender {
-    file_appender: OnceCell<RollingFileAppender>,
-    rotation: Rotation,
+    file_appender: OnceCell<FileAppender>,
+    config: LogFileConfig,
     directory: PathBuf,
     filename_prefix: PathBuf,
 }
 
 impl LazyRollingFileAppender {
     fn new(
-        rotation: Rotation,
+        config: LogFileConfig,
         directory: impl AsRef<Path>,
         filename_prefix: impl AsRef<Path>,
     ) -> LazyRollingFileAppender {
         LazyRollingFileAppender {
             file_appender: OnceCell::new(),
-            rotation,
+            config,
             directory: directory.as_ref().to_path_buf(),
             filename_prefix: filename_prefix.as_ref().to_path_buf(),
         }
@@ -83,20 +93,16 @@ impl LazyRollingFileAppender {
 }
 
 impl<'a> tracing_subscriber::fmt::writer::MakeWriter<'a> for LazyRollingFileAppender {
-    type Writer = RollingWriter<'a>;
+    type Writer = FileWriter<'a>;
     fn make_writer(&'a self) -> Self::Writer {
-        let file_appender = self.file_appender.get_or_init(|| {
-            RollingFileAppender::new(
-                self.rotation.clone(),
-                self.directory.clone(),
-                self.filename_prefix.clone(),
-            )
-        });
+        let file_appender = self
+            .file_appender
+            .get_or_init(|| FileAppender::new(self.config, &self.directory, &self.filename_prefix));
         file_appender.make_writer()
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Level {
     Error = 0,
     Warn = 1,
@@ -106,7 +112,7 @@ pub enum Level {
     Off = 5,
 }
 impl Level {
-    fn to_filter(&self) -> filter::LevelFilter {
+    fn to_filter(self) -> filter::LevelFilter {
         match self {
             Level::Trace => LevelFilter::TRACE,
             Level::Debug => LevelFilter::DEBUG,
@@ -118,6 +124,25 @@ impl Level {
     }
 }
 
+impl From<tracing::Level> for Level {
+    fn from(level: tracing::Level) -> Self {
+        match level {
+            tracing::Level::TRACE => Level::Trace,
+            tracing::Level::DEBUG => Level::Debug,
+            tracing::Level::INFO => Level::Info,
+            tracing::Level::WARN => Level::Warn,
+            tracing::Level::ERROR => Level::Error,
+        }
+    }
+}
+
+/// Identifiers of the client and connection that write a log, which are added to it as fields.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LogContext {
+    pub client_id: Option<u64>,
+    pub connection_id: Option<u64>,
+}
+
 /// Attempt to read a directory path from an environment variable. If the environment variable `envname` exists
 /// and contains a valid path - this function will create and return that path. In any case of failure,
 /// this method returns `None` (e.g. the environment variable exists but contains an empty path etc)
@@ -133,15 +158,13 @@ pub fn create_directory_from_env(envname: &str) -> Option<String> {
     Some(dirpath)
 }
 
-// Initialize the global logger to error level on the first call only
-// In any of the calls to the function, including the first - resetting the existence loggers to the new setting
-// provided by using the global reloadable handle
-// The logger will save only logs of the given level or above.
-pub fn init(minimal_level: Option<Level>, file_name: Option<&str>) -> Level {
-    let level = minimal_level.unwrap_or(Level::Warn);
-    let level_filter = level.to_filter();
-    let reloads = INITIATE_ONCE.init_once.get_or_init(|| {
+// Initialize the global logger on the first call only, with all of its outputs disabled
+fn get_or_init_reloads(file_name: Option<&str>) -> &'static Reloads {
+    INITIATE_ONCE.init_once.get_or_init(|| {
+        let json_format = Arc::new(AtomicBool::new(false));
         let stdout_fmt = tracing_subscriber::fmt::layer()
+            .fmt_fields(TextFields)
+            .event_format(EventFormat::new(json_format.clone()))
             .with_ansi(true)
             .with_filter(LevelFilter::OFF);
 
@@ -151,16 +174,21 @@ pub fn init(minimal_level: Option<Level>, file_name: Option<&str>) -> Level {
         let logs_dir =
             create_directory_from_env(ENV_GLIDE_LOG_DIR).unwrap_or(FILE_DIRECTORY.to_string());
         let file_appender = LazyRollingFileAppender::new(
-            Rotation::HOURLY,
+            LogFileConfig::default(),
             logs_dir,
             file_name.unwrap_or("output.log"),
         );
 
         let file_fmt = tracing_subscriber::fmt::layer()
+            .fmt_fields(TextFields)
+            .event_format(EventFormat::new(json_format.clone()))
             .with_writer(file_appender)
             .with_filter(LevelFilter::OFF);
         let (file_layer, file_reload) = reload::Layer::new(file_fmt);
 
+        let sink = SinkLayer::default().with_filter(LevelFilter::OFF);
+        let (sink_layer, sink_reload) = reload::Layer::new(sink);
+
         // If user has set the environment variable "RUST_LOG" with a valid log verbosity, use it
         let log_level = if let Ok(level) = std::env::var("RUST_LOG") {
             let trace_level = tracing::Level::from_str(&level).unwrap_or(tracing::Level::TRACE);
@@ -179,15 +207,62 @@ pub fn init(minimal_level: Option<Level>, file_name: Option<&str>) -> Level {
         tracing_subscriber::registry()
             .with(stdout_layer)
             .with(file_layer)
+            .with(sink_layer)
             .with(targets_filter)
             .init();
 
         let reloads: Reloads = Reloads {
             console_reload: RwLock::new(stdout_reload),
             file_reload: RwLock::new(file_reload),
+            sink_reload: RwLock::new(sink_reload),
+            json_format,
         };
         reloads
-    });
+    })
+}
+
+// Initialize the global logger to error level on the first call only
+// In any of the calls to the function, including the first - resetting the existence loggers to the new setting
+// provided by using the global reloadable handle
+// The logger will save only logs of the given level or above.
+pub fn init(minimal_level: Option<Level>, file_name: Option<&str>) -> Level {
+    init_with_format(minimal_level, file_name, LogFormat::Text)
+}
+
+// Like [init], writing the logs to the console or to the file in the given format.
+pub fn init_with_format(
+    minimal_level: Option<Level>,
+    file_name: Option<&str>,
+    format: LogFormat,
+) -> Level {
+    init_logger(minimal_level, file_name, format, LogFileConfig::default())
+}
+
+// Like [init_with_format], rotating the log files and limiting their number as configured by `file_config`.
+// The configuration applies to the file of the given `file_name`, and is ignored when logging to the console.
+// Returns an error without changing the logger if the configuration isn't valid, see [LogFileConfig::validate].
+pub fn init_with_file_config(
+    minimal_level: Option<Level>,
+    file_name: Option<&str>,
+    format: LogFormat,
+    file_config: LogFileConfig,
+) -> Result<Level, String> {
+    file_config.validate()?;
+    Ok(init_logger(minimal_level, file_name, format, file_config))
+}
+
+fn init_logger(
+    minimal_level: Option<Level>,
+    file_name: Option<&str>,
+    format: LogFormat,
+    file_config: LogFileConfig,
+) -> Level {
+    let level = minimal_level.unwrap_or(Level::Warn);
+    let level_filter = level.to_filter();
+    let reloads = get_or_init_reloads(file_name);
+    reloads
+        .json_format
+        .store(format == LogFormat::Json, Ordering::Relaxed);
 
     match file_name {
         None => {
@@ -208,7 +283,7 @@ pub fn init(minimal_level: Option<Level>, file_name: Option<&str>) -> Level {
             // Check if the environment variable GLIDE_LOG is set
             let logs_dir =
                 create_directory_from_env(ENV_GLIDE_LOG_DIR).unwrap_or(FILE_DIRECTORY.to_string());
-            let file_appender = LazyRollingFileAppender::new(Rotation::HOURLY, logs_dir, file);
+            let file_appender = LazyRollingFileAppender::new(file_config, logs_dir, file);
             let _ = reloads
                 .file_reload
                 .write()
@@ -227,11 +302,39 @@ pub fn init(minimal_level: Option<Level>, file_name: Option<&str>) -> Level {
     level
 }
 
+// Passes the logs of the given level or above to the given sink, in addition to the console or file set by [init].
+// Bindings can call `init(Some(Level::Off), None)` to pass the logs only to the sink.
+// Passing `None` as the sink stops passing logs to the previous sink.
+pub fn set_log_sink(minimal_level: Option<Level>, sink: Option<Arc<dyn LogSink>>) -> Level {
+    let level = minimal_level.unwrap_or(Level::Warn);
+    let level_filter = match sink {
+        Some(_) => level.to_filter(),
+        None => LevelFilter::OFF,
+    };
+    let _ = get_or_init_reloads(None)
+        .sink_reload
+        .write()
+        .expect("error reloading log sink")
+        .modify(|layer| {
+            *layer.filter_mut() = level_filter;
+            layer.inner_mut().sink = sink;
+        });
+    level
+}
+
 macro_rules! create_log {
-    ($name:ident, $uppercase_level:tt) => {
+    ($name:ident, $name_with_context:ident, $uppercase_level:tt) => {
         pub fn $name<Message: AsRef<str>, Identifier: AsRef<str>>(
             log_identifier: Identifier,
             message: Message,
+        ) {
+            $name_with_context(LogContext::default(), log_identifier, message)
+        }
+
+        pub fn $name_with_context<Message: AsRef<str>, Identifier: AsRef<str>>(
+            context: LogContext,
+            log_identifier: Identifier,
+            message: Message,
         ) {
             if INITIATE_ONCE.init_once.get().is_none() {
                 init(Some(Level::Warn), None);
@@ -240,17 +343,20 @@ macro_rules! create_log {
             let identifier_ref = log_identifier.as_ref();
             event!(
                 tracing::Level::$uppercase_level,
-                "{identifier_ref} - {message_ref}"
+                identifier = identifier_ref,
+                client_id = context.client_id,
+                connection_id = context.connection_id,
+                "{message_ref}"
             )
         }
     };
 }
 
-create_log!(log_trace, TRACE);
-create_log!(log_debug, DEBUG);
-create_log!(log_info, INFO);
-create_log!(log_warn, WARN);
-create_log!(log_error, ERROR);
+create_log!(log_trace, log_trace_with_context, TRACE);
+create_log!(log_debug, log_debug_with_context, DEBUG);
+create_log!(log_info, log_info_with_context, INFO);
+create_log!(log_warn, log_warn_with_context, WARN);
+create_log!(log_error, log_error_with_context, ERROR);
 
 // Logs the given log, with log_identifier and log level prefixed. If the given log level is below the threshold of given when the logger was initialized, the log will be ignored.
 // log_identifier should be used to add context to a log, and make it easier to connect it to other relevant logs. For example, it can be used to pass a task identifier.
@@ -260,13 +366,23 @@ pub fn log<Message: AsRef<str>, Identifier: AsRef<str>>(
     log_level: Level,
     log_identifier: Identifier,
     message: Message,
+) {
+    log_with_context(log_level, LogContext::default(), log_identifier, message)
+}
+
+// Like [log], adding the IDs of the given context to the log as fields.
+pub fn log_with_context<Message: AsRef<str>, Identifier: AsRef<str>>(
+    log_level: Level,
+    context: LogContext,
+    log_identifier: Identifier,
+    message: Message,
 ) {
     match log_level {
-        Level::Debug => log_debug(log_identifier, message),
-        Level::Trace => log_trace(log_identifier, message),
-        Level::Info => log_info(log_identifier, message),
-        Level::Warn => log_warn(log_identifier, message),
-        Level::Error => log_error(log_identifier, message),
+        Level::Debug => log_debug_with_context(context, log_identifier, message),
+        Level::Trace => log_trace_with_context(context, log_identifier, message),
+        Level::Info => log_info_with_context(context, log_identifier, message),
+        Level::Warn => log_warn_with_context(context, log_identifier, message),
+        Level::Error => log_error_with_context(context, log_identifier, message),
         Level::Off => (),
     }
 }
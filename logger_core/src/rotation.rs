@@ -0,0 +1,298 @@
+/**
+ * Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+ */
+use file_rotate::compression::Compression;
+use file_rotate::suffix::{AppendTimestamp, FileLimit};
+use file_rotate::{ContentLimit, FileRotate, TimeFrequency};
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+use tracing_appender::rolling::{RollingFileAppender, RollingWriter, Rotation};
+
+/// When the log file is rotated, closing it and writing the next logs to a new file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogRotation {
+    /// At the start of every hour.
+    #[default]
+    Hourly,
+    /// At the start of every day.
+    Daily,
+    /// Once the given interval has passed since the file was opened.
+    Interval(Duration),
+    /// Once the file grows beyond the given number of bytes.
+    Size(u64),
+}
+
+/// The rotation and retention of log files.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LogFileConfig {
+    pub rotation: LogRotation,
+    /// The maximum number of log files kept, including the one being written, after which the oldest
+    /// files are deleted. `None` keeps all the files.
+    pub max_files: Option<usize>,
+    /// Whether rotated files are compressed with gzip.
+    pub compress: bool,
+}
+
+impl LogFileConfig {
+    /// Checks that the interval of [LogRotation::Interval] and the size of [LogRotation::Size] aren't zero,
+    /// as the file would then be rotated on every write.
+    pub fn validate(&self) -> Result<(), String> {
+        match self.rotation {
+            LogRotation::Interval(interval) if interval.is_zero() => {
+                Err("The interval of the log rotation must be positive".to_string())
+            }
+            LogRotation::Size(0) => {
+                Err("The size of the log rotation must be positive".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Writes the logs to files rotated as configured by a [LogFileConfig].
+///
+/// Files rotated hourly or daily without compression are named `prefix.date`, by a [RollingFileAppender].
+/// Other files are written to `prefix`, and renamed `prefix.timestamp` (or `prefix.timestamp.gz`) when rotated.
+pub(crate) enum FileAppender {
+    Rolling(RollingFileAppender),
+    Rotating(Mutex<RotatingFile>),
+}
+
+impl FileAppender {
+    /// Expects a valid `config`, see [LogFileConfig::validate].
+    pub(crate) fn new(config: LogFileConfig, directory: &Path, filename_prefix: &Path) -> Self {
+        let max_files = config.max_files.map(|max_files| max_files.max(1));
+        let rolling_rotation = match config.rotation {
+            LogRotation::Hourly if !config.compress => Some(Rotation::HOURLY),
+            LogRotation::Daily if !config.compress => Some(Rotation::DAILY),
+            _ => None,
+        };
+        if let Some(rotation) = rolling_rotation {
+            let mut builder = RollingFileAppender::builder()
+                .rotation(rotation)
+                .filename_prefix(filename_prefix.to_string_lossy());
+            if let Some(max_files) = max_files {
+                builder = builder.max_log_files(max_files);
+            }
+            return FileAppender::Rolling(
+                builder
+                    .build(directory)
+                    .expect("initializing rolling file appender failed"),
+            );
+        }
+
+        let content_limit = match config.rotation {
+            LogRotation::Hourly => ContentLimit::Time(TimeFrequency::Hourly),
+            LogRotation::Daily => ContentLimit::Time(TimeFrequency::Daily),
+            LogRotation::Interval(_) => ContentLimit::None,
+            LogRotation::Size(bytes) => ContentLimit::BytesSurpassed(bytes as usize),
+        };
+        // The limit of file-rotate doesn't count the file being written
+        let file_limit = match max_files {
+            Some(max_files) => FileLimit::MaxFiles(max_files - 1),
+            None => FileLimit::Unlimited,
+        };
+        let compression = match config.compress {
+            true => Compression::OnRotate(0),
+            false => Compression::None,
+        };
+        let file = FileRotate::new(
+            directory.join(filename_prefix),
+            AppendTimestamp::default(file_limit),
+            content_limit,
+            compression,
+            #[cfg(unix)]
+            None,
+        );
+        let interval = match config.rotation {
+            LogRotation::Interval(interval) => Some(interval),
+            _ => None,
+        };
+        FileAppender::Rotating(Mutex::new(RotatingFile {
+            file,
+            interval,
+            next_rotation: interval.map(|interval| Instant::now() + interval),
+        }))
+    }
+
+    pub(crate) fn make_writer(&self) -> FileWriter<'_> {
+        match self {
+            FileAppender::Rolling(appender) => {
+                FileWriter::Rolling(tracing_subscriber::fmt::MakeWriter::make_writer(appender))
+            }
+            FileAppender::Rotating(file) => {
+                FileWriter::Rotating(file.lock().unwrap_or_else(PoisonError::into_inner))
+            }
+        }
+    }
+}
+
+/// A file rotated by size or time, which also rotates once its custom interval has passed.
+pub(crate) struct RotatingFile {
+    file: FileRotate<AppendTimestamp>,
+    interval: Option<Duration>,
+    next_rotation: Option<Instant>,
+}
+
+impl Write for RotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if let (Some(interval), Some(next_rotation)) = (self.interval, self.next_rotation)
+            && Instant::now() >= next_rotation
+        {
+            self.file.rotate()?;
+            self.next_rotation = Some(Instant::now() + interval);
+        }
+        self.file.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+pub(crate) enum FileWriter<'a> {
+    Rolling(RollingWriter<'a>),
+    Rotating(MutexGuard<'a, RotatingFile>),
+}
+
+impl Write for FileWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            FileWriter::Rolling(writer) => writer.write(buf),
+            FileWriter::Rotating(file) => file.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            FileWriter::Rolling(writer) => writer.flush(),
+            FileWriter::Rotating(file) => file.flush(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{read_dir, remove_dir_all};
+    use std::path::PathBuf;
+
+    fn test_directory(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("glide-logs-{name}"));
+        let _ = remove_dir_all(&directory);
+        directory
+    }
+
+    fn file_names(directory: &Path) -> Vec<String> {
+        let mut names: Vec<_> = read_dir(directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_size_rotation_keeps_max_files_compressed() {
+        let directory = test_directory("size");
+        let appender = FileAppender::new(
+            LogFileConfig {
+                rotation: LogRotation::Size(10),
+                max_files: Some(3),
+                compress: true,
+            },
+            &directory,
+            Path::new("output.log"),
+        );
+        for _ in 0..5 {
+            appender.make_writer().write_all(b"0123456789\n").unwrap();
+        }
+        let names = file_names(&directory);
+        assert_eq!(names.len(), 3, "Files: {names:?}");
+        assert!(
+            names.contains(&"output.log".to_string()),
+            "Files: {names:?}"
+        );
+        assert!(
+            names
+                .iter()
+                .filter(|name| *name != "output.log")
+                .all(|name| name.starts_with("output.log.") && name.ends_with(".gz")),
+            "Files: {names:?}"
+        );
+        remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_interval_rotation() {
+        let directory = test_directory("interval");
+        let appender = FileAppender::new(
+            LogFileConfig {
+                rotation: LogRotation::Interval(Duration::from_millis(100)),
+                max_files: None,
+                compress: false,
+            },
+            &directory,
+            Path::new("output.log"),
+        );
+        appender.make_writer().write_all(b"first\n").unwrap();
+        appender.make_writer().write_all(b"second\n").unwrap();
+        assert_eq!(file_names(&directory), vec!["output.log".to_string()]);
+        std::thread::sleep(Duration::from_millis(150));
+        appender.make_writer().write_all(b"third\n").unwrap();
+        let names = file_names(&directory);
+        assert_eq!(names.len(), 2, "Files: {names:?}");
+        assert_eq!(
+            std::fs::read_to_string(directory.join("output.log")).unwrap(),
+            "third\n"
+        );
+        remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_validate_rejects_zero_interval_and_size() {
+        let config = |rotation| LogFileConfig {
+            rotation,
+            ..Default::default()
+        };
+        assert!(
+            config(LogRotation::Interval(Duration::ZERO))
+                .validate()
+                .is_err()
+        );
+        assert!(config(LogRotation::Size(0)).validate().is_err());
+        assert!(
+            config(LogRotation::Interval(Duration::from_secs(1)))
+                .validate()
+                .is_ok()
+        );
+        assert!(config(LogRotation::Size(1)).validate().is_ok());
+        assert!(config(LogRotation::Daily).validate().is_ok());
+    }
+
+    #[test]
+    fn test_daily_rotation_names_files_by_date() {
+        let directory = test_directory("daily");
+        let appender = FileAppender::new(
+            LogFileConfig {
+                rotation: LogRotation::Daily,
+                max_files: Some(2),
+                compress: false,
+            },
+            &directory,
+            Path::new("output.log"),
+        );
+        appender.make_writer().write_all(b"log\n").unwrap();
+        let names = file_names(&directory);
+        assert_eq!(names.len(), 1, "Files: {names:?}");
+        // The files of daily rotation are named `prefix.YYYY-MM-DD`
+        assert_eq!(
+            names[0].len(),
+            "output.log.YYYY-MM-DD".len(),
+            "Files: {names:?}"
+        );
+        remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,60 @@
+/**
+ * Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
+ */
+use crate::Level;
+use crate::format::LogFields;
+use std::sync::Arc;
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+
+/// A log passed to a [LogSink].
+#[derive(Debug)]
+pub struct LogRecord<'a> {
+    pub level: Level,
+    /// The module that wrote the log.
+    pub target: &'a str,
+    pub identifier: &'a str,
+    pub message: &'a str,
+    /// The fields of the log's [crate::LogContext], or of the events of other crates, as `(name, value)`.
+    pub fields: &'a [(&'static str, String)],
+}
+
+/// Receives the logs, to pass them to the logger of the host language instead of writing them to the console or to files.
+///
+/// The sink is called synchronously on the thread that writes the log, so it should hand logs off quickly,
+/// and must not log through `logger_core` itself.
+pub trait LogSink: Send + Sync {
+    fn log(&self, record: &LogRecord<'_>);
+}
+
+impl<F> LogSink for F
+where
+    F: Fn(&LogRecord<'_>) + Send + Sync,
+{
+    fn log(&self, record: &LogRecord<'_>) {
+        self(record)
+    }
+}
+
+/// Passes the logs to the sink set by [crate::set_log_sink], if any.
+#[derive(Default)]
+pub(crate) struct SinkLayer {
+    pub(crate) sink: Option<Arc<dyn LogSink>>,
+}
+
+impl<S: Subscriber> Layer<S> for SinkLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let Some(sink) = &self.sink else {
+            return;
+        };
+        let metadata = event.metadata();
+        let log_fields = LogFields::from_fields(event);
+        sink.log(&LogRecord {
+            level: Level::from(*metadata.level()),
+            target: metadata.target(),
+            identifier: &log_fields.identifier,
+            message: &log_fields.message,
+            fields: &log_fields.fields,
+        });
+    }
+}
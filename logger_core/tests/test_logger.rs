@@ -7,11 +7,16 @@ use test_env_helpers::*;
 #[after_all]
 #[before_all]
 mod tests {
-    use logger_core::{init, log_debug, log_trace};
+    use logger_core::{
+        Level, LogContext, LogFileConfig, LogFormat, LogRecord, LogRotation, init,
+        init_with_file_config, init_with_format, log_debug, log_debug_with_context, log_trace,
+        set_log_sink,
+    };
     use rand::{Rng, distributions::Alphanumeric};
     use std::{
         fs::{read_dir, read_to_string, remove_dir_all},
         path::Path,
+        sync::{Arc, Mutex},
     };
     const FILE_DIRECTORY: &str = "glide-logs";
 
@@ -117,6 +122,96 @@ mod tests {
         assert!(!contents.contains("boo"), "Contents: {contents}");
     }
 
+    #[test]
+    fn log_to_file_in_json_format() {
+        let identifier = generate_random_string(10);
+        init_with_format(
+            Some(logger_core::Level::Debug),
+            Some(identifier.as_str()),
+            LogFormat::Json,
+        );
+        log_debug_with_context(
+            LogContext {
+                client_id: Some(1),
+                connection_id: None,
+            },
+            identifier.clone(),
+            "foo",
+        );
+        let contents = get_file_contents(identifier.as_str());
+        assert!(
+            contents.contains(&format!(
+                "\"identifier\":\"{identifier}\",\"message\":\"foo\",\"client_id\":\"1\"}}"
+            )),
+            "Contents: {contents}"
+        );
+        init(Some(logger_core::Level::Debug), Some(identifier.as_str()));
+    }
+
+    #[test]
+    fn log_to_file_works_after_init_with_invalid_file_config() {
+        let identifier = generate_random_string(10);
+        init(Some(logger_core::Level::Debug), Some(identifier.as_str()));
+        let other_identifier = generate_random_string(10);
+        let result = init_with_file_config(
+            Some(logger_core::Level::Trace),
+            Some(other_identifier.as_str()),
+            LogFormat::Json,
+            LogFileConfig {
+                rotation: LogRotation::Size(0),
+                ..Default::default()
+            },
+        );
+        assert!(result.is_err(), "Result: {result:?}");
+        log_debug(identifier.clone(), "foo");
+        log_trace(identifier.clone(), "boo");
+        let contents = get_file_contents(identifier.as_str());
+        assert!(contents.contains("foo"), "Contents: {contents}");
+        assert!(!contents.contains("boo"), "Contents: {contents}");
+    }
+
+    #[test]
+    fn log_to_sink_works_until_sink_is_removed() {
+        let identifier = generate_random_string(10);
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let sink_records = records.clone();
+        let sink_identifier = identifier.clone();
+        set_log_sink(
+            Some(Level::Debug),
+            Some(Arc::new(move |record: &LogRecord<'_>| {
+                if record.identifier == sink_identifier {
+                    sink_records.lock().unwrap().push((
+                        record.level,
+                        record.message.to_string(),
+                        record.fields.to_vec(),
+                    ));
+                }
+            })),
+        );
+        log_debug_with_context(
+            LogContext {
+                client_id: Some(1),
+                connection_id: Some(2),
+            },
+            identifier.clone(),
+            "foo",
+        );
+        log_trace(identifier.clone(), "boo");
+        set_log_sink(None, None);
+        log_debug(identifier, "bar");
+        assert_eq!(
+            *records.lock().unwrap(),
+            vec![(
+                Level::Debug,
+                "foo".to_string(),
+                vec![
+                    ("client_id", "1".to_string()),
+                    ("connection_id", "2".to_string())
+                ]
+            )]
+        );
+    }
+
     fn clean() -> Result<(), std::io::Error> {
         remove_dir_all(FILE_DIRECTORY)
     }
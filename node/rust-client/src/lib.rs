@@ -23,15 +23,18 @@ use glide_core::start_socket_listener;
 use napi::bindgen_prelude::BigInt;
 use napi::bindgen_prelude::Either;
 use napi::bindgen_prelude::Uint8Array;
-use napi::{Env, Error, JsObject, JsUnknown, Result, Status};
+use napi::threadsafe_function::{
+    ErrorStrategy, ThreadSafeCallContext, ThreadsafeFunction, ThreadsafeFunctionCallMode,
+};
+use napi::{Env, Error, JsFunction, JsObject, JsUnknown, Result, Status};
 use napi_derive::napi;
 use num_traits::sign::Signed;
 use redis::{AsyncCommands, Value, aio::MultiplexedConnection};
-#[cfg(feature = "testing_utilities")]
 use std::collections::HashMap;
 use std::ptr::from_mut;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::runtime::{Builder, Runtime};
 #[napi]
 pub enum Level {
@@ -43,6 +46,77 @@ pub enum Level {
     Off = 5,
 }
 
+/// When the log file is rotated, see [LogFileConfig].
+#[napi]
+pub enum LogRotation {
+    Hourly = 0,
+    Daily = 1,
+    Interval = 2,
+    Size = 3,
+}
+
+/// The format of the logs written to the console or to files.
+#[napi]
+pub enum LogFormat {
+    Text = 0,
+    Json = 1,
+}
+
+impl From<LogFormat> for logger_core::LogFormat {
+    fn from(format: LogFormat) -> Self {
+        match format {
+            LogFormat::Text => logger_core::LogFormat::Text,
+            LogFormat::Json => logger_core::LogFormat::Json,
+        }
+    }
+}
+
+/// A log passed to the callback set by `SetInternalLogCallback`.
+///
+/// - `fields`: The other fields of the log, such as the `client_id` and `connection_id` of its context.
+#[napi(object)]
+pub struct LogEntry {
+    pub level: Level,
+    pub identifier: String,
+    pub message: String,
+    pub fields: HashMap<String, String>,
+}
+
+/// Configuration of the rotation and retention of log files.
+///
+/// - `rotation`: When the log file is rotated. If `None`, the file is rotated hourly.
+/// - `rotation_value`: The interval in seconds of `Interval` rotation, or the maximum file size in bytes of `Size` rotation,
+///   which must be positive.
+/// - `max_files`: The maximum number of log files kept, including the one being written. If `None`, all the files are kept.
+/// - `compress`: Whether rotated files are compressed with gzip. If `None`, they aren't compressed.
+#[napi(object)]
+#[derive(Clone)]
+pub struct LogFileConfig {
+    pub rotation: Option<LogRotation>,
+    pub rotation_value: Option<i64>,
+    pub max_files: Option<u32>,
+    pub compress: Option<bool>,
+}
+
+impl From<LogFileConfig> for logger_core::LogFileConfig {
+    fn from(config: LogFileConfig) -> Self {
+        let rotation_value = config.rotation_value.unwrap_or_default().max(0) as u64;
+        let rotation = match config.rotation {
+            None | Some(LogRotation::Hourly) => logger_core::LogRotation::Hourly,
+            Some(LogRotation::Daily) => logger_core::LogRotation::Daily,
+            Some(LogRotation::Interval) => {
+                logger_core::LogRotation::Interval(Duration::from_secs(rotation_value))
+            }
+            Some(LogRotation::Size) => logger_core::LogRotation::Size(rotation_value),
+        };
+        logger_core::LogFileConfig {
+            rotation,
+            max_files: config.max_files.map(|max_files| max_files as usize),
+            compress: config.compress.unwrap_or_default(),
+        }
+    }
+}
+
 #[napi]
 pub const MAX_REQUEST_ARGS_LEN: u32 = MAX_REQUEST_ARGS_LENGTH as u32;
 
@@ -301,9 +375,55 @@ pub fn log(log_level: Level, log_identifier: String, message: String) {
 }
 
 #[napi(js_name = "InitInternalLogger")]
-pub fn init(level: Option<Level>, file_name: Option<&str>) -> Level {
-    let logger_level = logger_core::init(level.map(|level| level.into()), file_name);
-    logger_level.into()
+pub fn init(
+    level: Option<Level>,
+    file_name: Option<&str>,
+    file_config: Option<LogFileConfig>,
+    format: Option<LogFormat>,
+) -> Result<Level> {
+    let logger_level = logger_core::init_with_file_config(
+        level.map(|level| level.into()),
+        file_name,
+        format.map(Into::into).unwrap_or_default(),
+        file_config.map(Into::into).unwrap_or_default(),
+    )
+    .map_err(|err| Error::new(Status::InvalidArg, err))?;
+    Ok(logger_level.into())
+}
+
+/// Passes the logs of the given level or above to `callback`, in addition to the console or file set by
+/// `InitInternalLogger`. The callback is called on the JS thread with a [LogEntry] for each log, and doesn't keep
+/// the process alive. Passing no callback stops passing the logs to the previous callback.
+#[napi(js_name = "SetInternalLogCallback")]
+pub fn set_log_callback(
+    env: Env,
+    level: Option<Level>,
+    callback: Option<JsFunction>,
+) -> Result<Level> {
+    let sink = match callback {
+        Some(callback) => {
+            let mut callback: ThreadsafeFunction<LogEntry, ErrorStrategy::Fatal> = callback
+                .create_threadsafe_function(0, |ctx: ThreadSafeCallContext<LogEntry>| {
+                    Ok(vec![ctx.value])
+                })?;
+            callback.unref(&env)?;
+            Some(Arc::new(move |record: &logger_core::LogRecord<'_>| {
+                let entry = LogEntry {
+                    level: record.level.into(),
+                    identifier: record.identifier.to_string(),
+                    message: record.message.to_string(),
+                    fields: record
+                        .fields
+                        .iter()
+                        .map(|(name, value)| (name.to_string(), value.clone()))
+                        .collect(),
+                };
+                callback.call(entry, ThreadsafeFunctionCallMode::NonBlocking);
+            }) as Arc<dyn logger_core::LogSink>)
+        }
+        None => None,
+    };
+    Ok(logger_core::set_log_sink(level.map(|level| level.into()), sink).into())
 }
 
 fn resp_value_to_js(val: Value, js_env: Env, string_decoder: bool) -> Result<JsUnknown> {
@@ -584,6 +584,9 @@ export type ReadFrom =
     /** Spread the requests between all replicas in a round robin manner.
         If no replica is available, route the requests to the primary.*/
     | "preferReplica"
+    /** Route the requests to the replica with the lowest measured round-trip latency.
+        If no replica is available, route the requests to the primary.*/
+    | "lowestLatency"
     /** Spread the requests between replicas in the same client's Aviliablity zone in a round robin manner.
         If no replica is available, route the requests to the primary.*/
     | "AZAffinity"
@@ -922,6 +925,22 @@ export interface AdvancedBaseClientConfiguration {
          * - This is useful when connecting to servers with self-signed certificates or custom certificate authorities.
          */
         rootCertificates?: string | Buffer;
+
+        /**
+         * Client certificate chain for mutual TLS (mTLS), in PEM format as a string or Buffer.
+         *
+         * - Must be provided together with {@link clientKey}, otherwise a `ConfigurationError` will be thrown.
+         *
+         * - The key must match the certificate, otherwise client creation fails.
+         */
+        clientCertificate?: string | Buffer;
+
+        /**
+         * Private key of the client certificate, in PEM format as a string or Buffer.
+         *
+         * - Must be provided together with {@link clientCertificate}.
+         */
+        clientKey?: string | Buffer;
     };
 }
 
@@ -7437,6 +7456,7 @@ export class BaseClient {
     > = {
         primary: connection_request.ReadFrom.Primary,
         preferReplica: connection_request.ReadFrom.PreferReplica,
+        lowestLatency: connection_request.ReadFrom.LowestLatency,
         AZAffinity: connection_request.ReadFrom.AZAffinity,
         AZAffinityReplicasAndPrimary:
             connection_request.ReadFrom.AZAffinityReplicasAndPrimary,
@@ -9232,6 +9252,26 @@ export class BaseClient {
                         : options.tlsAdvancedConfiguration.rootCertificates;
                 request.rootCerts = [new Uint8Array(certData)];
             }
+
+            const { clientCertificate, clientKey } =
+                options.tlsAdvancedConfiguration;
+
+            if (!clientCertificate !== !clientKey) {
+                throw new ConfigurationError(
+                    "clientCertificate and clientKey must be provided together.",
+                );
+            }
+
+            if (clientCertificate && clientKey) {
+                const toBytes = (data: string | Buffer) =>
+                    new Uint8Array(
+                        typeof data === "string"
+                            ? Buffer.from(data, "utf-8")
+                            : data,
+                    );
+                request.clientCert = toBytes(clientCertificate);
+                request.clientKey = toBytes(clientKey);
+            }
         }
     }
 
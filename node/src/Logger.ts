@@ -2,7 +2,15 @@
  * Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
  */
 
-import { InitInternalLogger, Level, log } from ".";
+import {
+    InitInternalLogger,
+    Level,
+    LogEntry,
+    LogFileConfig,
+    LogFormat,
+    SetInternalLogCallback,
+    log,
+} from ".";
 
 const LEVEL = new Map<LevelOptions | undefined, Level | undefined>([
     ["error", Level.Error],
@@ -33,8 +41,18 @@ export class Logger {
     private static _instance: Logger;
     private static logger_level = 0;
 
-    private constructor(level?: LevelOptions, fileName?: string) {
-        Logger.logger_level = InitInternalLogger(LEVEL.get(level), fileName);
+    private constructor(
+        level?: LevelOptions,
+        fileName?: string,
+        fileConfig?: LogFileConfig,
+        format?: LogFormat,
+    ) {
+        Logger.logger_level = InitInternalLogger(
+            LEVEL.get(level),
+            fileName,
+            fileConfig,
+            format,
+        );
     }
 
     /**
@@ -76,10 +94,19 @@ export class Logger {
      *   To turn off logging completely, set the level to level "off".
      * @param fileName - If provided the target of the logs will be the file mentioned.
      *   Otherwise, logs will be printed to the console.
+     * @param fileConfig - The rotation and retention of the log files, used when `fileName` is provided.
+     *   If not provided, the files are rotated hourly and all of them are kept.
+     * @param format - The format of the logs, one of [Text, Json]. If not provided, the logs are written as text.
+     * @throws If the `rotationValue` of `Interval` or `Size` rotation is 0, in which case the logger isn't changed.
      */
-    public static init(level?: LevelOptions, fileName?: string) {
+    public static init(
+        level?: LevelOptions,
+        fileName?: string,
+        fileConfig?: LogFileConfig,
+        format?: LogFormat,
+    ) {
         if (!this._instance) {
-            this._instance = new this(level, fileName);
+            this._instance = new this(level, fileName, fileConfig, format);
         }
     }
 
@@ -88,8 +115,32 @@ export class Logger {
      *
      * @param level - Set the logger level to one of [ERROR, WARN, INFO, DEBUG, TRACE, OFF].
      * @param fileName - The target of the logs will be the file mentioned.
+     * @param fileConfig - The rotation and retention of the log files, used when `fileName` is provided.
+     * @param format - The format of the logs, one of [Text, Json]. If not provided, the logs are written as text.
+     * @throws If the `rotationValue` of `Interval` or `Size` rotation is 0, in which case the logger isn't changed.
      */
-    public static setLoggerConfig(level: LevelOptions, fileName?: string) {
-        this._instance = new this(level, fileName);
+    public static setLoggerConfig(
+        level: LevelOptions,
+        fileName?: string,
+        fileConfig?: LogFileConfig,
+        format?: LogFormat,
+    ) {
+        this._instance = new this(level, fileName, fileConfig, format);
+    }
+
+    /**
+     * Passes the logs of the given level or above to the callback, in addition to the console or file of the logger.
+     * This allows forwarding the logs of the internal GLIDE core to the logging library of the application.
+     * Calling this method without a callback stops passing the logs to the previous callback.
+     *
+     * @param level - The minimal level of the logs passed to the callback. If not provided, "warn" is used.
+     * @param callback - Called with the level, identifier, message and other fields of each log, such as the
+     *   `client_id` and `connection_id` of the client and connection that wrote it.
+     */
+    public static setLogCallback(
+        level?: LevelOptions,
+        callback?: (entry: LogEntry) => void,
+    ) {
+        SetInternalLogCallback(LEVEL.get(level), callback);
     }
 }
@@ -3,6 +3,7 @@
  */
 
 import { describe, expect, it } from "@jest/globals";
+import { Level, LogEntry, Logger, LogRotation, log } from "../build-ts";
 import { compareMaps } from "./TestUtilities";
 
 describe("test compareMaps", () => {
@@ -102,3 +103,43 @@ describe("test compareMaps", () => {
         expect(compareMaps(map1, map2)).toBe(false);
     });
 });
+
+describe("test Logger", () => {
+    it.each([LogRotation.Interval, LogRotation.Size])(
+        "Zero rotation value is rejected for rotation %p",
+        (rotation) => {
+            expect(() =>
+                Logger.setLoggerConfig("info", "zero_rotation.log", {
+                    rotation,
+                    rotationValue: 0,
+                }),
+            ).toThrow("must be positive");
+        },
+    );
+
+    it("Log callback receives the logs of its level", async () => {
+        const received: LogEntry[] = [];
+        Logger.setLogCallback("info", (entry) => {
+            if (entry.identifier === "callback_test") {
+                received.push(entry);
+            }
+        });
+        // Logs directly to the core, as `Logger.log` filters the logs below the level of the logger.
+        log(Level.Info, "callback_test", "info message");
+        log(Level.Debug, "callback_test", "debug message");
+        // The callback is called asynchronously on the JS thread.
+        await new Promise((resolve) => setTimeout(resolve, 100));
+        Logger.setLogCallback();
+        log(Level.Error, "callback_test", "message after reset");
+        await new Promise((resolve) => setTimeout(resolve, 100));
+
+        expect(received).toEqual([
+            {
+                level: Level.Info,
+                identifier: "callback_test",
+                message: "info message",
+                fields: {},
+            },
+        ]);
+    });
+});
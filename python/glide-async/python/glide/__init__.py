@@ -163,7 +163,7 @@ from glide_shared import (
 from .async_commands import ft, glide_json
 from .glide_client import GlideClient, GlideClusterClient, TGlideClient
 from .logger import Level as LogLevel
-from .logger import Logger
+from .logger import LogFileConfig, LogFormat, Logger, LogRotation
 
 _glide_module = sys.modules[__name__]
 
@@ -337,6 +337,9 @@ __all__ = [
     # Logger
     "Logger",
     "LogLevel",
+    "LogFileConfig",
+    "LogFormat",
+    "LogRotation",
     # Routes
     "Route",
     "SlotType",
@@ -1,6 +1,6 @@
 from collections.abc import Callable
 from enum import Enum
-from typing import List, Optional, Union
+from typing import Dict, List, Optional, Tuple, Union
 
 from glide_shared.constants import TResult
 
@@ -54,7 +54,32 @@ def value_from_pointer(pointer: int) -> TResult: ...
 def create_leaked_value(message: str) -> int: ...
 def create_leaked_bytes_vec(args_vec: List[bytes]) -> int: ...
 def get_statistics() -> dict: ...
-def py_init(level: Optional[Level], file_name: Optional[str]) -> Level: ...
+
+class LogRotation(Enum):
+    Hourly = 0
+    Daily = 1
+    Interval = 2
+    Size = 3
+
+class LogFormat(Enum):
+    Text = 0
+    Json = 1
+
+def py_init(
+    level: Optional[Level],
+    file_name: Optional[str],
+    rotation: Optional[LogRotation] = None,
+    rotation_value: int = 0,
+    max_files: Optional[int] = None,
+    compress: bool = False,
+    format: Optional[LogFormat] = None,
+) -> Level: ...
+class LogCallbackReceiver:
+    def recv(self) -> Optional[Tuple[Level, str, str, Dict[str, str]]]: ...
+
+def py_set_log_callback(
+    level: Optional[Level] = None, enabled: bool = False
+) -> Optional[LogCallbackReceiver]: ...
 def py_log(log_level: Level, log_identifier: str, message: str) -> None: ...
 def create_otel_span(name: str) -> int: ...
 def drop_otel_span(span_ptr: int) -> None: ...
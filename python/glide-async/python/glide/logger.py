@@ -2,12 +2,16 @@
 
 from __future__ import annotations
 
+import threading
 import traceback
+from dataclasses import dataclass
 from enum import Enum
-from typing import Optional
+from typing import Callable, Dict, Optional
 
 from glide.glide import Level as internalLevel
-from glide.glide import py_init, py_log
+from glide.glide import LogFormat as internalLogFormat
+from glide.glide import LogRotation as internalLogRotation
+from glide.glide import py_init, py_log, py_set_log_callback
 
 
 class Level(Enum):
@@ -19,6 +23,52 @@ class Level(Enum):
     OFF = internalLevel.Off
 
 
+class LogRotation(Enum):
+    """
+    When the log file is rotated, closing it and writing the next logs to a new file.
+    """
+
+    HOURLY = internalLogRotation.Hourly
+    """ At the start of every hour. """
+    DAILY = internalLogRotation.Daily
+    """ At the start of every day. """
+    INTERVAL = internalLogRotation.Interval
+    """ Every `rotation_value` seconds. """
+    SIZE = internalLogRotation.Size
+    """ Once the file grows beyond `rotation_value` bytes. """
+
+
+class LogFormat(Enum):
+    """
+    The format of the logs written to the console or to a file.
+    """
+
+    TEXT = internalLogFormat.Text
+    """ Human-readable lines. """
+    JSON = internalLogFormat.Json
+    """ JSON lines, with the timestamp, level, target, identifier, message and context of each log as fields. """
+
+
+@dataclass
+class LogFileConfig:
+    """
+    The rotation and retention of log files.
+
+    Attributes:
+        rotation (LogRotation): When the log file is rotated. Defaults to LogRotation.HOURLY.
+        rotation_value (int): The interval in seconds of LogRotation.INTERVAL, or the maximum file size in bytes
+            of LogRotation.SIZE, which must be positive. Ignored by other rotations.
+        max_files (Optional[int]): The maximum number of log files kept, including the one being written, after which
+            the oldest files are deleted. If not provided, all the files are kept.
+        compress (bool): Whether rotated files are compressed with gzip. Defaults to False.
+    """
+
+    rotation: LogRotation = LogRotation.HOURLY
+    rotation_value: int = 0
+    max_files: Optional[int] = None
+    compress: bool = False
+
+
 class Logger:
     """
     A singleton class that allows logging which is consistent with logs from the internal GLIDE core.
@@ -30,14 +80,36 @@ class Logger:
     """
 
     _instance = None
+    _log_callback_thread: Optional[threading.Thread] = None
     logger_level: internalLevel
 
-    def __init__(self, level: Optional[Level] = None, file_name: Optional[str] = None):
+    def __init__(
+        self,
+        level: Optional[Level] = None,
+        file_name: Optional[str] = None,
+        file_config: Optional[LogFileConfig] = None,
+        format: LogFormat = LogFormat.TEXT,
+    ):
         level_value = level.value if level else None
-        Logger.logger_level = py_init(level_value, file_name)
+        file_config = file_config or LogFileConfig()
+        Logger.logger_level = py_init(
+            level_value,
+            file_name,
+            file_config.rotation.value,
+            file_config.rotation_value,
+            file_config.max_files,
+            file_config.compress,
+            format.value,
+        )
 
     @classmethod
-    def init(cls, level: Optional[Level] = None, file_name: Optional[str] = None):
+    def init(
+        cls,
+        level: Optional[Level] = None,
+        file_name: Optional[str] = None,
+        file_config: Optional[LogFileConfig] = None,
+        format: LogFormat = LogFormat.TEXT,
+    ):
         """
         Initialize a logger if it wasn't initialized before - this method is meant to be used when there is no intention to
         replace an existing logger. Otherwise, use `set_logger_config` for overriding the existing logger configs.
@@ -51,9 +123,15 @@ class Logger:
                 To turn off logging completely, set the level to Level.OFF.
             file_name (Optional[str]): If provided the target of the logs will be the file mentioned.
                 Otherwise, logs will be printed to the console.
+            file_config (Optional[LogFileConfig]): The rotation and retention of the log files, used when file_name
+                is provided. If not provided, the files are rotated hourly and all of them are kept.
+            format (LogFormat): The format of the logs. Defaults to LogFormat.TEXT.
+
+        Raises:
+            ValueError: If the rotation_value of LogRotation.INTERVAL or LogRotation.SIZE is 0.
         """
         if cls._instance is None:
-            cls._instance = cls(level, file_name)
+            cls._instance = cls(level, file_name, file_config, format)
 
     @classmethod
     def log(
@@ -82,7 +160,11 @@ class Logger:
 
     @classmethod
     def set_logger_config(
-        cls, level: Optional[Level] = None, file_name: Optional[str] = None
+        cls,
+        level: Optional[Level] = None,
+        file_name: Optional[str] = None,
+        file_config: Optional[LogFileConfig] = None,
+        format: LogFormat = LogFormat.TEXT,
     ):
         """
         Creates a new logger instance and configure it with the provided log level and file name.
@@ -93,5 +175,59 @@ class Logger:
                 To turn off logging completely, set the level to OFF.
             file_name (Optional[str]): If provided the target of the logs will be the file mentioned.
                 Otherwise, logs will be printed to the console.
+            file_config (Optional[LogFileConfig]): The rotation and retention of the log files, used when file_name
+                is provided.
+            format (LogFormat): The format of the logs. Defaults to LogFormat.TEXT.
+
+        Raises:
+            ValueError: If the rotation_value of LogRotation.INTERVAL or LogRotation.SIZE is 0.
         """
-        Logger._instance = Logger(level, file_name)
+        Logger._instance = Logger(level, file_name, file_config, format)
+
+    @classmethod
+    def set_log_callback(
+        cls,
+        level: Optional[Level] = None,
+        callback: Optional[Callable[[Level, str, str, Dict[str, str]], None]] = None,
+    ):
+        """
+        Passes the logs of the given level or above to `callback`, in addition to the console or file of the logger,
+        for example to forward them to the `logging` module. To pass the logs only to the callback, configure the
+        logger with Level.OFF.
+
+        The callback is called with the level, identifier and message of each log, and a dict of its other fields,
+        such as the `client_id` and `connection_id` of the client and connection that wrote it. It's called on a
+        dedicated thread, in the order the logs were written, and must not log through the Logger. Logs are dropped
+        while too many logs wait for a slow callback.
+
+        Args:
+            level (Optional[Level]): The minimal level of the logs passed to the callback. Defaults to Level.WARN.
+            callback (Optional[Callable[[Level, str, str, Dict[str, str]], None]]): The callback that receives the logs.
+                If not provided, the logs stop being passed to the previous callback, once it received the logs
+                written before.
+        """
+        receiver = py_set_log_callback(
+            level.value if level else None, callback is not None
+        )
+        # The previous receiver was closed, so its thread exits once it passed the remaining logs to the callback.
+        previous_thread, cls._log_callback_thread = cls._log_callback_thread, None
+        if (
+            previous_thread is not None
+            and previous_thread is not threading.current_thread()
+        ):
+            previous_thread.join()
+        if receiver is None or callback is None:
+            return
+
+        def pass_logs_to_callback():
+            while (record := receiver.recv()) is not None:
+                internal_level, identifier, message, fields = record
+                try:
+                    callback(Level(internal_level), identifier, message, fields)
+                except Exception:
+                    traceback.print_exc()
+
+        cls._log_callback_thread = threading.Thread(
+            target=pass_logs_to_callback, name="glide-log-callback", daemon=True
+        )
+        cls._log_callback_thread.start()
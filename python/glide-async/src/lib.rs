@@ -12,7 +12,7 @@ use glide_core::{
     GlideOpenTelemetrySignalsExporter, GlideSpan,
 };
 use pyo3::Python;
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::{PyAny, PyBool, PyBytes, PyDict, PyFloat, PyList, PySet, PyString};
 use redis::Value;
@@ -20,6 +20,7 @@ use std::collections::HashMap;
 use std::ptr::from_mut;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 
 pub const DEFAULT_TIMEOUT_IN_MILLISECONDS: u32 =
     glide_core::client::DEFAULT_RESPONSE_TIMEOUT.as_millis() as u32;
@@ -152,6 +153,24 @@ pub enum Level {
     Off = 5,
 }
 
+/// When the log file is rotated, see [init].
+#[pyclass(eq, eq_int)]
+#[derive(PartialEq, Eq, Clone)]
+pub enum LogRotation {
+    Hourly = 0,
+    Daily = 1,
+    Interval = 2,
+    Size = 3,
+}
+
+/// The format of the logs written to the console or to a file, see [init].
+#[pyclass(eq, eq_int)]
+#[derive(PartialEq, Eq, Clone)]
+pub enum LogFormat {
+    Text = 0,
+    Json = 1,
+}
+
 #[allow(dead_code)]
 #[pymethods]
 impl Level {
@@ -232,6 +251,9 @@ impl Script {
 #[pymodule]
 fn glide(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<Level>()?;
+    m.add_class::<LogRotation>()?;
+    m.add_class::<LogFormat>()?;
+    m.add_class::<LogCallbackReceiver>()?;
     m.add_class::<Script>()?;
     m.add_class::<ClusterScanCursor>()?;
     m.add_class::<OpenTelemetryConfig>()?;
@@ -248,6 +270,7 @@ fn glide(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add("MAX_REQUEST_ARGS_LEN", MAX_REQUEST_ARGS_LEN)?;
     m.add_function(wrap_pyfunction!(py_log, m)?)?;
     m.add_function(wrap_pyfunction!(py_init, m)?)?;
+    m.add_function(wrap_pyfunction!(py_set_log_callback, m)?)?;
     m.add_function(wrap_pyfunction!(start_socket_listener_external, m)?)?;
     m.add_function(wrap_pyfunction!(value_from_pointer, m)?)?;
     m.add_function(wrap_pyfunction!(create_leaked_value, m)?)?;
@@ -290,9 +313,31 @@ fn glide(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     }
 
     #[pyfunction]
-    #[pyo3(signature = (level=None, file_name=None))]
-    fn py_init(level: Option<Level>, file_name: Option<&str>) -> Level {
-        init(level, file_name)
+    #[pyo3(signature = (level=None, file_name=None, rotation=None, rotation_value=0, max_files=None, compress=false, format=None))]
+    fn py_init(
+        level: Option<Level>,
+        file_name: Option<&str>,
+        rotation: Option<LogRotation>,
+        rotation_value: u64,
+        max_files: Option<usize>,
+        compress: bool,
+        format: Option<LogFormat>,
+    ) -> PyResult<Level> {
+        init(
+            level,
+            file_name,
+            rotation,
+            rotation_value,
+            max_files,
+            compress,
+            format,
+        )
+    }
+
+    #[pyfunction]
+    #[pyo3(signature = (level=None, enabled=false))]
+    fn py_set_log_callback(level: Option<Level>, enabled: bool) -> Option<LogCallbackReceiver> {
+        set_log_callback(level, enabled)
     }
     #[pyfunction]
     fn start_socket_listener_external(init_callback: PyObject) -> PyResult<PyObject> {
@@ -607,9 +652,98 @@ pub fn log(log_level: Level, log_identifier: String, message: String) {
     logger_core::log(log_level.into(), log_identifier, message);
 }
 
+/// Initializes the logger. When logging to a file, `rotation` sets when the file is rotated, hourly if not set,
+/// and `rotation_value` is the interval in seconds of `Interval` rotation, or the maximum file size in bytes of
+/// `Size` rotation. `max_files` limits the number of files kept, including the one being written, and `compress`
+/// compresses rotated files with gzip. The logs are written as text, unless `format` is `Json`.
+/// Raises a `ValueError`, without changing the logger, if `rotation_value` is 0 for `Interval` or `Size` rotation.
 #[pyfunction]
-#[pyo3(signature = (level=None, file_name=None))]
-pub fn init(level: Option<Level>, file_name: Option<&str>) -> Level {
-    let logger_level = logger_core::init(level.map(|level| level.into()), file_name);
-    logger_level.into()
+#[pyo3(signature = (level=None, file_name=None, rotation=None, rotation_value=0, max_files=None, compress=false, format=None))]
+pub fn init(
+    level: Option<Level>,
+    file_name: Option<&str>,
+    rotation: Option<LogRotation>,
+    rotation_value: u64,
+    max_files: Option<usize>,
+    compress: bool,
+    format: Option<LogFormat>,
+) -> PyResult<Level> {
+    let rotation = match rotation {
+        None | Some(LogRotation::Hourly) => logger_core::LogRotation::Hourly,
+        Some(LogRotation::Daily) => logger_core::LogRotation::Daily,
+        Some(LogRotation::Interval) => {
+            logger_core::LogRotation::Interval(Duration::from_secs(rotation_value))
+        }
+        Some(LogRotation::Size) => logger_core::LogRotation::Size(rotation_value),
+    };
+    let format = match format {
+        None | Some(LogFormat::Text) => logger_core::LogFormat::Text,
+        Some(LogFormat::Json) => logger_core::LogFormat::Json,
+    };
+    let logger_level = logger_core::init_with_file_config(
+        level.map(|level| level.into()),
+        file_name,
+        format,
+        logger_core::LogFileConfig {
+            rotation,
+            max_files,
+            compress,
+        },
+    )
+    .map_err(PyValueError::new_err)?;
+    Ok(logger_level.into())
+}
+
+/// The number of logs that can wait for the log callback before the next ones are dropped.
+const LOG_CALLBACK_QUEUE_SIZE: usize = 10_000;
+
+/// A log passed to the log callback: its level, identifier, message and other fields.
+type LogCallbackRecord = (Level, String, String, HashMap<String, String>);
+
+/// Receives the logs set by [set_log_callback] for the callback, which is called on a Python thread rather than on
+/// the threads that write the logs, since these may hold locks that a thread holding the GIL waits for.
+#[pyclass]
+pub struct LogCallbackReceiver {
+    receiver: std::sync::Mutex<std::sync::mpsc::Receiver<LogCallbackRecord>>,
+}
+
+#[pymethods]
+impl LogCallbackReceiver {
+    /// Waits without holding the GIL for the next log. Returns `None` once the callback is replaced or removed and
+    /// the previous logs were received.
+    fn recv(&self, py: Python) -> Option<LogCallbackRecord> {
+        py.allow_threads(|| self.receiver.lock().unwrap().recv().ok())
+    }
+}
+
+/// Passes the logs of the given level or above to the returned receiver, in addition to the console or file set by
+/// [init], if `enabled` is set. Otherwise, stops passing the logs to the previous receiver. Logs are dropped while
+/// [LOG_CALLBACK_QUEUE_SIZE] logs wait to be received, so that writing a log never waits for the callback.
+#[pyfunction]
+#[pyo3(signature = (level=None, enabled=false))]
+pub fn set_log_callback(level: Option<Level>, enabled: bool) -> Option<LogCallbackReceiver> {
+    let (sink, receiver) = if enabled {
+        let (sender, receiver) = std::sync::mpsc::sync_channel(LOG_CALLBACK_QUEUE_SIZE);
+        let sink = Arc::new(move |record: &logger_core::LogRecord<'_>| {
+            let fields = record
+                .fields
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.clone()))
+                .collect();
+            _ = sender.try_send((
+                Level::from(record.level),
+                record.identifier.to_string(),
+                record.message.to_string(),
+                fields,
+            ));
+        }) as Arc<dyn logger_core::LogSink>;
+        let receiver = LogCallbackReceiver {
+            receiver: std::sync::Mutex::new(receiver),
+        };
+        (Some(sink), Some(receiver))
+    } else {
+        (None, None)
+    };
+    logger_core::set_log_sink(level.map(|level| level.into()), sink);
+    receiver
 }
@@ -48,6 +48,11 @@ class ReadFrom(Enum):
     Spread the requests between all replicas in a round robin manner.
     If no replica is available, route the requests to the primary.
     """
+    LOWEST_LATENCY = ProtobufReadFrom.LowestLatency
+    """
+    Route the read requests to the replica with the lowest measured round-trip latency.
+    If no replica is available, route the requests to the primary.
+    """
     AZ_AFFINITY = ProtobufReadFrom.AZAffinity
     """
     Spread the read requests between replicas in the same client's AZ (Aviliablity zone) in a round robin manner,
@@ -266,15 +271,39 @@ class TlsAdvancedConfiguration:
                 # Or provide directly
                 cert_data = b"-----BEGIN CERTIFICATE-----\\n...\\n-----END CERTIFICATE-----"
                 tls_config = TlsAdvancedConfiguration(root_pem_cacerts=cert_data)
+
+        client_cert_pem (Optional[bytes]): Client certificate chain in PEM format, used for mutual TLS (mTLS).
+
+            - Must be provided together with `client_key_pem`, otherwise a `ConfigurationError` will be raised.
+
+            - The key must match the certificate, otherwise client creation fails.
+
+            - If None (default), the client doesn't authenticate itself with a certificate.
+
+        client_key_pem (Optional[bytes]): Private key of the client certificate in PEM format.
+
+            Example usage::
+
+                with open('/path/to/client.crt', 'rb') as f:
+                    client_cert = f.read()
+                with open('/path/to/client.key', 'rb') as f:
+                    client_key = f.read()
+                tls_config = TlsAdvancedConfiguration(
+                    client_cert_pem=client_cert, client_key_pem=client_key
+                )
     """
 
     def __init__(
         self,
         use_insecure_tls: Optional[bool] = None,
         root_pem_cacerts: Optional[bytes] = None,
+        client_cert_pem: Optional[bytes] = None,
+        client_key_pem: Optional[bytes] = None,
     ):
         self.use_insecure_tls = use_insecure_tls
         self.root_pem_cacerts = root_pem_cacerts
+        self.client_cert_pem = client_cert_pem
+        self.client_key_pem = client_key_pem
 
 
 class AdvancedBaseClientConfiguration:
@@ -324,6 +353,25 @@ class AdvancedBaseClientConfiguration:
                     )
                 request.root_certs.append(self.tls_config.root_pem_cacerts)
 
+            # Handle the client certificate for mTLS
+            client_cert = self.tls_config.client_cert_pem
+            client_key = self.tls_config.client_key_pem
+            if (client_cert is None) != (client_key is None):
+                raise ConfigurationError(
+                    "client_cert_pem and client_key_pem must be provided together."
+                )
+            if client_cert is not None and client_key is not None:
+                if len(client_cert) == 0 or len(client_key) == 0:
+                    raise ConfigurationError(
+                        "client_cert_pem and client_key_pem cannot be empty bytes objects."
+                    )
+                if request.tls_mode == TlsMode.NoTls:
+                    raise ConfigurationError(
+                        "A client certificate cannot be used when use_tls is disabled."
+                    )
+                request.client_cert = client_cert
+                request.client_key = client_key
+
         return request
 
 
@@ -151,7 +151,7 @@ from glide_shared import (
 from .config import GlideClientConfiguration, GlideClusterClientConfiguration
 from .glide_client import GlideClient, GlideClusterClient, TGlideClient
 from .logger import Level as LogLevel
-from .logger import Logger
+from .logger import LogFileConfig, LogFormat, Logger, LogRotation
 from .sync_commands import ft, glide_json
 from .sync_commands.cluster_scan_cursor import ClusterScanCursor
 from .sync_commands.script import Script
@@ -292,6 +292,9 @@ __all__ = [
     # Logger
     "Logger",
     "LogLevel",
+    "LogFileConfig",
+    "LogFormat",
+    "LogRotation",
     # Ft
     "DataType",
     "DistanceMetricType",
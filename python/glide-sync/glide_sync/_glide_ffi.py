@@ -264,8 +264,33 @@ class _GlideFFI:
                 int level;
             } LogResult;
 
+            typedef enum {
+                HOURLY = 0,
+                DAILY = 1,
+                INTERVAL = 2,
+                SIZE = 3
+            } LogRotation;
+
+            typedef struct {
+                LogRotation rotation;
+                uint64_t rotation_value;
+                size_t max_files;
+                bool compress;
+            } LogFileConfig;
+
+            typedef enum {
+                TEXT = 0,
+                JSON = 1
+            } LogFormat;
+
             LogResult* glide_log(int level, const char* identifier, const char* message);
             LogResult* init(const Level* level, const char* file_name);
+            LogResult* init_with_config(
+                const Level* level,
+                const char* file_name,
+                const LogFormat* format,
+                const LogFileConfig* file_config
+            );
             void free_log_result(LogResult* result_ptr);
 
             // ============== OPENTELEMETRY ==============
@@ -3,6 +3,7 @@
 from __future__ import annotations
 
 import traceback
+from dataclasses import dataclass
 from enum import Enum
 from pathlib import Path
 from typing import Optional, cast
@@ -25,6 +26,52 @@ class Level(Enum):
     OFF = 5
 
 
+class LogRotation(Enum):
+    """
+    When the log file is rotated, closing it and writing the next logs to a new file.
+    """
+
+    HOURLY = 0
+    """ At the start of every hour. """
+    DAILY = 1
+    """ At the start of every day. """
+    INTERVAL = 2
+    """ Every `rotation_value` seconds. """
+    SIZE = 3
+    """ Once the file grows beyond `rotation_value` bytes. """
+
+
+class LogFormat(Enum):
+    """
+    The format of the logs written to the console or to a file.
+    """
+
+    TEXT = 0
+    """ Human-readable lines. """
+    JSON = 1
+    """ JSON lines, with the timestamp, level, target, identifier, message and context of each log as fields. """
+
+
+@dataclass
+class LogFileConfig:
+    """
+    The rotation and retention of log files.
+
+    Attributes:
+        rotation (LogRotation): When the log file is rotated. Defaults to LogRotation.HOURLY.
+        rotation_value (int): The interval in seconds of LogRotation.INTERVAL, or the maximum file size in bytes
+            of LogRotation.SIZE, which must be positive. Ignored by other rotations.
+        max_files (Optional[int]): The maximum number of log files kept, including the one being written, after which
+            the oldest files are deleted. If not provided, all the files are kept.
+        compress (bool): Whether rotated files are compressed with gzip. Defaults to False.
+    """
+
+    rotation: LogRotation = LogRotation.HOURLY
+    rotation_value: int = 0
+    max_files: Optional[int] = None
+    compress: bool = False
+
+
 class Logger:
     """
     A singleton class that allows logging which is consistent with logs from the internal GLIDE core.
@@ -41,7 +88,14 @@ class Logger:
     _lib = _glide_ffi.lib
     logger_level: Level = Level.OFF
 
-    def __init__(self, level: Optional[Level] = None, file_name: Optional[str] = None):
+    def __init__(
+        self,
+        level: Optional[Level] = None,
+        file_name: Optional[str] = None,
+        file_config: Optional[LogFileConfig] = None,
+        format: LogFormat = LogFormat.TEXT,
+    ):
+        file_config = file_config or LogFileConfig()
         c_level = (
             Logger._ffi.new("Level*", level.value)
             if level is not None
@@ -53,7 +107,20 @@ class Logger:
             else Logger._ffi.NULL
         )
 
-        result_ptr = Logger._lib.init(c_level, c_file_name)
+        c_format = Logger._ffi.new("LogFormat*", format.value)
+        c_file_config = Logger._ffi.new(
+            "LogFileConfig*",
+            {
+                "rotation": file_config.rotation.value,
+                "rotation_value": file_config.rotation_value,
+                "max_files": file_config.max_files or 0,
+                "compress": file_config.compress,
+            },
+        )
+
+        result_ptr = Logger._lib.init_with_config(
+            c_level, c_file_name, c_format, c_file_config
+        )
 
         if result_ptr != Logger._ffi.NULL:
             try:
@@ -71,7 +138,13 @@ class Logger:
             raise LoggerError("Logger init received a null pointer")
 
     @classmethod
-    def init(cls, level: Optional[Level] = None, file_name: Optional[str] = None):
+    def init(
+        cls,
+        level: Optional[Level] = None,
+        file_name: Optional[str] = None,
+        file_config: Optional[LogFileConfig] = None,
+        format: LogFormat = LogFormat.TEXT,
+    ):
         """
         Initialize a logger if it wasn't initialized before - this method is meant to be used when there is no intention to
         replace an existing logger. Otherwise, use `set_logger_config` for overriding the existing logger configs.
@@ -85,9 +158,15 @@ class Logger:
                 To turn off logging completely, set the level to Level.OFF.
             file_name (Optional[str]): If provided the target of the logs will be the file mentioned.
                 Otherwise, logs will be printed to the console.
+            file_config (Optional[LogFileConfig]): The rotation and retention of the log files, used when file_name
+                is provided. If not provided, the files are rotated hourly and all of them are kept.
+            format (LogFormat): The format of the logs. Defaults to LogFormat.TEXT.
+
+        Raises:
+            LoggerError: If the rotation_value of LogRotation.INTERVAL or LogRotation.SIZE is 0.
         """
         if cls._instance is None:
-            cls._instance = cls(level, file_name)
+            cls._instance = cls(level, file_name, file_config, format)
 
     @classmethod
     def log(
@@ -154,7 +233,11 @@ class Logger:
 
     @classmethod
     def set_logger_config(
-        cls, level: Optional[Level] = None, file_name: Optional[str] = None
+        cls,
+        level: Optional[Level] = None,
+        file_name: Optional[str] = None,
+        file_config: Optional[LogFileConfig] = None,
+        format: LogFormat = LogFormat.TEXT,
     ):
         """
         Creates a new logger instance and configure it with the provided log level and file name.
@@ -165,5 +248,11 @@ class Logger:
                 To turn off logging completely, set the level to OFF.
             file_name (Optional[str]): If provided the target of the logs will be the file mentioned.
                 Otherwise, logs will be printed to the console.
+            file_config (Optional[LogFileConfig]): The rotation and retention of the log files, used when file_name
+                is provided.
+            format (LogFormat): The format of the logs. Defaults to LogFormat.TEXT.
+
+        Raises:
+            LoggerError: If the rotation_value of LogRotation.INTERVAL or LogRotation.SIZE is 0.
         """
-        Logger._instance = cls(level, file_name)
+        Logger._instance = cls(level, file_name, file_config, format)
@@ -33,6 +33,8 @@ EXCLUDED_API_FUNCTIONS = {
         "is_lower",
         "py_init",
         "py_log",
+        "py_set_log_callback",
+        "set_log_callback",
         # others
         "init_callback",
         "create_leaked_bytes_vec",
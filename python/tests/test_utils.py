@@ -1,11 +1,16 @@
 # Copyright Valkey GLIDE Project Contributors - SPDX Identifier: Apache-2.0
 
 import glob
+import json
 import os
 from pathlib import Path
 
-from glide.logger import Level, Logger
+import pytest
+from glide.logger import Level, LogFileConfig, LogFormat, Logger, LogRotation
+from glide_shared.exceptions import LoggerError
+from glide_sync import LogFileConfig as SyncLogFileConfig
 from glide_sync import LogLevel as SyncLogLevel
+from glide_sync import LogRotation as SyncLogRotation
 from glide_sync.logger import Logger as SyncLogger
 
 from tests.utils.utils import (
@@ -62,6 +67,64 @@ class TestLogger:
         # Reset logger config to default
         Logger.set_logger_config(DEFAULT_TEST_LOG_LEVEL)
 
+    def test_log_writes_json_to_file(self):
+        filename = get_random_string(10) + ".log"
+        Logger.set_logger_config(Level.INFO, filename, format=LogFormat.JSON)
+        Logger.log(Level.INFO, "test", "test json log message")
+
+        matched_files = find_log_files(filename)
+        assert (
+            len(matched_files) == 1
+        ), f"Expected exactly one log file with prefix '{filename}', found {len(matched_files)}"
+
+        log_file = matched_files[0]
+
+        with open(log_file, "r", encoding="utf-8") as f:
+            logs = [json.loads(line) for line in f if line.strip()]
+            assert any(
+                log["level"] == "INFO"
+                and log["identifier"] == "test"
+                and log["message"] == "test json log message"
+                for log in logs
+            ), f"JSON log not found in {logs}"
+
+        # Clean up the file
+        os.remove(log_file)
+
+        # Reset logger config to default
+        Logger.set_logger_config(DEFAULT_TEST_LOG_LEVEL)
+
+    @pytest.mark.parametrize("rotation", [LogRotation.INTERVAL, LogRotation.SIZE])
+    def test_zero_rotation_value_is_rejected(self, rotation):
+        filename = get_random_string(10) + ".log"
+        with pytest.raises(ValueError, match="must be positive"):
+            Logger.set_logger_config(
+                Level.INFO, filename, LogFileConfig(rotation=rotation)
+            )
+        # The logger isn't changed
+        assert Logger.logger_level == DEFAULT_TEST_LOG_LEVEL.value
+        assert find_log_files(filename) == []
+
+    def test_log_callback(self):
+        received = []
+        Logger.set_log_callback(
+            Level.INFO,
+            lambda level, identifier, message, fields: received.append(
+                (level, identifier, message, fields)
+            ),
+        )
+        Logger.set_logger_config(Level.INFO)
+        Logger.log(Level.INFO, "callback_test", "test callback log message")
+        Logger.set_log_callback()
+        Logger.log(Level.INFO, "callback_test", "message after reset")
+
+        assert [log for log in received if log[1] == "callback_test"] == [
+            (Level.INFO, "callback_test", "test callback log message", {})
+        ]
+
+        # Reset logger config to default
+        Logger.set_logger_config(DEFAULT_TEST_LOG_LEVEL)
+
     def test_init_sync_logger(self):
         # The logger is already configured in the conftest file, so calling init again shouldn't modify the log level
         SyncLogger.init(SyncLogLevel.ERROR)
@@ -98,6 +161,19 @@ class TestLogger:
         # Reset logger config to default
         SyncLogger.set_logger_config(DEFAULT_SYNC_TEST_LOG_LEVEL)
 
+    @pytest.mark.parametrize(
+        "rotation", [SyncLogRotation.INTERVAL, SyncLogRotation.SIZE]
+    )
+    def test_sync_zero_rotation_value_is_rejected(self, rotation):
+        filename = get_random_string(10) + ".log"
+        with pytest.raises(LoggerError, match="must be positive"):
+            SyncLogger.set_logger_config(
+                SyncLogLevel.INFO, filename, SyncLogFileConfig(rotation=rotation)
+            )
+        # The logger isn't changed
+        assert SyncLogger.logger_level == DEFAULT_SYNC_TEST_LOG_LEVEL
+        assert find_log_files(filename) == []
+
 
 class TestCompareMaps:
     def test_empty_maps(self):
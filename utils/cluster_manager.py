@@ -584,6 +584,7 @@ def create_standalone_replication(
     for i, server in enumerate(servers):
         if i == 0:
             continue  # Skip the primary server
+        server.set_primary(False)
         replica_of_command = [
             get_cli_command(),
             *get_cli_option_args(cluster_folder, use_tls),
@@ -615,6 +616,7 @@ def create_standalone_replication(
     logging.debug(
         f"{len(servers) - 1} nodes successfully became replicas of the primary {primary_server}!"
     )
+    print_servers_json(servers)
 
     toc = time.perf_counter()
     logging.debug(f"create_replication Elapsed time: {toc - tic:0.4f}")